@@ -0,0 +1,20 @@
+//! Minimal timing helpers shared by the benchmarks (run with `cargo bench`)
+
+use std::time::{Duration, Instant};
+
+/// Run the closure `iterations` times and print the total and the mean duration
+pub fn bench<R>(name: &str, iterations: u32, mut f: impl FnMut() -> R) -> Duration {
+    // Warm up the caches (patterns, regex) before timing
+    for _ in 0..iterations.min(1_000) {
+        std::hint::black_box(f());
+    }
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        std::hint::black_box(f());
+    }
+    let elapsed = start.elapsed();
+
+    println!("{:<48} {:>10.2?} ({:?}/op)", name, elapsed, elapsed / iterations);
+    elapsed
+}
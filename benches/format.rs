@@ -0,0 +1,34 @@
+//! Compare the String path (to_format) with the write path (write_format) of ToFormat
+
+mod common;
+
+use num_string::{Culture, ToFormat};
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn main() {
+    let numbers = [1234567.891, -0.5, 42.0];
+
+    let mut index = 0;
+    let mut next_number = move || {
+        index = (index + 1) % numbers.len();
+        numbers[index]
+    };
+
+    common::bench("to_format", ITERATIONS, || next_number().to_format("N2", Culture::French).unwrap().len());
+
+    // The buffer is reused, as in a logger or a report writer
+    let mut buffer = String::with_capacity(64);
+    common::bench("write_format", ITERATIONS, || {
+        buffer.clear();
+        next_number().write_format(&mut buffer, "N2", Culture::French).unwrap();
+        buffer.len()
+    });
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(64);
+    common::bench("write_format_bytes", ITERATIONS, || {
+        bytes.clear();
+        next_number().write_format_bytes(&mut bytes, "N2", Culture::French).unwrap();
+        bytes.len()
+    });
+}
@@ -0,0 +1,28 @@
+//! Compare the culture pattern lookup of NumberPatterns (indexed by culture code)
+//! with a linear search in a Vec of culture patterns, as before the index
+
+mod common;
+
+use num_string::pattern::{CulturePattern, NumberPatterns};
+use num_string::Culture;
+
+const ITERATIONS: u32 = 100_000;
+
+fn main() {
+    for custom_cultures in [0, 100, 1_000] {
+        let mut patterns = NumberPatterns::default();
+        for index in 0..custom_cultures {
+            let name = format!("custom_{}", index);
+            patterns.add_culture_pattern(CulturePattern::new(&name, Culture::English.into()).unwrap());
+        }
+        // The looked up culture is the last one of the linear search
+        let linear = patterns.get_all_culture_pattern();
+        let name = linear.last().unwrap().get_name().to_owned();
+
+        println!("{} cultures", linear.len());
+        common::bench("  NumberPatterns::get_culture_pattern_by_name", ITERATIONS, || {
+            patterns.get_culture_pattern_by_name(&name).is_some()
+        });
+        common::bench("  linear search", ITERATIONS, || linear.iter().any(|pattern| pattern.get_name() == name));
+    }
+}
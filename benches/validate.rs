@@ -0,0 +1,25 @@
+//! Compare pattern::validate with ConvertString::is_numeric on the same inputs
+
+mod common;
+
+use num_string::pattern::validate;
+use num_string::{ConvertString, Culture};
+
+const ITERATIONS: u32 = 100_000;
+
+fn main() {
+    let inputs = [
+        ("-1 234,50", Culture::French),
+        ("1,000,000.25", Culture::English),
+        ("\u{2212}1.234.567,0", Culture::Italian),
+        ("abc", Culture::English),
+    ];
+
+    for (string_num, culture) in inputs {
+        println!("'{}' ({})", string_num, culture.display_name());
+        common::bench("  validate", ITERATIONS, || validate(string_num, culture).is_some());
+        common::bench("  ConvertString::is_numeric", ITERATIONS, || {
+            ConvertString::new(string_num, Some(culture)).is_numeric()
+        });
+    }
+}
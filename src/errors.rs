@@ -1,7 +1,9 @@
 use std::{fmt::Display};
 
+use crate::{pattern::Separator, Culture};
+
 /// The different kind of error which can happen during the conversion
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ConversionError {
     /// When trying to convert the string. This error happen when after cleaned the input the core::str::parse() function return a conversion error
     UnableToConvertStringToNumber,
@@ -22,7 +24,47 @@ pub enum ConversionError {
     SeparatorNotFound,
 
     /// When the dynamic regex generation fail (automatically build from culture and type parsing)
-    RegexBuilder
+    RegexBuilder,
+
+    /// The thousand grouping of the string number is not consistent with the culture (ex: "1,00" in English)
+    InvalidGrouping { expected: u8, found: u8 },
+
+    /// The string number should have thousand separators but has none (ex: "1234567" in French)
+    MissingGrouping,
+
+    /// The string number is valid for several cultures, but with different values (ex: "1,000" is 1000 in English and 1 in French)
+    /// Each alternative is a culture with the value it would produce
+    AmbiguousInput { alternatives: Vec<(Culture, String)> },
+
+    /// The string number is too big for the float type, and would be converted to infinity
+    Overflow,
+
+    /// The string number is too small for the float type, and would be converted to 0
+    Underflow,
+
+    /// The formatted number can't be written to the writer
+    WriteFailed,
+
+    /// The thousand and decimal separators are the same
+    DuplicateSeparators { separator: Separator },
+
+    /// The minimum of the range is greater than the maximum
+    InvalidRange { min_str: String, max_str: String },
+
+    /// The string number is a decimal number, but an integer is expected (ex: "1.5" with to_integer_strict)
+    ExpectedInteger,
+
+    /// An element of the list can't be formatted, with its index and the error (see to_format_list)
+    ListElement { index: usize, error: Box<ConversionError> },
+
+    /// The string number is not written exactly as the culture writes it (see to_number_strict)
+    NoExactPatternMatch,
+
+    /// The custom pattern is not valid (see to_format_pattern), with the position of the invalid character
+    InvalidFormatPattern { position: usize },
+
+    /// The separator can't be the decimal separator (ex: a whitespace, see NumberCultureSettings::swap_separators)
+    InvalidDecimalSeparator { separator: Separator },
 }
 
 impl ConversionError {
@@ -35,12 +77,124 @@ impl ConversionError {
             Self::PatternCultureNotFound => "Unable to find pattern culture",
             Self::SeparatorNotFound => "Unable to find separator from string",
             Self::RegexBuilder => "Unable to create regex",
+            Self::InvalidGrouping { .. } => "The thousand grouping is not consistent with the culture",
+            Self::MissingGrouping => "The thousand separator is required for this number",
+            Self::AmbiguousInput { .. } => "The string number has different values depending on the culture",
+            Self::Overflow => "The number is too big and would be converted to infinity",
+            Self::Underflow => "The number is too small and would be converted to 0",
+            Self::WriteFailed => "Unable to write the formatted number",
+            Self::DuplicateSeparators { .. } => "The thousand and decimal separators must be different",
+            Self::InvalidRange { .. } => "The minimum of the range is greater than the maximum",
+            Self::ExpectedInteger => "The string number is not an integer",
+            Self::ListElement { .. } => "Unable to format an element of the list",
+            Self::NoExactPatternMatch => "The string number doesn't match exactly a pattern of the culture",
+            Self::InvalidFormatPattern { .. } => "The format pattern is not valid",
+            Self::InvalidDecimalSeparator { .. } => "The separator can't be used as decimal separator",
         }
     }
 }
 
 impl Display for ConversionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message())
+        match self {
+            Self::InvalidGrouping { expected, found } => write!(
+                f,
+                "{} (expected {} digits, found {})",
+                self.message(),
+                expected,
+                found
+            ),
+            Self::AmbiguousInput { alternatives } => write!(
+                f,
+                "{} ({})",
+                self.message(),
+                alternatives
+                    .iter()
+                    .map(|(culture, value)| format!("{:?} = {}", culture, value))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::DuplicateSeparators { separator } => write!(f, "{} ({:?})", self.message(), separator),
+            Self::InvalidRange { min_str, max_str } => write!(
+                f,
+                "{} (min = {}, max = {})",
+                self.message(),
+                min_str,
+                max_str
+            ),
+            Self::ListElement { index, error } => write!(f, "{} (index = {} : {})", self.message(), index, error),
+            Self::InvalidFormatPattern { position } => write!(f, "{} (position {})", self.message(), position),
+            Self::InvalidDecimalSeparator { separator } => write!(f, "{} ({:?})", self.message(), separator),
+            _ => write!(f, "{}", self.message()),
+        }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{Culture, Separator};
+
+    use super::ConversionError;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ConversionError::UnableToDisplayFormat.to_string(),
+            "Error when trying to display format number"
+        );
+        assert_eq!(
+            ConversionError::InvalidGrouping { expected: 3, found: 2 }.to_string(),
+            "The thousand grouping is not consistent with the culture (expected 3 digits, found 2)"
+        );
+        assert_eq!(
+            ConversionError::AmbiguousInput {
+                alternatives: vec![(Culture::English, "1000".to_owned()), (Culture::Italian, "1".to_owned())]
+            }
+            .to_string(),
+            "The string number has different values depending on the culture (English = 1000, Italian = 1)"
+        );
+        assert_eq!(
+            ConversionError::DuplicateSeparators { separator: Separator::COMMA }.to_string(),
+            "The thousand and decimal separators must be different (COMMA)"
+        );
+        assert_eq!(
+            ConversionError::InvalidRange { min_str: "10".to_owned(), max_str: "1.5".to_owned() }.to_string(),
+            "The minimum of the range is greater than the maximum (min = 10, max = 1.5)"
+        );
+        assert_eq!(
+            ConversionError::ListElement { index: 2, error: Box::new(ConversionError::UnableToDisplayFormat) }.to_string(),
+            "Unable to format an element of the list (index = 2 : Error when trying to display format number)"
+        );
+        assert_eq!(
+            ConversionError::InvalidFormatPattern { position: 4 }.to_string(),
+            "The format pattern is not valid (position 4)"
+        );
+        assert_eq!(
+            ConversionError::InvalidDecimalSeparator { separator: Separator::SPACE }.to_string(),
+            "The separator can't be used as decimal separator (SPACE)"
+        );
+    }
+
+    #[test]
+    fn test_clone_and_hash() {
+        let error = ConversionError::MissingGrouping;
+        let cloned = error.clone();
+        assert_eq!(error, cloned);
+        assert!(HashSet::from([cloned]).contains(&error));
+
+        let ambiguous = ConversionError::AmbiguousInput {
+            alternatives: vec![(Culture::English, "1000".to_owned()), (Culture::French, "1".to_owned())],
+        };
+        let errors = HashSet::from([
+            ambiguous.clone(),
+            ambiguous.clone(),
+            ConversionError::InvalidGrouping { expected: 3, found: 2 },
+            ConversionError::InvalidGrouping { expected: 3, found: 1 },
+        ]);
+        assert_eq!(errors.len(), 3);
+        assert!(errors.contains(&ambiguous));
+        assert!(!errors.contains(&ConversionError::InvalidGrouping { expected: 2, found: 1 }));
+    }
+}
@@ -104,13 +104,32 @@
 //!     let string_error = ConvertString::new("NotANumber", Some(Culture::English));
 //!     assert!(!string_error.is_numeric());
 //! ```
+//!
+//! ## Prelude
+//!
+//! All the common items can be imported at once
+//!
+//! ``` rust
+//! use num_string::prelude::*;
+//!     assert_eq!("1.000,5".to_number_culture::<f64>(Culture::Italian).unwrap(), 1000.5);
+//!     assert_eq!((-1000).to_format("N0", Culture::French).unwrap(), "-1 000");
+//!
+//!     let string_num = ConvertString::new("1,000", Some(Culture::English));
+//!     assert_eq!(string_num.get_current_pattern().unwrap().get_number_type(), &NumberType::WHOLE);
+//! ```
 
+use pattern::{RegexPattern, TypeParsing};
 use regex::Regex;
 
+#[macro_use]
+mod macros;
 pub mod errors;
 pub mod number_to_string;
 pub mod string_to_number;
 pub mod pattern;
+pub mod prelude;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 pub use errors::ConversionError;
 pub use number_to_string::ToFormat;
@@ -118,12 +137,13 @@ pub use string_to_number::NumberConversion;
 pub use pattern::{ConvertString, NumberCultureSettings, Separator, ThousandGrouping};
 
 /// Represent the current "ConvertString" culture
-#[derive(PartialEq, Debug, Clone, Copy, enum_iterator::Sequence)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, enum_iterator::Sequence)]
 pub enum Culture {
     English,
     French,
     Italian,
-    Indian
+    Indian,
+    Turkish
 }
 
 /// Default culture = English
@@ -139,7 +159,8 @@ impl From<Culture> for &str {
             Culture::English => "en",
             Culture::French => "fr",
             Culture::Italian => "it",
-            Culture::Indian => "id"
+            Culture::Indian => "id",
+            Culture::Turkish => "tr"
         }
     }
 }
@@ -153,15 +174,76 @@ impl TryFrom<&str> for Culture {
             "fr" => Culture::French,
             "it" => Culture::Italian,
             "id" => Culture::Indian,
+            "tr" => Culture::Turkish,
             _ => return Err(ConversionError::PatternCultureNotFound),
         })
     }
 }
 
+impl Culture {
+    /// Return all the cultures (ex: to fill a locale picker)
+    /// Ref 'test_culture_names'
+    pub fn all() -> impl Iterator<Item = Culture> {
+        enum_iterator::all::<Culture>()
+    }
+
+    /// Return the human readable name of the culture
+    /// Ref 'test_culture_names'
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Culture::English => "English",
+            Culture::French => "French (France)",
+            Culture::Italian => "Italian (Italy)",
+            Culture::Indian => "Indian (Hindi)",
+            Culture::Turkish => "Turkish (Turkey)",
+        }
+    }
+
+    /// Return the BCP 47 primary language tag of the culture
+    /// Not the same as the culture code ('into'), kept for compatibility ("id" for Indian)
+    /// Ref 'test_culture_names'
+    pub fn iso_code(&self) -> &'static str {
+        match self {
+            Culture::English => "en",
+            Culture::French => "fr",
+            Culture::Italian => "it",
+            Culture::Indian => "hi",
+            Culture::Turkish => "tr",
+        }
+    }
+
+    /// Return the region code of the culture, None when the language defaults to its region (Italian = Italy)
+    /// Ref 'test_culture_names'
+    pub fn region_code(&self) -> Option<&'static str> {
+        match self {
+            Culture::English => Some("US"),
+            Culture::French => Some("FR"),
+            Culture::Italian => None,
+            Culture::Indian => Some("IN"),
+            Culture::Turkish => Some("TR"),
+        }
+    }
+
+    /// Return the anchored regex used to parse the type of string number in this culture,
+    /// to validate the input on the client side (ex: JSON schema pattern, HTML input)
+    /// Return RegexBuilder for TypeParsing::Custom, which has no built-in regex
+    /// Ref 'test_regex_for'
+    pub fn regex_for(&self, type_parsing: TypeParsing) -> Result<String, ConversionError> {
+        Ok(RegexPattern::new(&type_parsing, Some((*self).into()))?.as_regex_string())
+    }
+
+    /// Return one anchored regex matching all the string numbers accepted in this culture (see NumberPatterns::culture_regex)
+    /// Ref 'test_regex_for'
+    pub fn regex_all(&self) -> Option<String> {
+        ConvertString::load_patterns().culture_regex(self)
+    }
+}
+
 // Tout ajouter dans NumberPatterns > culture_pattern
 
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
 mod tests {
 
     use crate::errors::ConversionError;
@@ -202,6 +284,7 @@ mod tests {
             (-2000, Culture::French, "-2 000"),
             (-2000, Culture::Italian, "-2.000"),
             (-2000, Culture::Indian, "-2,000"),
+            (-2000, Culture::Turkish, "-2.000"),
         ];
 
         for (number, culture, to_string_format) in integers {
@@ -249,6 +332,7 @@ mod tests {
             (100000000.10, "100.000.000,10", Culture::Italian),
             (-50.50, "-50,50", Culture::Italian),
             (100000000.10, "10,00,00,000.10", Culture::Indian),
+            (1234.56, "1.234,56", Culture::Turkish),
         ];
 
         for (val_f64, val_str, culture) in values_float {
@@ -256,4 +340,42 @@ mod tests {
             assert_eq!(val_str.to_number_culture::<f64>(culture).unwrap(), val_f64);
         }
     }
+
+    #[test]
+    fn test_culture_turkish() {
+        assert_eq!(Culture::try_from("tr"), Ok(Culture::Turkish));
+        let code: &str = Culture::Turkish.into();
+        assert_eq!(code, "tr");
+        assert!(enum_iterator::all::<Culture>().any(|c| c == Culture::Turkish));
+
+        // Same separators as Italian, but still a distinct culture
+        assert_ne!(Culture::Turkish, Culture::Italian);
+        assert_eq!(
+            crate::NumberCultureSettings::from(Culture::Turkish),
+            crate::NumberCultureSettings::from(Culture::Italian)
+        );
+
+        assert_eq!("1.234,56".to_number_culture::<f64>(Culture::Turkish).unwrap(), 1234.56);
+        assert_eq!(1234.56.to_format("N2", Culture::Turkish).unwrap(), "1.234,56");
+    }
+
+    #[test]
+    fn test_culture_names() {
+        assert_eq!(Culture::all().count(), 5);
+        for culture in Culture::all() {
+            assert!(!culture.display_name().is_empty());
+            let iso_code = culture.iso_code();
+            assert_eq!(iso_code.len(), 2, "{:?}", culture);
+            assert!(iso_code.chars().all(|c| c.is_ascii_lowercase()), "{:?}", culture);
+            if let Some(region) = culture.region_code() {
+                assert_eq!(region.len(), 2, "{:?}", culture);
+                assert!(region.chars().all(|c| c.is_ascii_uppercase()), "{:?}", culture);
+            }
+        }
+
+        assert_eq!(Culture::French.display_name(), "French (France)");
+        assert_eq!(Culture::Indian.iso_code(), "hi");
+        assert_eq!(Culture::English.region_code(), Some("US"));
+        assert_eq!(Culture::Italian.region_code(), None);
+    }
 }
@@ -0,0 +1,57 @@
+//! Macros to format the numbers when an error can only be a programming mistake
+
+/// Format the number like 'ToFormat::to_format', and panic if the formatting fails
+/// (wrong format string, like format! with wrong arguments)
+/// Ref 'test_format_number'
+///
+/// ``` rust
+/// use num_string::{format_number, Culture};
+///     assert_eq!(format_number!(1000.5, "N2", Culture::English), "1,000.50");
+///     assert_eq!(format_number!(-1000, "N0", Culture::French), "-1 000");
+/// ```
+#[macro_export]
+macro_rules! format_number {
+    ($value:expr, $format:expr, $culture:expr) => {
+        $crate::ToFormat::to_format($value, $format, $culture).unwrap_or_else(|e| panic!("format_number! failed: {}", e))
+    };
+}
+
+/// Format the number like 'ToFormat::to_format', and return the default string if the formatting fails
+/// Ref 'test_format_number'
+///
+/// ``` rust
+/// use num_string::{format_number_or, Culture};
+///     assert_eq!(format_number_or!(1000.5, "N2", Culture::English, "N/A"), "1,000.50");
+///     assert_eq!(format_number_or!(1000.5, "X2", Culture::English, "N/A"), "N/A");
+/// ```
+#[macro_export]
+macro_rules! format_number_or {
+    ($value:expr, $format:expr, $culture:expr, $default:expr) => {
+        $crate::ToFormat::to_format($value, $format, $culture).unwrap_or_else(|_| ::std::string::String::from($default))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Culture;
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number!(1000, "N0", Culture::English), "1,000");
+        assert_eq!(format_number!(1234.567, "N2", Culture::French), "1 234,57");
+        assert_eq!(format_number!(100000.5_f32, "N1", Culture::Indian), "1,00,000.5");
+
+        let value = -42;
+        assert_eq!(format_number!(value, "N2", Culture::Italian), "-42,00");
+
+        assert_eq!(format_number_or!(1000, "N0", Culture::English, "N/A"), "1,000");
+        assert_eq!(format_number_or!(1000, "N", Culture::English, "N/A"), "N/A");
+        assert_eq!(format_number_or!(1000, "bad", Culture::English, String::from("-")), "-");
+    }
+
+    #[test]
+    #[should_panic(expected = "format_number! failed")]
+    fn test_format_number_panic() {
+        format_number!(1000, "X2", Culture::English);
+    }
+}
@@ -1,21 +1,23 @@
 use crate::pattern::ThousandGrouping;
-use crate::pattern::ConvertString;
-use crate::string_to_number::NumberConversion;
 use crate::ConversionError;
 use crate::Culture;
 use crate::NumberCultureSettings;
 use crate::Regex;
 use log::error;
 use log::trace;
-use num::Num;
-use thousands::SeparatorPolicy;
+use num::bigint::Sign;
+use num::rational::Ratio;
+use num::{BigInt, BigUint, Integer, Num, Zero};
 use std::fmt::Display;
-use thousands::Separable;
+use std::sync::OnceLock;
 
 /// Trait to display a number with 'to_format' function
 /// The format parameter is like C# toString() function with N0 / N2 / N4 values
 /// N0 display 0 digit, N2 two digit, N4 four digit etc.
 /// The max is N9 digit
+/// F0 to F9 are the fixed formats, like N without the thousand separator ("F2" : 1234567.891 = "1234567.89")
+/// With a culture, the other format letters are C for the currency ("C2"), P for the percent ("P1" : 0.125 = "12.5%")
+/// and E for the scientific notation ("E2" : 12345.678 = "1.23E+4", the exponent always has its sign and no leading zero)
 /// And the culture parameter is use to display with the selected culture (it automatically
 /// apply the thousand and decimal separator of the given culture)
 /// Or you can specify your custom thousand and decimal separator with NumberCultureSettings
@@ -31,21 +33,472 @@ use thousands::Separable;
 pub trait ToFormat {
     fn to_format_separators(self, digit: &str, separators: NumberCultureSettings) -> Result<String, ConversionError>;
     fn to_format(self, digit: &str, culture: Culture) -> Result<String, ConversionError>;
+
+    /// Display the number like 'to_format', and also return the value rounded to the displayed precision,
+    /// computed from the displayed digits without parsing the string. Only the N, F and C formats are supported
+    /// Ex : 1.005.to_format_with_value("N2", Culture::English) = ("1.01", 1.01)
+    /// Ref 'test_to_format_with_value'
+    fn to_format_with_value(self, digit: &str, culture: Culture) -> Result<(String, f64), ConversionError>;
+
+    /// Display the number like 'to_format', with the whole part padded with leading zeros to have at least min_int_digits digits
+    /// Ex : 42.to_format_with_min_integer(6, "N2", Culture::English) = "000,042.00"
+    fn to_format_with_min_integer(self, min_int_digits: u8, digit: &str, culture: Culture) -> Result<String, ConversionError>;
+
+    /// Display the number like 'to_format', followed by a space and the unit
+    /// Ex : 1234.5.to_format_unit("N1", Culture::French, "kg") = "1 234,5 kg"
+    fn to_format_unit(self, digit: &str, culture: Culture, unit: &str) -> Result<String, ConversionError>;
+
+    /// Display the number with max_sig_figs significant figures.
+    /// Below compact_threshold the number is displayed in fixed-point, above it uses the compact notation (K, M, B, T).
+    /// The tiny numbers which need more than 255 decimals in fixed-point are displayed in scientific notation (1e-300 = "1.00E-300")
+    /// Ex with to_format_smart(3, 10000.0, Culture::English) : 12345 = "12.3K", 1234 = "1,230", 0.00123 = "0.00123"
+    fn to_format_smart(self, max_sig_figs: u8, compact_threshold: f64, culture: Culture) -> Result<String, ConversionError>;
+
+    /// Display the number with the culture and the format options, when the Nx format is not enough
+    /// (ex: at least 2 and at most 4 decimals, FormatOption::new(2, 4))
+    /// Ex : 1.56789.to_format_options(Culture::French, FormatOption::new(2, 4)) = "1,5679"
+    /// Ref 'test_to_format_options_trait'
+    fn to_format_options(self, culture: Culture, options: FormatOption) -> Result<String, ConversionError>;
+
+    /// Display the number like 'to_format', with the decimal part also grouped by 3 digits with the thousand separator
+    /// Ex : 3.141592653.to_format_grouped_fraction("N9", Culture::French) = "3,141 592 653"
+    fn to_format_grouped_fraction(self, digit: &str, culture: Culture) -> Result<String, ConversionError>;
+
+    /// Display the number like 'to_format' with a currency ("C0" to "C9" formats), instead of the default currency of the culture
+    /// Ex : 1234.5.to_format_currency("C2", Culture::French, Currency::new("CHF", CurrencyPosition::After).with_space(true)) = "1 234,50 CHF"
+    fn to_format_currency(self, digit: &str, culture: Culture, currency: Currency) -> Result<String, ConversionError>;
+
+    /// Display the number in scientific or engineering notation, the significand uses the decimal separator of the culture
+    /// Ex : 12345.to_format_exponent(&ExponentFormat::scientific(2), Culture::English) = "1.23E+4"
+    fn to_format_exponent(self, fmt: &ExponentFormat, culture: Culture) -> Result<String, ConversionError>;
+
+    /// Display the number with a custom pattern, like the .NET / Excel custom formats :
+    ///  - '0' is a digit always displayed, '#' a digit displayed only if needed ("#,##0.0#" = 1 to 2 decimals)
+    ///  - ',' in the whole part groups the thousands, '.' is the decimal separator (both replaced by the culture ones)
+    ///  - the other characters before and after the digits are displayed as they are, with '%' multiplying the number by 100
+    ///  - an optional second section, after ';', is used for the negative numbers, without the minus sign ("0.00;(0.00)")
+    ///
+    /// The whole part always has at least one digit. Invalid patterns return InvalidFormatPattern with the position of the error
+    /// Ex : 1234.5.to_format_pattern("#,##0.00", Culture::French) = "1 234,50"
+    /// Ref 'test_to_format_pattern'
+    fn to_format_pattern(self, pattern: &str, culture: Culture) -> Result<String, ConversionError>;
+
+    /// Write the number like 'to_format' to the writer, without allocating the result String (all the formats of 'to_format')
+    /// Ref 'test_write_format'
+    fn write_format<W: std::fmt::Write>(self, w: &mut W, digit: &str, culture: Culture) -> Result<(), ConversionError>;
+
+    /// Write the number like 'to_format' to the byte stream (file, socket, Vec<u8> etc.)
+    fn write_format_bytes<W: std::io::Write>(self, w: &mut W, digit: &str, culture: Culture) -> Result<(), ConversionError>;
 }
 
 /// Implement the trait for all primitive (i8, i64, u32, f32 etc.), thanks to Num trait
+///
+/// References (&u64, &f32 etc.) work with the method call syntax, the reference is dereferenced and the number copied.
+/// There is no implementation for &T : it would conflict with this one (num could implement Num for references)
+/// The rationals (num::rational::Ratio) are covered too, and displayed as decimal numbers (Ratio::new(1, 3) in N4 = "0.3333").
+/// The other number types need an implementation of DecimalString
 impl<T> ToFormat for T
 where
-    T: Num + Display,
+    T: Num + DecimalString,
 {
     fn to_format(self, digit: &str, culture: Culture) -> Result<String, ConversionError> {
-        self.to_format_separators(digit, culture.into())
-        
+        let mut number_string = String::new();
+        self.write_format(&mut number_string, digit, culture)?;
+        Ok(number_string)
     }
 
     fn to_format_separators(self, digit: &str, separators: NumberCultureSettings) -> Result<String, ConversionError> {
+        Number::<T>::new(self).to_format_options(separators, Number::<T>::separators_format(digit)?)
+    }
+
+    fn to_format_with_value(self, digit: &str, culture: Culture) -> Result<(String, f64), ConversionError> {
+        let format = match digit.chars().next() {
+            Some(letter @ ('F' | 'C')) => {
+                let nb_digit = Number::<T>::read_format(digit, letter)?;
+                match letter {
+                    'F' => FormatOption::new(nb_digit, nb_digit).with_use_grouping(false),
+                    _ => FormatOption::new(nb_digit, nb_digit).with_currency(culture.into()),
+                }
+            }
+            _ => {
+                let nb_digit = Number::<T>::set_nb_digits(digit)?;
+                FormatOption::new(nb_digit, nb_digit)
+            }
+        };
+
+        // The value is built from the rounded digits which are displayed, so the string and the value are always consistent
+        let number = Number::<T>::new(self);
+        let (is_negative, whole, decimal) = number.rounded_digits(&format)?;
+        let sign = if is_negative { "-" } else { "" };
+        let value = match decimal {
+            Some(decimal) => format!("{}{}.{}", sign, whole, decimal),
+            None => format!("{}{}", sign, whole),
+        }
+        .parse::<f64>()
+        .map_err(|_| ConversionError::UnableToConvertNumberToString)?;
+
+        Ok((number.to_format_options(culture.into(), format)?, value))
+    }
+
+    fn to_format_with_min_integer(self, min_int_digits: u8, digit: &str, culture: Culture) -> Result<String, ConversionError> {
+        let nb_digit = Number::<T>::set_nb_digits(digit)?;
+        Number::<T>::new(self).to_format_options(
+            culture.into(),
+            FormatOption::new(nb_digit, nb_digit).with_min_integer_digits(min_int_digits),
+        )
+    }
+
+    fn to_format_unit(self, digit: &str, culture: Culture, unit: &str) -> Result<String, ConversionError> {
+        let nb_digit = Number::<T>::set_nb_digits(digit)?;
+        let number_string = Number::<T>::new(self).to_format_options(culture.into(), FormatOption::new(nb_digit, nb_digit))?;
+
+        // The sign is part of the number string, so the unit always stays after the magnitude
+        Ok(format!("{} {}", number_string, unit))
+    }
+
+    fn to_format_smart(self, max_sig_figs: u8, compact_threshold: f64, culture: Culture) -> Result<String, ConversionError> {
+        if max_sig_figs == 0 {
+            return Err(ConversionError::UnableToDisplayFormat);
+        }
+
+        let value = self.decimal_string().parse::<f64>().map_err(|_| ConversionError::UnableToConvertNumberToString)?;
+        if !value.is_finite() {
+            error!("{} can't be displayed with significant figures", value);
+            return Err(ConversionError::UnableToConvertNumberToString);
+        }
+
+        if value.abs() < compact_threshold {
+            return format_significant(value, max_sig_figs, culture);
+        }
+
+        // Compact notation : the value is divided by 1000 for each suffix
+        let suffixes = ["", "K", "M", "B", "T"];
+        let mut index = ((value.abs().log10() / 3.0).floor() as usize).min(suffixes.len() - 1);
+        let mut scaled = value / 10f64.powi(3 * index as i32);
+
+        // The rounding can reach the next suffix (ex: 999,950 = 1,000K = 1M)
+        if round_significant(scaled, max_sig_figs).abs() >= 1000.0 && index < suffixes.len() - 1 {
+            index += 1;
+            scaled = value / 10f64.powi(3 * index as i32);
+        }
+
+        Ok(format!("{}{}", format_significant(scaled, max_sig_figs, culture)?, suffixes[index]))
+    }
+
+    fn to_format_options(self, culture: Culture, options: FormatOption) -> Result<String, ConversionError> {
+        Number::<T>::new(self).to_format_options(culture.into(), options)
+    }
+
+    fn to_format_grouped_fraction(self, digit: &str, culture: Culture) -> Result<String, ConversionError> {
         let nb_digit = Number::<T>::set_nb_digits(digit)?;
-        Number::<T>::new(self).to_format_options(separators, FormatOption::new(nb_digit, nb_digit))
+        Number::<T>::new(self).to_format_options(
+            culture.into(),
+            FormatOption::new(nb_digit, nb_digit).with_group_fraction(true),
+        )
+    }
+
+    fn to_format_currency(self, digit: &str, culture: Culture, currency: Currency) -> Result<String, ConversionError> {
+        let nb_digit = Number::<T>::read_format(digit, 'C')?;
+        Number::<T>::new(self).to_format_options(
+            culture.into(),
+            FormatOption::new(nb_digit, nb_digit).with_currency(currency),
+        )
+    }
+
+    fn to_format_exponent(self, fmt: &ExponentFormat, culture: Culture) -> Result<String, ConversionError> {
+        if fmt.base == 0 {
+            return Err(ConversionError::UnableToDisplayFormat);
+        }
+
+        let value = self.decimal_string().parse::<f64>().map_err(|_| ConversionError::UnableToConvertNumberToString)?;
+        if !value.is_finite() {
+            error!("{} can't be displayed with an exponent", value);
+            return Err(ConversionError::UnableToConvertNumberToString);
+        }
+
+        let base = fmt.base as i32;
+        let scale = |exponent: i32| match exponent < 0 {
+            // Multiply by the positive power of ten, which is exact (in two steps for the subnormal numbers, 10^324 is infinity)
+            true if exponent < -300 => value * 1e300 * 10f64.powi(-exponent - 300),
+            true => value * 10f64.powi(-exponent),
+            false => value / 10f64.powi(exponent),
+        };
+        let round = |significand: f64| (significand * 10f64.powi(fmt.precision as i32)).round() / 10f64.powi(fmt.precision as i32);
+
+        // The exponent is the biggest multiple of base below the magnitude of the value
+        let mut exponent = match value == 0.0 {
+            true => 0,
+            false => (value.abs().log10().floor() as i32).div_euclid(base) * base,
+        };
+        // The rounding can reach the next exponent (ex: 9.999 = 10.00E+0 = 1.00E+1)
+        if round(scale(exponent)).abs() >= 10f64.powi(base) {
+            exponent += base;
+        }
+
+        let significand = Number::new(scale(exponent))
+            .to_format_options(culture.into(), FormatOption::new(fmt.precision, fmt.precision))?;
+        let exponent_sign = match (exponent < 0, fmt.always_show_sign) {
+            (true, _) => "-",
+            (false, true) => "+",
+            (false, false) => "",
+        };
+
+        Ok(format!(
+            "{}{}{}{}",
+            significand,
+            if fmt.capital_e { 'E' } else { 'e' },
+            exponent_sign,
+            exponent.abs()
+        ))
+    }
+
+    fn to_format_pattern(self, pattern: &str, culture: Culture) -> Result<String, ConversionError> {
+        let sections = PatternSection::parse_pattern(pattern)?;
+        let number = Number::new(self);
+
+        let formatted = sections[0].format(&number, culture)?;
+        match (formatted.strip_prefix('-'), sections.get(1)) {
+            (Some(unsigned), None) => Ok(format!("-{}{}{}", sections[0].prefix, unsigned, sections[0].suffix)),
+            (Some(_), Some(negative)) => {
+                let formatted = negative.format(&number, culture)?;
+                let unsigned = formatted.strip_prefix('-').unwrap_or(&formatted);
+                Ok(format!("{}{}{}", negative.prefix, unsigned, negative.suffix))
+            }
+            (None, _) => Ok(format!("{}{}{}", sections[0].prefix, formatted, sections[0].suffix)),
+        }
+    }
+
+    fn write_format<W: std::fmt::Write>(self, w: &mut W, digit: &str, culture: Culture) -> Result<(), ConversionError> {
+        // The currency and percent formats need the culture, not only its separators
+        match digit.chars().next() {
+            Some('C') => {
+                let nb_digit = Number::<T>::read_format(digit, 'C')?;
+                Number::<T>::new(self).write_options(
+                    w,
+                    culture.into(),
+                    FormatOption::new(nb_digit, nb_digit).with_currency(culture.into()),
+                )
+            }
+            Some('P') => Number::<T>::new(self).write_percent(w, digit, culture),
+            Some('E') => {
+                let precision = Number::<T>::read_format(digit, 'E')?;
+                let number_string = self.to_format_exponent(&ExponentFormat::scientific(precision), culture)?;
+                w.write_str(&number_string).map_err(|e| {
+                    error!("Unable to write the formatted number : {:?}", e);
+                    ConversionError::WriteFailed
+                })
+            }
+            _ => Number::<T>::new(self).write_options(w, culture.into(), Number::<T>::separators_format(digit)?),
+        }
+    }
+
+    fn write_format_bytes<W: std::io::Write>(self, w: &mut W, digit: &str, culture: Culture) -> Result<(), ConversionError> {
+        self.write_format(&mut IoWriter { inner: w }, digit, culture)
+    }
+}
+
+/// Return the number of decimals needed to display max_sig_figs significant figures, and the power of ten
+/// the whole part has to be rounded to when there are more digits than significant figures.
+/// Return None when the number of decimals doesn't fit in the format options (ex: 1e-300)
+fn significant_decimals(value: f64, max_sig_figs: u8) -> Option<(u8, Option<i32>)> {
+    if value == 0.0 {
+        return Some((max_sig_figs - 1, None));
+    }
+
+    let last_digit_exponent = value.abs().log10().floor() as i32 - max_sig_figs as i32 + 1;
+    if last_digit_exponent >= 0 {
+        Some((0, Some(last_digit_exponent)))
+    } else {
+        u8::try_from(-last_digit_exponent).ok().map(|decimals| (decimals, None))
+    }
+}
+
+/// Round the value to max_sig_figs significant figures (the value is unchanged when the decimals don't fit, see significant_decimals)
+fn round_significant(value: f64, max_sig_figs: u8) -> f64 {
+    match significant_decimals(value, max_sig_figs) {
+        Some((_, Some(exponent))) => (value / 10f64.powi(exponent)).round() * 10f64.powi(exponent),
+        Some((decimals, None)) => (value * 10f64.powi(decimals as i32)).round() / 10f64.powi(decimals as i32),
+        None => value,
+    }
+}
+
+/// Display the value in fixed-point with max_sig_figs significant figures
+/// Ref 'test_to_format_smart'
+fn format_significant(value: f64, max_sig_figs: u8, culture: Culture) -> Result<String, ConversionError> {
+    // The number of decimals is read from the rounded value, which can have one more digit (ex: 0.99995 = 1.00)
+    match significant_decimals(round_significant(value, max_sig_figs), max_sig_figs) {
+        // Too many digits in the whole part, the last ones are replaced by zeros
+        Some((_, Some(_))) => Number::new(round_significant(value, max_sig_figs)).to_format_options(culture.into(), FormatOption::new(0, 0)),
+        // The decimal part is rounded by the format options
+        Some((decimals, None)) => Number::new(value).to_format_options(culture.into(), FormatOption::new(decimals, decimals)),
+        // Too many decimals for the fixed-point notation, the scientific notation is used
+        None => value.to_format_exponent(&ExponentFormat::scientific(max_sig_figs - 1), culture),
+    }
+}
+
+/// Display the number in a JSON compatible way : no thousand separator, dot as decimal separator and no exponent.
+/// NaN and infinity are not supported by JSON and return an error
+/// Ref 'test_to_format_json'
+pub fn to_format_json<T: Num + DecimalString>(n: T) -> Result<String, ConversionError> {
+    let number_string = n.decimal_string();
+
+    let regex = Regex::new(r"^[\-]?[0-9]+([\.][0-9]+)?$").map_err(|_| ConversionError::RegexBuilder)?;
+    if !regex.is_match(&number_string) {
+        error!("{} can't be displayed as a JSON number", number_string);
+        return Err(ConversionError::UnableToConvertNumberToString);
+    }
+
+    Ok(number_string)
+}
+
+/// Display the number with the culture separators, keeping all its decimals, for a CSV field.
+/// With has_quotes, the value is quoted when it contains one of the culture separators
+/// (to avoid confusion with the CSV field delimiter, ex: "1,000.5" in English)
+/// Ref 'test_to_format_csv'
+pub fn to_format_csv<T: Num + DecimalString>(n: T, has_quotes: bool, culture: Culture) -> Result<String, ConversionError> {
+    let separators: NumberCultureSettings = culture.into();
+    let number = Number::new(n);
+
+    let (_, _, decimal_opt_string) = number.regex_read_number()?;
+    let nb_digit = decimal_opt_string.map(|d| d.len() as u8).unwrap_or(0);
+    let number_string = number.to_format_options(separators, FormatOption::new(nb_digit, nb_digit))?;
+
+    let has_separator = number_string.contains(separators.into_thousand_separator_string().as_str())
+        || number_string.contains(separators.into_decimal_separator_string().as_str());
+    if has_quotes && has_separator {
+        return Ok(format!("\"{}\"", number_string));
+    }
+
+    Ok(number_string)
+}
+
+/// Display the numbers with the format and culture, joined with the separator.
+/// Return ListElement with the index of the first number which can't be formatted (ex: NaN)
+/// Ex : [1000.0, 2000.5] with ", " and "N2" in English = "1,000.00, 2,000.50"
+/// Ref 'test_to_format_list'
+pub fn to_format_list<N: Num + DecimalString + Copy>(
+    numbers: &[N],
+    separator: &str,
+    format: &str,
+    culture: Culture,
+) -> Result<String, ConversionError> {
+    let formatted = numbers
+        .iter()
+        .enumerate()
+        .map(|(index, n)| {
+            n.to_format(format, culture)
+                .map_err(|error| ConversionError::ListElement { index, error: Box::new(error) })
+        })
+        .collect::<Result<Vec<String>, ConversionError>>()?;
+
+    Ok(formatted.join(separator))
+}
+
+/// Like 'to_format_list', but the numbers which can't be formatted are displayed with the default string
+/// Ref 'test_to_format_list'
+pub fn to_format_list_or_default<N: Num + DecimalString + Copy>(
+    numbers: &[N],
+    separator: &str,
+    format: &str,
+    culture: Culture,
+    default: &str,
+) -> String {
+    numbers
+        .iter()
+        .map(|n| n.to_format(format, culture).unwrap_or_else(|_| default.to_owned()))
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+/// Display the numbers with the format and culture, right-aligned on the widest formatted value (minus sign included),
+/// to build a column of a text table: all the returned strings have the same length in characters.
+/// The numbers which can't be formatted (ex: NaN) are displayed as is
+/// Ex : [1000.0, -2.5] with "N2" in English = ["1,000.00", "   -2.50"]
+/// Ref 'test_to_format_table'
+pub fn to_format_table<N: Num + DecimalString + Copy>(numbers: &[N], format: &str, culture: Culture) -> Vec<String> {
+    let formatted = format_table_cells(numbers, format, culture);
+    let width = formatted.iter().map(|cell| cell.chars().count()).max().unwrap_or(0);
+
+    align_table_cells(formatted, width)
+}
+
+/// Like 'to_format_table', with a header centered over the column.
+/// The column is as wide as the header when the header is wider than the numbers
+/// Ex : "Total" and [1000.0, -2.5] with "N2" in English = (" Total  ", ["1,000.00", "   -2.50"])
+/// Ref 'test_to_format_table'
+pub fn to_format_table_with_header<N: Num + DecimalString + Copy>(
+    header: &str,
+    numbers: &[N],
+    format: &str,
+    culture: Culture,
+) -> (String, Vec<String>) {
+    let formatted = format_table_cells(numbers, format, culture);
+    let width = formatted
+        .iter()
+        .map(|cell| cell.chars().count())
+        .chain(std::iter::once(header.chars().count()))
+        .max()
+        .unwrap_or(0);
+
+    (format!("{:^width$}", header), align_table_cells(formatted, width))
+}
+
+fn format_table_cells<N: Num + DecimalString + Copy>(numbers: &[N], format: &str, culture: Culture) -> Vec<String> {
+    numbers
+        .iter()
+        .map(|n| n.to_format(format, culture).unwrap_or_else(|_| n.to_string()))
+        .collect()
+}
+
+fn align_table_cells(cells: Vec<String>, width: usize) -> Vec<String> {
+    cells.into_iter().map(|cell| format!("{:>width$}", cell)).collect()
+}
+
+/// The number of decimals computed for the rationals (see Number::decimal_string), more than the N9 format needs to round
+const RATIO_MAX_DECIMALS: usize = 30;
+
+/// The number written with its decimal digits, read by the formats of 'ToFormat'.
+/// It's the display of the number by default, so the number types of other crates only need an empty implementation
+/// (impl DecimalString for MyNumber {})
+/// Ref 'test_to_format_ratio'
+pub trait DecimalString: Display {
+    fn decimal_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! impl_decimal_string {
+    ($($t:ty),*) => {
+        $(impl DecimalString for $t {})*
+    };
+}
+
+impl_decimal_string!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, BigInt, BigUint);
+
+/// The rationals are displayed as a fraction ("1/3"), they are expanded to a decimal string instead
+/// (with RATIO_MAX_DECIMALS decimals at most)
+impl<T: Integer + Display + Clone> DecimalString for Ratio<T> {
+    fn decimal_string(&self) -> String {
+        let (numerator, denominator) = match (self.numer().to_string().parse::<BigInt>(), self.denom().to_string().parse::<BigInt>()) {
+            (Ok(numerator), Ok(denominator)) if !denominator.is_zero() => (numerator, denominator),
+            _ => return self.to_string(),
+        };
+        let sign = if numerator.sign() * denominator.sign() == Sign::Minus { "-" } else { "" };
+        let (numerator, denominator) = (numerator.magnitude(), denominator.magnitude());
+
+        // Long division, the decimals after the last displayed one are only needed to round
+        let mut decimals = String::new();
+        let mut remainder = numerator % denominator;
+        while !remainder.is_zero() && decimals.len() < RATIO_MAX_DECIMALS {
+            remainder *= 10u32;
+            decimals.push_str(&(&remainder / denominator).to_string());
+            remainder %= denominator;
+        }
+
+        match decimals.is_empty() {
+            true => format!("{}{}", sign, numerator / denominator),
+            false => format!("{}{}.{}", sign, numerator / denominator, decimals),
+        }
     }
 }
 
@@ -55,7 +508,7 @@ pub struct Number<T: Num + Display> {
     pub num: T,
 }
 
-impl<T: num::Num + Display> Number<T> {
+impl<T: num::Num + DecimalString> Number<T> {
     pub fn new(num: T) -> Number<T> {
         Number { num }
     }
@@ -67,13 +520,15 @@ impl<T: num::Num + Display> Number<T> {
     ///     -10         should return : ("-", "10", None)
     /// See 'test_split_number' for example
     pub fn regex_read_number(&self) -> Result<(String, String, Option<String>), ConversionError> {
-        let str = &self.num.to_string();
+        Number::<T>::read_digits(&self.num.decimal_string())
+    }
 
-        // Regex to split the current number
-        let regex = Regex::new(r"([\-\+]?)([0-9]+)([\.]?)([0-9]*)").map_err(|e| {
-            error!("{:?}", e);
-            return ConversionError::UnableToConvertNumberToString;
-        })?;
+    /// Split the decimal string of a number in sign, whole and decimal parts (see 'regex_read_number')
+    fn read_digits(str: &str) -> Result<(String, String, Option<String>), ConversionError> {
+
+        // Regex to split the current number, compiled only once (the pattern is valid)
+        static SPLIT_REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = SPLIT_REGEX.get_or_init(|| Regex::new(r"([\-\+]?)([0-9]+)([\.]?)([0-9]*)").unwrap());
 
         let capture = regex
             .captures(str)
@@ -105,38 +560,183 @@ impl<T: num::Num + Display> Number<T> {
     /// Allowed values : N0, N1, N2, N3, N4, N5, N6, N7, N8, N9
     /// Ref test_set_nb_digits
     fn set_nb_digits(digit: &str) -> Result<u8, ConversionError> {
+        Number::<T>::read_format(digit, 'N')
+    }
+
+    /// Display the number multiplied by 100 with the percent sign of the culture ("P0" to "P9" formats)
+    /// The multiplication moves the decimal separator in the digits, so there is no float error (0.145 = "15%" in P0)
+    /// Ref 'test_to_format_percent'
+    fn write_percent<W: std::fmt::Write>(&self, w: &mut W, digit: &str, culture: Culture) -> Result<(), ConversionError> {
+        let nb_digit = Number::<T>::read_format(digit, 'P')?;
+
+        Number::<T>::write_digits(
+            w,
+            &self.percent_string(),
+            culture.into(),
+            FormatOption::new(nb_digit, nb_digit).with_currency(Number::<T>::percent_sign(culture)),
+        )
+    }
+
+    /// The decimal string of the number multiplied by 100, by moving the decimal separator in the digits
+    /// The digits are kept as a string, so the rounding stays exact for the big numbers (u64::MAX in P0)
+    /// Ref 'test_to_format_percent'
+    fn percent_string(&self) -> String {
+        let number_string = self.num.decimal_string();
+        let (sign, unsigned) = match number_string.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", number_string.as_str()),
+        };
+        let (whole, decimal) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let decimal = format!("{:0<2}", decimal);
+        let whole = format!("{}{}", whole, &decimal[..2]);
+        let whole = match whole.trim_start_matches('0') {
+            "" => "0",
+            trimmed => trimmed,
+        };
+        match &decimal[2..] {
+            "" => format!("{}{}", sign, whole),
+            fraction => format!("{}{}.{}", sign, whole, fraction),
+        }
+    }
+
+    /// The format options of the formats which only need the separators of the culture :
+    /// the number format ("N0" to "N9") and the fixed format ("F0" to "F9"), which is the number format without grouping
+    /// Ref 'test_format_use_grouping'
+    fn separators_format(digit: &str) -> Result<FormatOption, ConversionError> {
+        if digit.starts_with('F') {
+            let nb_digit = Number::<T>::read_format(digit, 'F')?;
+            return Ok(FormatOption::new(nb_digit, nb_digit).with_use_grouping(false));
+        }
+
+        let nb_digit = Number::<T>::set_nb_digits(digit)?;
+        Ok(FormatOption::new(nb_digit, nb_digit))
+    }
+
+    /// The percent sign of the culture, and its placement (written like a currency symbol)
+    fn percent_sign(culture: Culture) -> Currency {
+        match culture {
+            Culture::English | Culture::Indian | Culture::Italian => Currency::new("%", CurrencyPosition::After),
+            Culture::French => Currency::new("%", CurrencyPosition::After).with_space(true),
+            Culture::Turkish => Currency::new("%", CurrencyPosition::Before),
+        }
+    }
+
+    /// Split the format made of the letter and the number of digits (ex: 'C2' for the currency format)
+    /// Ref test_set_nb_digits
+    fn read_format(digit: &str, letter: char) -> Result<u8, ConversionError> {
         if digit.len() != 2 {
             return Err(ConversionError::UnableToDisplayFormat);
         }
 
         let chars: Vec<char> = digit.chars().collect();
-        if chars[0] != "N".chars().next().unwrap() {
+        if chars[0] != letter {
             return Err(ConversionError::UnableToDisplayFormat);
         }
 
-        Ok(chars[1].to_string().as_str().to_number::<u8>()?)
+        // A single ASCII digit, read without the string conversion machinery
+        chars[1]
+            .to_digit(10)
+            .map(|nb_digit| nb_digit as u8)
+            .ok_or(ConversionError::UnableToConvertStringToNumber)
     }
 
-    /// Apply the thousand separator to the whole number given in parameter
-    /// Thanks to thousands crate
-    /// Ref 'test_apply_thousand_separator'
-    fn apply_thousand_separator(num: i32, separators: NumberCultureSettings) -> String {
-        num.separate_by_policy(SeparatorPolicy {
-            separator: separators.thousand_separator().to_owned_string().as_str(),
-            groups: separators.thousand_grouping().into(),
-            digits: thousands::digits::ASCII_DECIMAL
-        })
+    /// Write the whole digits, padded with zeros up to min_digits, with the thousand separator when the separators are given
+    /// The padding is applied before the thousand separator, so the zeros are grouped too
+    /// Ref 'test_write_whole'
+    fn write_whole<W: std::fmt::Write>(
+        w: &mut W,
+        whole_string: &str,
+        min_digits: usize,
+        grouping: Option<NumberCultureSettings>,
+    ) -> std::fmt::Result {
+        let padding = min_digits.saturating_sub(whole_string.len());
+        let nb_digits = padding + whole_string.len();
+        let digits = std::iter::repeat_n('0', padding).chain(whole_string.chars());
+
+        for (index, digit) in digits.enumerate() {
+            w.write_char(digit)?;
+            let grouping = grouping.filter(|separators| Number::<T>::is_group_end(nb_digits - index - 1, separators.thousand_grouping()));
+            if let Some(separators) = grouping {
+                w.write_char(separators.thousand_separator().to_char())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return true if the thousand separator follows the digit which has nb_digits_after digits on its right
+    /// The groups are counted from the decimal separator, the last one is repeated (Indian : 3 then 2, 1,00,00,000)
+    fn is_group_end(nb_digits_after: usize, grouping: ThousandGrouping) -> bool {
+        let groups: &[u8] = grouping.into();
+        let mut group_end = 0;
+        let mut last_group = 3;
+
+        for group in groups {
+            last_group = *group as usize;
+            group_end += last_group;
+            if nb_digits_after <= group_end {
+                return nb_digits_after == group_end;
+            }
+        }
+
+        (nb_digits_after - group_end).is_multiple_of(last_group)
+    }
+
+    /// Write the decimal digits, with the thousand separator every 3 digits from the decimal separator when the separators are given
+    /// Ex : "141592653" = "141 592 653" in French
+    /// Ref 'test_to_format_grouped_fraction'
+    fn write_fraction<W: std::fmt::Write>(
+        w: &mut W,
+        decimal_string: &str,
+        grouping: Option<NumberCultureSettings>,
+    ) -> std::fmt::Result {
+        for (index, digit) in decimal_string.chars().enumerate() {
+            if let Some(separators) = grouping.filter(|_| index > 0 && index.is_multiple_of(3)) {
+                w.write_char(separators.thousand_separator().to_char())?;
+            }
+            w.write_char(digit)?;
+        }
+
+        Ok(())
+    }
+
+    /// Add one to the number written with the digits, without parsing it (so there is no size limit) : the trailing 9 become 0
+    /// Ex : "1299" = "1300", "999" = "1000"
+    fn increment_digits(digits: &str) -> String {
+        let kept = digits.trim_end_matches('9');
+        let nb_nines = digits.len() - kept.len();
+
+        match kept.char_indices().last() {
+            Some((index, last)) => format!("{}{}{}", &kept[..index], char::from(last as u8 + 1), "0".repeat(nb_nines)),
+            None => format!("1{}", "0".repeat(nb_nines)),
+        }
     }
 
-    /// Apply the format option to the decimal part (which is currently manipulated as a whole integer)
-    /// This function sucks, todo refacto later
+    /// Apply the format option to the decimal part, given as an integer
+    /// The leading zeros of the decimal part can't be written in an integer (1.05 = 5), see 'apply_decimal_digits'
     /// Ref 'test_apply_decimal'
     pub fn apply_decimal_format(decimal_part: i32, options: FormatOption) -> Option<(String, bool)> {
+        Number::<T>::apply_decimal_digits(&decimal_part.unsigned_abs().to_string(), options)
+    }
+
+    /// Apply the format option to the decimal part
+    /// The decimal part is given as the string of the decimal digits, to keep the leading zeros ("05" for 1.05)
+    /// The rounding is exact, computed on the digits without float conversion (ex: "344999999999999999999" = "34")
+    /// The decimal part is the one of a positive number, rounded half away from zero by default (see FormatOption::with_rounding_mode)
+    /// Return the formatted decimal part, and if the whole part needs to be rounded up
+    /// Return None without fraction digit (N0) : the whole part is rounded by 'write_options', with the same rounding mode
+    /// Ref 'test_apply_decimal', 'test_format_n0_rounding', 'test_exact_decimal_rounding'
+    pub fn apply_decimal_digits(decimal_string: &str, options: FormatOption) -> Option<(String, bool)> {
+        Number::<T>::round_decimal_digits(decimal_string, false, &options)
+    }
+
+    /// Like 'apply_decimal_digits', with the sign of the number needed by the rounding modes toward an infinity (see RoundingMode)
+    /// Ref 'test_rounding_mode'
+    pub(crate) fn round_decimal_digits(decimal_string: &str, is_negative: bool, options: &FormatOption) -> Option<(String, bool)> {
         if options.minimum_fraction_digit == 0 {
             return None;
         }
 
-        let decimal_string = decimal_part.to_string();
         let decimal_len = decimal_string.len() as u8;
 
         if decimal_len < options.minimum_fraction_digit {
@@ -147,7 +747,7 @@ impl<T: num::Num + Display> Number<T> {
             );
             return Some((format!(
                 "{}{}",
-                decimal_part,
+                decimal_string,
                 "0".repeat(options.minimum_fraction_digit as usize - decimal_len as usize)
             ), false));
         }
@@ -158,16 +758,19 @@ impl<T: num::Num + Display> Number<T> {
                 decimal_len,
                 options.maximum_fraction_digit
             );
+            let (kept, dropped) = decimal_string.split_at(options.maximum_fraction_digit as usize);
+            if !options.rounding_mode.is_rounded_up(kept, dropped, is_negative) {
+                return Some((kept.to_owned(), false));
+            }
+
             //Check if we need to round the whole part
-            let decimal_rounded = decimal_part as f64 / (10i32.pow(decimal_len as u32 - options.maximum_fraction_digit as u32) as f64);
-            if decimal_rounded.round() as u32 == 10u32.pow(options.maximum_fraction_digit as u32) {
+            if kept.chars().all(|c| c == '9') {
                 trace!("Need to round the whole part up");
                 return Some(("0".repeat(options.maximum_fraction_digit as usize), true));
             }
 
-            let exp = 10i32.pow((decimal_len - options.maximum_fraction_digit) as u32) as f64;
-            let calc = ((decimal_part as f64) / exp).round() as u128;
-            return Some((calc.to_string(), false));
+            // Add one to the last kept digit, the trailing 9 become 0
+            return Some((Number::<T>::increment_digits(kept), false));
         }
 
         trace!(
@@ -175,7 +778,34 @@ impl<T: num::Num + Display> Number<T> {
             decimal_len,
             options.minimum_fraction_digit
         );
-        Some((decimal_part.to_string(), false))
+        Some((decimal_string.to_owned(), false))
+    }
+
+    /// Return the sign (true if negative), the whole digits and the decimal digits of the number rounded with the format options,
+    /// without padding nor separators. The decimal digits are None without fraction digit (N0)
+    /// Ref 'test_to_format_with_value'
+    fn rounded_digits(&self, format: &FormatOption) -> Result<(bool, String, Option<String>), ConversionError> {
+        Number::<T>::round_digits(&self.num.decimal_string(), format)
+    }
+
+    /// Like 'rounded_digits', for the given decimal string of a number
+    fn round_digits(number_string: &str, format: &FormatOption) -> Result<(bool, String, Option<String>), ConversionError> {
+        let (sign_string, whole_string, decimal_opt_string) = Number::<T>::read_digits(number_string)?;
+        let is_negative = sign_string == "-";
+        let rounding_mode = format.rounding_mode;
+
+        // the decimal read by the previous regex or "0" if None
+        let decimal_string = decimal_opt_string.unwrap_or("0".to_owned());
+
+        trace!("Decimal part : {}", decimal_string);
+        match Number::<T>::round_decimal_digits(decimal_string.as_str(), is_negative, format) {
+            Some((decimal_format, true)) => Ok((is_negative, Number::<T>::increment_digits(&whole_string), Some(decimal_format))),
+            Some((decimal_format, false)) => Ok((is_negative, whole_string, Some(decimal_format))),
+            None => match rounding_mode.is_rounded_up(&whole_string, &decimal_string, is_negative) {
+                true => Ok((is_negative, Number::<T>::increment_digits(&whole_string), None)),
+                false => Ok((is_negative, whole_string, None)),
+            },
+        }
     }
 
     /// Main function
@@ -185,56 +815,90 @@ impl<T: num::Num + Display> Number<T> {
         separators: NumberCultureSettings,
         format: FormatOption,
     ) -> Result<String, ConversionError> {
+        Number::<T>::format_digits(&self.num.decimal_string(), separators, format)
+    }
+
+    /// Like 'to_format_options', for the given decimal string of a number
+    fn format_digits(
+        number_string: &str,
+        separators: NumberCultureSettings,
+        format: FormatOption,
+    ) -> Result<String, ConversionError> {
+        let mut formatted = String::new();
+        Number::<T>::write_digits(&mut formatted, number_string, separators, format)?;
+        Ok(formatted)
+    }
+
+    /// Apply the format to the number, and write it to the writer
+    /// The sign, the currency symbol, the digits and the separators are written one by one, the formatted String is never built
+    /// Ref 'test_write_format'
+    pub fn write_options<W: std::fmt::Write>(
+        &self,
+        w: &mut W,
+        separators: NumberCultureSettings,
+        format: FormatOption,
+    ) -> Result<(), ConversionError> {
+        Number::<T>::write_digits(w, &self.num.decimal_string(), separators, format)
+    }
+
+    /// Like 'write_options', for the given decimal string of a number
+    fn write_digits<W: std::fmt::Write>(
+        w: &mut W,
+        number_string: &str,
+        separators: NumberCultureSettings,
+        format: FormatOption,
+    ) -> Result<(), ConversionError> {
         trace!("format = {:?}", format);
-        let (sign_string, whole_string, decimal_opt_string) = self.regex_read_number()?;
-
-        let calc_to_string = |sign_string, whole_string| -> String {
-            Number::<T>::apply_thousand_separator(
-                ConvertString::new(format!("{}{}", sign_string, whole_string).as_str(), None)
-                    .to_number::<i32>()
-                    .unwrap(),
-                separators,
-            )
+        let (is_negative, whole_string, decimal_opt) = Number::<T>::round_digits(number_string, &format)?;
+
+        // The sign is not displayed when the number is rounded to zero (ex: -0.004 in N2 = "0.00")
+        let is_zero = whole_string
+            .chars()
+            .chain(decimal_opt.iter().flat_map(|decimal| decimal.chars()))
+            .all(|c| c == '0');
+        let sign = match (is_zero, is_negative) {
+            (true, _) => None,
+            (false, true) => Some(format.minus_char),
+            (false, false) => format.always_show_sign.then_some('+'),
         };
-        let mut number_string;
 
-        // the decimal read by the previous regex or "0" if None
-        let decimal_string = decimal_opt_string.unwrap_or("0".to_owned());
-        let decimal_part = ConvertString::new(decimal_string.as_str(), None)
-            .to_number::<i32>()
-            .unwrap();
+        let mut write = || -> std::fmt::Result {
+            // The sign is written before everything else, the currency symbol too
+            if let Some(sign) = sign {
+                w.write_char(sign)?;
+            }
+            if let Some(currency) = &format.currency {
+                currency.write_before(w)?;
+            }
 
-        trace!("Decimal part : {}", decimal_part);
-        let decimal_opt = Number::<T>::apply_decimal_format(decimal_part, format);
-        if let Some((decimal_format, need_round_up_whole_part)) = decimal_opt {
-            if need_round_up_whole_part {
-                number_string = calc_to_string(
-                    sign_string,
-                    (whole_string.as_str().to_number::<u64>().unwrap() + 1).to_string(),
-                );
-            } else {
-                number_string = calc_to_string(sign_string, whole_string);
+            let grouping = format.use_grouping.then_some(separators);
+            Number::<T>::write_whole(w, &whole_string, format.minimum_integer_digit as usize, grouping)?;
+            if let Some(decimal_string) = &decimal_opt {
+                w.write_char(separators.decimal_separator().to_char())?;
+                Number::<T>::write_fraction(w, decimal_string, format.group_fraction.then_some(separators))?;
             }
 
-            number_string = format!(
-                "{}{}{}",
-                number_string,
-                separators.into_decimal_separator_string(),
-                decimal_format
-            );
-        } else {
-            // No decimal required but
-            let whole_number = whole_string.as_str().to_number::<u64>().unwrap();
+            if let Some(currency) = &format.currency {
+                currency.write_after(w)?;
+            }
+            Ok(())
+        };
 
-            let exp = 10i32.pow(decimal_part.to_string().len() as u32) as f64;
+        write().map_err(|e| {
+            error!("Unable to write the formatted number : {:?}", e);
+            ConversionError::WriteFailed
+        })
+    }
+}
 
-            number_string = calc_to_string(
-                sign_string,
-                (whole_number + (((decimal_part as f64) / exp).round() as u64)).to_string(),
-            );
-        }
+/// Adapter to write the formatted number to a byte stream
+struct IoWriter<'a, W: std::io::Write> {
+    inner: &'a mut W,
+}
 
-        Ok(number_string)
+impl<W: std::io::Write> std::fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|_| std::fmt::Error)
     }
 }
 
@@ -250,58 +914,428 @@ impl<T: num::Num + Display> Display for Number<T> {
     }
 }
 
-/// Structure with the nb decimal required when display a number to string
-#[derive(Debug)]
-pub struct FormatOption {
-    minimum_fraction_digit: u8,
-    maximum_fraction_digit: u8,
-    thousand_grouping: ThousandGrouping,
+/// How the digits which are not displayed are rounded
+/// Ref 'test_rounding_mode'
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, enum_iterator::Sequence)]
+pub enum RoundingMode {
+    /// The halfway values are rounded away from zero (ex: 2.345 = 2.35, -2.345 = -2.35)
+    #[default]
+    HalfUp,
+    /// The halfway values are rounded to the even digit, the banker's rounding (ex: 2.345 = 2.34, 2.355 = 2.36)
+    HalfEven,
+    /// The halfway values are rounded toward zero (ex: 2.345 = 2.34, -2.345 = -2.34)
+    HalfDown,
+    /// Rounded toward the negative infinity (ex: 2.349 = 2.34, -2.341 = -2.35)
+    Floor,
+    /// Rounded toward the positive infinity (ex: 2.341 = 2.35, -2.349 = -2.34)
+    Ceil,
+    /// The digits are dropped, rounded toward zero (ex: 2.349 = 2.34, -2.349 = -2.34)
+    Truncate,
 }
 
-impl FormatOption {
-    /// Create a new format option
-    pub fn new(minimum_fraction_digit: u8, maximum_fraction_digit: u8) -> FormatOption {
-        FormatOption {
-            minimum_fraction_digit,
-            maximum_fraction_digit,
-            thousand_grouping: ThousandGrouping::ThreeBlock
+impl RoundingMode {
+    /// Return true if the absolute value of the kept digits has to be increased by one unit of the last digit,
+    /// the dropped digits being the digits after the kept ones
+    fn is_rounded_up(self, kept: &str, dropped: &str, is_negative: bool) -> bool {
+        if dropped.chars().all(|c| c == '0') {
+            return false;
         }
-    }
 
-    /// Change the default grouping
-    pub fn with_grouping(mut self, thousand_grouping: ThousandGrouping) -> Self {
-        self.thousand_grouping = thousand_grouping;
-        self
-    }
-}
+        // Compare the dropped digits to the halfway value (5, 50, 500...)
+        let half = match dropped.split_at(1) {
+            (first, _) if first > "5" => std::cmp::Ordering::Greater,
+            ("5", rest) if rest.chars().any(|c| c != '0') => std::cmp::Ordering::Greater,
+            ("5", _) => std::cmp::Ordering::Equal,
+            _ => std::cmp::Ordering::Less,
+        };
 
-impl Default for FormatOption {
-    fn default() -> Self {
-        Self {
-            minimum_fraction_digit: 2,
-            maximum_fraction_digit: 2,
-            thousand_grouping: ThousandGrouping::ThreeBlock,
+        match (self, half) {
+            (RoundingMode::Truncate, _) => false,
+            (RoundingMode::Floor, _) => is_negative,
+            (RoundingMode::Ceil, _) => !is_negative,
+            (_, std::cmp::Ordering::Greater) => true,
+            (_, std::cmp::Ordering::Less) => false,
+            (RoundingMode::HalfUp, _) => true,
+            (RoundingMode::HalfDown, _) => false,
+            (RoundingMode::HalfEven, _) => kept.ends_with(['1', '3', '5', '7', '9']),
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::NumberCultureSettings;
-use crate::number_to_string::FormatOption;
-use crate::{number_to_string::ToFormat, Culture, errors::ConversionError};
-    use super::Number;
+/// The position of the currency symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyPosition {
+    /// Ex : $1,234.50
+    Before,
+    /// Ex : 1 234,50 €
+    After,
+}
 
-    fn dot_comma() -> NumberCultureSettings {
-        NumberCultureSettings::from((".", ","))
+/// The currency symbol displayed by the "C" formats, and its placement
+/// Ref 'test_to_format_currency'
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Currency {
+    symbol: String,
+    position: CurrencyPosition,
+    /// A space between the number and the symbol
+    space: bool,
+}
+
+impl Currency {
+    /// Create a new currency, without space between the number and the symbol
+    pub fn new(symbol: &str, position: CurrencyPosition) -> Currency {
+        Currency {
+            symbol: String::from(symbol),
+            position,
+            space: false,
+        }
     }
-    fn comma_dot() -> NumberCultureSettings {
-        NumberCultureSettings::from((",", "."))
+
+    /// Set a space between the number and the symbol
+    pub fn with_space(mut self, space: bool) -> Self {
+        self.space = space;
+        self
     }
-    fn comma_dot_grouping_two() -> NumberCultureSettings {
-        NumberCultureSettings::from((",", ".")).with_grouping(crate::ThousandGrouping::TwoBlock)
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
     }
-    fn space_comma() -> NumberCultureSettings {
+
+    pub fn position(&self) -> CurrencyPosition {
+        self.position
+    }
+
+    /// Write the symbol placed before the unsigned formatted number, and its space
+    fn write_before<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        if self.position == CurrencyPosition::Before {
+            w.write_str(&self.symbol)?;
+            if self.space {
+                w.write_char(' ')?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the symbol placed after the unsigned formatted number, and its space
+    fn write_after<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        if self.position == CurrencyPosition::After {
+            if self.space {
+                w.write_char(' ')?;
+            }
+            w.write_str(&self.symbol)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The default currency of the culture
+impl From<Culture> for Currency {
+    fn from(culture: Culture) -> Self {
+        match culture {
+            Culture::English => Currency::new("$", CurrencyPosition::Before),
+            Culture::French => Currency::new("€", CurrencyPosition::After).with_space(true),
+            Culture::Italian => Currency::new("€", CurrencyPosition::After).with_space(true),
+            Culture::Indian => Currency::new("₹", CurrencyPosition::Before),
+            Culture::Turkish => Currency::new("₺", CurrencyPosition::Before),
+        }
+    }
+}
+
+/// The exponent notation used by 'to_format_exponent'
+/// Ref 'test_to_format_exponent'
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExponentFormat {
+    /// The exponent is a multiple of the base : 1 for the scientific notation, 3 for the engineering notation
+    pub base: u32,
+    /// The number of decimals of the significand
+    pub precision: u8,
+    /// Display 'E' instead of 'e'
+    pub capital_e: bool,
+    /// Display the '+' sign of the positive exponents
+    pub always_show_sign: bool,
+}
+
+impl ExponentFormat {
+    /// Scientific notation, one digit before the decimal separator (ex: "1.23E+4")
+    pub fn scientific(precision: u8) -> ExponentFormat {
+        ExponentFormat {
+            base: 1,
+            precision,
+            capital_e: true,
+            always_show_sign: true,
+        }
+    }
+
+    /// Engineering notation, the exponent is a multiple of 3 (ex: "12.345E+3")
+    pub fn engineering(precision: u8) -> ExponentFormat {
+        ExponentFormat {
+            base: 3,
+            ..ExponentFormat::scientific(precision)
+        }
+    }
+
+    /// Display 'E' (default) or 'e'
+    pub fn with_capital_e(mut self, capital_e: bool) -> Self {
+        self.capital_e = capital_e;
+        self
+    }
+
+    /// Display the '+' sign of the positive exponents (default) or not
+    pub fn with_always_show_sign(mut self, always_show_sign: bool) -> Self {
+        self.always_show_sign = always_show_sign;
+        self
+    }
+}
+
+/// A section of a custom pattern (see 'ToFormat::to_format_pattern') : the number format, and the literals around it
+/// Ref 'test_to_format_pattern'
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PatternSection {
+    prefix: String,
+    suffix: String,
+    minimum_integer_digit: u8,
+    minimum_fraction_digit: u8,
+    maximum_fraction_digit: u8,
+    use_grouping: bool,
+    percent: bool,
+}
+
+impl PatternSection {
+    /// The maximum number of decimals, like the N9 format
+    const MAX_FRACTION_DIGIT: u8 = 9;
+
+    /// Parse the sections of the pattern (the negative one is optional)
+    fn parse_pattern(pattern: &str) -> Result<Vec<PatternSection>, ConversionError> {
+        let mut sections = Vec::new();
+        let mut offset = 0;
+        for (index, section) in pattern.split(';').enumerate() {
+            if index > 1 {
+                return Err(ConversionError::InvalidFormatPattern { position: offset - 1 });
+            }
+            sections.push(PatternSection::parse(section, offset)?);
+            offset += section.chars().count() + 1;
+        }
+        Ok(sections)
+    }
+
+    /// Parse a section of the pattern, the offset is the position of the section in the pattern (for the errors)
+    fn parse(section: &str, offset: usize) -> Result<PatternSection, ConversionError> {
+        let chars = section.chars().collect::<Vec<char>>();
+        let invalid = |index: usize| ConversionError::InvalidFormatPattern { position: offset + index };
+        let is_placeholder = |c: &char| ['#', '0', ',', '.'].contains(c);
+
+        let start = chars.iter().position(is_placeholder).ok_or_else(|| invalid(0))?;
+        let end = chars.iter().rposition(is_placeholder).unwrap_or(start);
+        if let Some(index) = (start..=end).find(|index| !is_placeholder(&chars[*index])) {
+            return Err(invalid(index));
+        }
+
+        // The whole part, until the decimal separator
+        let point = (start..=end).find(|index| chars[*index] == '.').unwrap_or(end + 1);
+        let whole = start..point;
+        for index in whole.clone() {
+            let previous = index.checked_sub(1).filter(|previous| whole.contains(previous)).map(|previous| chars[previous]);
+            let next = chars.get(index + 1).filter(|_| whole.contains(&(index + 1)));
+            let misplaced = match chars[index] {
+                // The grouping comma is between two digits
+                ',' => matches!(previous, None | Some(',')) || next.is_none(),
+                '#' => previous == Some('0'),
+                _ => false,
+            };
+            if misplaced {
+                return Err(invalid(index));
+            }
+        }
+
+        // The decimal part, the optional digits are after the required ones
+        let decimal = (point + 1).min(end + 1)..end + 1;
+        for index in decimal.clone() {
+            let misplaced = match chars[index] {
+                '0' => index > decimal.start && chars[index - 1] == '#',
+                '#' => false,
+                _ => true,
+            };
+            if misplaced || index - decimal.start >= PatternSection::MAX_FRACTION_DIGIT as usize {
+                return Err(invalid(index));
+            }
+        }
+
+        let count = |range: std::ops::Range<usize>, c: char| chars[range].iter().filter(|x| **x == c).count();
+        let prefix = chars[..start].iter().collect::<String>();
+        let suffix = chars[end + 1..].iter().collect::<String>();
+        Ok(PatternSection {
+            percent: prefix.contains('%') || suffix.contains('%'),
+            prefix,
+            suffix,
+            minimum_integer_digit: u8::try_from(count(whole.clone(), '0')).map_err(|_| invalid(start))?,
+            minimum_fraction_digit: count(decimal.clone(), '0') as u8,
+            maximum_fraction_digit: decimal.len() as u8,
+            use_grouping: count(whole, ',') > 0,
+        })
+    }
+
+    /// Format the number with the section, without the prefix and the suffix (the number keeps its sign)
+    fn format<T: Num + DecimalString>(&self, number: &Number<T>, culture: Culture) -> Result<String, ConversionError> {
+        let format = FormatOption::new(self.maximum_fraction_digit, self.maximum_fraction_digit)
+            .with_min_integer_digits(self.minimum_integer_digit)
+            .with_use_grouping(self.use_grouping);
+
+        let separators = NumberCultureSettings::from(culture);
+        let formatted = match self.percent {
+            true => Number::<T>::format_digits(&number.percent_string(), separators, format)?,
+            false => number.to_format_options(separators, format)?,
+        };
+
+        // Remove the optional decimals which are 0
+        let decimal_separator = separators.into_decimal_separator_string();
+        match formatted.rsplit_once(decimal_separator.as_str()) {
+            Some((whole, decimal)) if self.minimum_fraction_digit < self.maximum_fraction_digit => {
+                let nb_decimals = decimal.trim_end_matches('0').len().max(self.minimum_fraction_digit as usize);
+                match nb_decimals {
+                    0 => Ok(whole.to_owned()),
+                    _ => Ok(format!("{}{}{}", whole, decimal_separator, &decimal[..nb_decimals])),
+                }
+            }
+            _ => Ok(formatted),
+        }
+    }
+}
+
+/// Structure with the nb decimal required when display a number to string
+#[derive(Debug, Clone)]
+pub struct FormatOption {
+    minimum_fraction_digit: u8,
+    maximum_fraction_digit: u8,
+    thousand_grouping: ThousandGrouping,
+    /// The whole part is padded with leading zeros to have at least this number of digits
+    minimum_integer_digit: u8,
+    /// The character displayed before negative numbers
+    minus_char: char,
+    /// The decimal part is grouped by 3 digits with the thousand separator
+    group_fraction: bool,
+    /// The currency symbol written around the number
+    currency: Option<Currency>,
+    /// The whole part is grouped with the thousand separator
+    use_grouping: bool,
+    /// The '+' sign is displayed before the positive numbers
+    always_show_sign: bool,
+    /// How the digits which are not displayed are rounded
+    rounding_mode: RoundingMode,
+}
+
+impl FormatOption {
+    /// Create a new format option
+    pub fn new(minimum_fraction_digit: u8, maximum_fraction_digit: u8) -> FormatOption {
+        FormatOption {
+            minimum_fraction_digit,
+            maximum_fraction_digit,
+            thousand_grouping: ThousandGrouping::ThreeBlock,
+            minimum_integer_digit: 0,
+            minus_char: '-',
+            group_fraction: false,
+            currency: None,
+            use_grouping: true,
+            always_show_sign: false,
+            rounding_mode: RoundingMode::HalfUp,
+        }
+    }
+
+    /// Change the default grouping
+    pub fn with_grouping(mut self, thousand_grouping: ThousandGrouping) -> Self {
+        self.thousand_grouping = thousand_grouping;
+        self
+    }
+
+    /// Pad the whole part with leading zeros to have at least min_int_digits digits
+    /// Ref 'test_to_format_with_min_integer'
+    pub fn with_min_integer_digits(mut self, min_int_digits: u8) -> Self {
+        self.minimum_integer_digit = min_int_digits;
+        self
+    }
+
+    /// Change the character displayed before negative numbers ('-' by default).
+    /// Use string_to_number::MINUS_SIGN (U+2212) for a typographically correct output, it's accepted when parsing
+    /// Ref 'test_format_minus_char'
+    pub fn with_minus_char(mut self, minus_char: char) -> Self {
+        self.minus_char = minus_char;
+        self
+    }
+
+    /// Group the decimal part by 3 digits with the thousand separator (ex: "3.141 592 653" for engineering displays)
+    /// Ref 'test_to_format_grouped_fraction'
+    pub fn with_group_fraction(mut self, group_fraction: bool) -> Self {
+        self.group_fraction = group_fraction;
+        self
+    }
+
+    /// Display the currency symbol around the number, after the minus sign (ex: "-$1,234.50")
+    /// Ref 'test_to_format_currency'
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Group the whole part with the thousand separator (default), or not for a machine readable output (ex: "1234567,89")
+    /// Ref 'test_format_use_grouping'
+    pub fn with_use_grouping(mut self, use_grouping: bool) -> Self {
+        self.use_grouping = use_grouping;
+        self
+    }
+
+    /// Display the '+' sign before the positive numbers (ex: "+1,234.50"), not before the numbers displayed as zero
+    /// Ref 'test_format_always_show_sign'
+    pub fn with_always_show_sign(mut self, always_show_sign: bool) -> Self {
+        self.always_show_sign = always_show_sign;
+        self
+    }
+
+    /// Change how the digits which are not displayed are rounded (RoundingMode::HalfUp by default)
+    /// Ex : 2.345 with FormatOption::new(2, 2).with_rounding_mode(RoundingMode::HalfEven) = "2.34"
+    /// Ref 'test_rounding_mode'
+    pub fn with_rounding_mode(mut self, rounding_mode: RoundingMode) -> Self {
+        self.rounding_mode = rounding_mode;
+        self
+    }
+}
+
+impl Default for FormatOption {
+    fn default() -> Self {
+        Self {
+            minimum_fraction_digit: 2,
+            maximum_fraction_digit: 2,
+            thousand_grouping: ThousandGrouping::ThreeBlock,
+            minimum_integer_digit: 0,
+            minus_char: '-',
+            group_fraction: false,
+            currency: None,
+            use_grouping: true,
+            always_show_sign: false,
+            rounding_mode: RoundingMode::HalfUp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NumberCultureSettings;
+use crate::number_to_string::{to_format_csv, to_format_json, to_format_list, to_format_list_or_default, to_format_table, to_format_table_with_header, Currency, CurrencyPosition, ExponentFormat, FormatOption, RoundingMode};
+use crate::string_to_number::{NumberConversion, MINUS_SIGN};
+use crate::{number_to_string::ToFormat, Culture, errors::ConversionError};
+    use super::{DecimalString, Number};
+    use num::{rational::Ratio, BigInt};
+
+    fn dot_comma() -> NumberCultureSettings {
+        NumberCultureSettings::from((".", ","))
+    }
+    fn comma_dot() -> NumberCultureSettings {
+        NumberCultureSettings::from((",", "."))
+    }
+    fn comma_dot_grouping_two() -> NumberCultureSettings {
+        NumberCultureSettings::from((",", ".")).with_grouping(crate::ThousandGrouping::TwoBlock)
+    }
+    fn space_comma() -> NumberCultureSettings {
         NumberCultureSettings::from((" ", ","))
     }
     
@@ -354,6 +1388,40 @@ use crate::{number_to_string::ToFormat, Culture, errors::ConversionError};
         }
     }
 
+    /// The halfway values are rounded away from zero, with or without fraction digits
+    #[test]
+    pub fn test_format_n0_rounding() {
+        let values = vec![
+            (0.5f64, "N0", "1"),
+            (1.5, "N0", "2"),
+            (2.5, "N0", "3"),
+            (-2.5, "N0", "-3"),
+            (-1.5, "N0", "-2"),
+            (-0.5, "N0", "-1"),
+            (0.49, "N0", "0"),
+            (-0.49, "N0", "0"),
+            (999.5, "N0", "1,000"),
+            (2_000.98, "N0", "2,001"),
+            (0.125, "N2", "0.13"),
+            (2.345, "N2", "2.35"),
+            (-2.345, "N2", "-2.35"),
+            (0.995, "N2", "1.00"),
+        ];
+
+        for (value, format, expected) in values {
+            assert_eq!(value.to_format(format, Culture::English).unwrap(), expected, "{} {}", value, format);
+        }
+
+        // The whole part is rounded on its digits, without size limit
+        let big = BigInt::from(10).pow(40);
+        let grouped = format!("10{}", ",000".repeat(13));
+        assert_eq!(big.clone().to_format("N0", Culture::English).unwrap(), grouped);
+        assert_eq!((-big.clone()).to_format("N0", Culture::English).unwrap(), format!("-{}", grouped));
+        assert_eq!(Ratio::new(&big * 2 - 1, BigInt::from(2)).to_format("N0", Culture::English).unwrap(), grouped);
+        assert_eq!(Ratio::new(&big * 1000 - 1, BigInt::from(1000)).to_format("N2", Culture::English).unwrap(), format!("{}.00", grouped));
+        assert_eq!(Ratio::new(BigInt::from(1) - &big * 2, BigInt::from(2)).to_format("N0", Culture::English).unwrap(), format!("-{}", grouped));
+    }
+
     /// Test of 'to_format' function to display number to string with float values
     #[test]
     pub fn str_to_format_float_culture() {
@@ -404,12 +1472,854 @@ use crate::{number_to_string::ToFormat, Culture, errors::ConversionError};
 
         for (val_f64, to_format, separator, string_result) in vals_f64 {
             assert_eq!(
-                val_f64.to_format_separators(to_format, separator).expect(format!("Fail to parse {} with separator = {:?}", val_f64, separator).as_str()),
+                val_f64.to_format_separators(to_format, separator).unwrap_or_else(|_| panic!("Fail to parse {} with separator = {:?}", val_f64, separator)),
+                string_result
+            );
+        }
+    }
+
+    /// Test of 'to_format' function with decimal parts starting with zeros
+    #[test]
+    pub fn str_to_format_decimal_leading_zero() {
+        let vals_f64 = vec![
+            (1.05, "N2", Culture::English, "1.05"),
+            (1.05, "N1", Culture::English, "1.1"),
+            (1.05, "N0", Culture::English, "1"),
+            (0.04, "N2", Culture::French, "0,04"),
+            (0.0512, "N2", Culture::French, "0,05"),
+            (1.005, "N2", Culture::Italian, "1,01"),
+            (1000.000001, "N4", Culture::English, "1,000.0000"),
+        ];
+
+        for (val_f64, to_format, culture, string_result) in vals_f64 {
+            assert_eq!(
+                val_f64.to_format(to_format, culture).unwrap(),
                 string_result
             );
         }
     }
 
+    /// Test of 'to_format_with_value' function
+    #[test]
+    pub fn test_to_format_with_value() {
+        assert_eq!(1.005.to_format_with_value("N2", Culture::English).unwrap(), ("1.01".to_owned(), 1.01));
+        assert_eq!(1000.66666.to_format_with_value("N2", Culture::French).unwrap(), ("1 000,67".to_owned(), 1000.67));
+        assert_eq!((-1000.999).to_format_with_value("N2", Culture::French).unwrap(), ("-1 001,00".to_owned(), -1001.0));
+        assert_eq!(2_000.98.to_format_with_value("N0", Culture::Italian).unwrap(), ("2.001".to_owned(), 2001.0));
+        assert_eq!(1000.to_format_with_value("N2", Culture::Indian).unwrap(), ("1,000.00".to_owned(), 1000.0));
+        // The formats which don't parse back in the culture
+        assert_eq!(1234.567.to_format_with_value("C2", Culture::French).unwrap(), ("1 234,57 €".to_owned(), 1234.57));
+        assert_eq!((-1234.567).to_format_with_value("C1", Culture::English).unwrap(), ("-$1,234.6".to_owned(), -1234.6));
+        assert_eq!(1234567.891.to_format_with_value("F1", Culture::Italian).unwrap(), ("1234567,9".to_owned(), 1234567.9));
+        assert_eq!((-0.5).to_format_with_value("N0", Culture::English).unwrap(), ("-1".to_owned(), -1.0));
+        assert_eq!((-0.004).to_format_with_value("N2", Culture::English).unwrap(), ("0.00".to_owned(), 0.0));
+        assert_eq!(
+            0.5.to_format_with_value("P1", Culture::English),
+            Err(ConversionError::UnableToDisplayFormat)
+        );
+        assert_eq!(
+            1000.to_format_with_value("X2", Culture::English),
+            Err(ConversionError::UnableToDisplayFormat)
+        );
+    }
+
+    #[test]
+    pub fn test_to_format_with_min_integer() {
+        let values = vec![
+            (42.0f64, 6, "N2", Culture::English, "000,042.00"),
+            (-42.0, 6, "N2", Culture::English, "-000,042.00"),
+            (42.0, 6, "N0", Culture::French, "000 042"),
+            (42.0, 4, "N1", Culture::Italian, "0.042,0"),
+            (42.0, 3, "N0", Culture::English, "042"),
+            (1234.5, 8, "N2", Culture::Indian, "0,00,01,234.50"),
+            // The rounding is applied before the padding
+            (999.999, 5, "N2", Culture::English, "01,000.00"),
+            (0.5, 3, "N2", Culture::English, "000.50"),
+            // The minimum is lower or equal to the natural number of digits : same as to_format
+            (42.0, 2, "N2", Culture::English, "42.00"),
+            (-1234.5, 4, "N2", Culture::English, "-1,234.50"),
+            (1234.5, 0, "N2", Culture::English, "1,234.50"),
+            (1234.5, 1, "N2", Culture::French, "1 234,50"),
+        ];
+
+        for (number, min_int_digits, format, culture, expected) in values {
+            assert_eq!(
+                number.to_format_with_min_integer(min_int_digits, format, culture).unwrap(),
+                expected
+            );
+            if (number.abs().trunc() as u64).to_string().len() >= min_int_digits as usize {
+                assert_eq!(expected, number.to_format(format, culture).unwrap());
+            }
+        }
+
+        assert_eq!(42.to_format_with_min_integer(6, "N2", Culture::English).unwrap(), "000,042.00");
+        assert_eq!((-42_i64).to_format_with_min_integer(4, "N0", Culture::English).unwrap(), "-0,042");
+        assert_eq!(
+            Number::new(7)
+                .to_format_options(comma_dot(), FormatOption::new(0, 0).with_min_integer_digits(3))
+                .unwrap(),
+            "007"
+        );
+        assert_eq!(
+            42.to_format_with_min_integer(6, "X2", Culture::English),
+            Err(ConversionError::UnableToDisplayFormat)
+        );
+    }
+
+    #[test]
+    pub fn test_to_format_unit() {
+        let values = vec![
+            (1234.5, "N1", Culture::French, "kg", "1 234,5 kg"),
+            (1234.5, "N2", Culture::English, "kg", "1,234.50 kg"),
+            (-1234.5, "N1", Culture::French, "kg", "-1 234,5 kg"),
+            (-12.75, "N2", Culture::Italian, "m", "-12,75 m"),
+            (100000.0, "N0", Culture::Indian, "km/h", "1,00,000 km/h"),
+            (12.0, "N0", Culture::Turkish, "°C", "12 °C"),
+        ];
+
+        for (number, format, culture, unit, expected) in values {
+            assert_eq!(number.to_format_unit(format, culture, unit).unwrap(), expected);
+        }
+
+        assert_eq!(3.to_format_unit("N0", Culture::English, "items").unwrap(), "3 items");
+        assert_eq!(
+            3.to_format_unit("X0", Culture::English, "items"),
+            Err(ConversionError::UnableToDisplayFormat)
+        );
+    }
+
+    #[test]
+    pub fn test_to_format_currency() {
+        let values = vec![
+            (1234.5, Culture::English, "$1,234.50"),
+            (-1234.5, Culture::English, "-$1,234.50"),
+            (1234.5, Culture::French, "1 234,50 €"),
+            (-1234.5, Culture::French, "-1 234,50 €"),
+            (1234.5, Culture::Italian, "1.234,50 €"),
+            (123456.5, Culture::Indian, "₹1,23,456.50"),
+            (-1234.5, Culture::Turkish, "-₺1.234,50"),
+            (0.004, Culture::English, "$0.00"),
+        ];
+        for (number, culture, expected) in values {
+            assert_eq!(number.to_format("C2", culture).unwrap(), expected);
+        }
+        assert_eq!(1000.to_format("C0", Culture::English).unwrap(), "$1,000");
+
+        // Another currency than the default one of the culture
+        let chf = Currency::new("CHF", CurrencyPosition::After).with_space(true);
+        assert_eq!(1234.5.to_format_currency("C2", Culture::French, chf.clone()).unwrap(), "1 234,50 CHF");
+        assert_eq!((-5).to_format_currency("C1", Culture::English, chf).unwrap(), "-5.0 CHF");
+        assert_eq!(
+            1234.5.to_format_currency("C2", Culture::English, Currency::new("€", CurrencyPosition::Before)).unwrap(),
+            "€1,234.50"
+        );
+        assert_eq!(
+            Number::new(-1234.5)
+                .to_format_options(
+                    Culture::English.into(),
+                    FormatOption::new(2, 2).with_currency(Culture::English.into()).with_minus_char(MINUS_SIGN)
+                )
+                .unwrap(),
+            "\u{2212}$1,234.50"
+        );
+
+        // The currency format needs the culture
+        assert_eq!(
+            1234.5.to_format_separators("C2", Culture::English.into()),
+            Err(ConversionError::UnableToDisplayFormat)
+        );
+        assert_eq!(1234.5.to_format("C", Culture::English), Err(ConversionError::UnableToDisplayFormat));
+        assert_eq!(Currency::from(Culture::French).symbol(), "€");
+        assert_eq!(Currency::from(Culture::English).position(), CurrencyPosition::Before);
+    }
+
+    #[test]
+    pub fn test_to_format_percent() {
+        let values = vec![
+            (0.125, "P1", Culture::English, "12.5%"),
+            (0.125, "P1", Culture::French, "12,5 %"),
+            (0.125, "P2", Culture::Italian, "12,50%"),
+            (0.125, "P0", Culture::Turkish, "%13"),
+            (-0.125, "P1", Culture::Turkish, "-%12,5"),
+            (-0.125, "P1", Culture::English, "-12.5%"),
+            // No float error : 0.145 * 100 = 14.499999999999998
+            (0.145, "P0", Culture::English, "15%"),
+            (0.285, "P0", Culture::English, "29%"),
+            (1.005, "P1", Culture::English, "100.5%"),
+            // The thousand grouping applies to the scaled value
+            (12.5, "P0", Culture::French, "1 250 %"),
+            (1234.5, "P0", Culture::Indian, "1,23,450%"),
+            (0.0, "P2", Culture::English, "0.00%"),
+            (-0.001, "P0", Culture::English, "0%"),
+            (0.00001, "P3", Culture::English, "0.001%"),
+        ];
+        for (number, format, culture, expected) in values {
+            assert_eq!(number.to_format(format, culture).unwrap(), expected, "{} {}", number, format);
+        }
+
+        assert_eq!(1.to_format("P0", Culture::English).unwrap(), "100%");
+        assert_eq!((-12).to_format("P1", Culture::French).unwrap(), "-1 200,0 %");
+        assert_eq!(0.5_f32.to_format("P0", Culture::English).unwrap(), "50%");
+        // The scaled digits are not converted to a float
+        assert_eq!(u64::MAX.to_format("P0", Culture::English).unwrap(), "1,844,674,407,370,955,161,500%");
+        assert_eq!(0.5.to_format("P", Culture::English), Err(ConversionError::UnableToDisplayFormat));
+        assert_eq!(0.5.to_format_separators("P0", Culture::English.into()), Err(ConversionError::UnableToDisplayFormat));
+    }
+
+    #[test]
+    pub fn test_to_format_pattern() {
+        let values = vec![
+            // Grouping and decimals
+            (1234567.891, "#,##0.00", Culture::English, "1,234,567.89"),
+            (1234567.891, "#,##0.00", Culture::French, "1 234 567,89"),
+            (1234567.891, "#,##0.00", Culture::Italian, "1.234.567,89"),
+            (1234567.891, "#,##0.00", Culture::Indian, "12,34,567.89"),
+            (1234567.891, "0.00", Culture::French, "1234567,89"),
+            (0.5, "#,##0", Culture::English, "1"),
+            // Zero padding
+            (42.0, "0000", Culture::English, "0042"),
+            (12345.0, "0000", Culture::English, "12345"),
+            (5.0, "#,#00", Culture::English, "05"),
+            // Optional decimals
+            (1234.5, "#,##0.##", Culture::English, "1,234.5"),
+            (1234.0, "#,##0.##", Culture::English, "1,234"),
+            (1234.999, "#,##0.##", Culture::English, "1,235"),
+            (1.5, "0.0#", Culture::French, "1,5"),
+            (1.567, "0.0#", Culture::French, "1,57"),
+            // Negative section
+            (-1.5, "0.00;(0.00)", Culture::English, "(1.50)"),
+            (1.5, "0.00;(0.00)", Culture::English, "1.50"),
+            (-1234.5, "#,##0.00 €;-#,##0.00 € (débit)", Culture::French, "-1 234,50 € (débit)"),
+            (-0.001, "0.00;(0.00)", Culture::English, "0.00"),
+            (-1.5, "0.00", Culture::English, "-1.50"),
+            // Literals and percent
+            (1234.5, "$#,##0.00", Culture::English, "$1,234.50"),
+            (-1234.5, "$#,##0.00", Culture::English, "-$1,234.50"),
+            (0.125, "0.0%", Culture::English, "12.5%"),
+            (0.125, "0.0 %", Culture::French, "12,5 %"),
+        ];
+        for (number, pattern, culture, expected) in values {
+            assert_eq!(number.to_format_pattern(pattern, culture).unwrap(), expected, "{} {}", number, pattern);
+        }
+        assert_eq!(1234567.to_format_pattern("#,##0.00", Culture::English).unwrap(), "1,234,567.00");
+        assert_eq!((-42).to_format_pattern("000;[000]", Culture::English).unwrap(), "[042]");
+
+        // Invalid patterns, with the position of the error
+        let invalid = vec![
+            ("", 0),
+            ("abc", 0),
+            ("0.00.0", 4),
+            ("0 0", 1),
+            (",##0", 0),
+            ("#,,##0", 2),
+            ("#,##0,.00", 5),
+            ("0#", 1),
+            ("0.#0", 3),
+            ("0.0,0", 3),
+            ("0.0000000000", 11),
+            ("0.00;(0.00);0", 11),
+            ("0.00;", 5),
+        ];
+        for (pattern, position) in invalid {
+            assert_eq!(
+                1.5.to_format_pattern(pattern, Culture::English),
+                Err(ConversionError::InvalidFormatPattern { position }),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_to_format_ratio() {
+        let values = vec![
+            // Common fractions
+            (Ratio::new(1, 3), "N4", "0.3333"),
+            (Ratio::new(2, 3), "N4", "0.6667"),
+            (Ratio::new(1, 7), "N6", "0.142857"),
+            (Ratio::new(1, 2), "N2", "0.50"),
+            (Ratio::new(1, 8), "N2", "0.13"),
+            // Improper fractions
+            (Ratio::new(7, 2), "N2", "3.50"),
+            (Ratio::new(10_000, 3), "N1", "3,333.3"),
+            (Ratio::new(5, 1), "N0", "5"),
+            // Negative rationals
+            (Ratio::new(-7, 2), "N2", "-3.50"),
+            (Ratio::new(4, -3), "N3", "-1.333"),
+        ];
+        for (number, format, expected) in values {
+            assert_eq!(number.to_format(format, Culture::English).unwrap(), expected, "{} {}", number, format);
+        }
+
+        assert_eq!(Ratio::new(1, 3).to_format("N2", Culture::French).unwrap(), "0,33");
+        assert_eq!(Ratio::new(1, 8).to_format("P1", Culture::English).unwrap(), "12.5%");
+        assert_eq!(Ratio::new(1, 3).to_format("E2", Culture::English).unwrap(), "3.33E-1");
+
+        // No overflow for the big rationals
+        let big = Ratio::new(BigInt::from(10).pow(30), BigInt::from(3));
+        assert_eq!(big.to_format("N2", Culture::English).unwrap(), "333,333,333,333,333,333,333,333,333,333.33");
+
+        // Only the rationals are expanded, the other numbers keep their display
+        assert_eq!(Ratio::new(1, 4).decimal_string(), "0.25");
+        assert_eq!(Ratio::new_raw(1, -4).decimal_string(), "-0.25");
+        assert_eq!(Ratio::new(-6, 3).decimal_string(), "-2");
+        assert_eq!(1000.5.decimal_string(), "1000.5");
+        assert_eq!((-42i64).decimal_string(), "-42");
+    }
+
+    #[test]
+    pub fn test_to_format_scientific() {
+        let values = vec![
+            (12345.678, "E2", Culture::French, "1,23E+4"),
+            (12345.678, "E2", Culture::English, "1.23E+4"),
+            (12345.678, "E0", Culture::English, "1E+4"),
+            (-12345.678, "E3", Culture::Italian, "-1,235E+4"),
+            (0.000123, "E1", Culture::English, "1.2E-4"),
+            (0.0, "E2", Culture::French, "0,00E+0"),
+            (-0.0, "E2", Culture::English, "0.00E+0"),
+            (f64::MAX, "E2", Culture::English, "1.80E+308"),
+            (f64::MIN_POSITIVE, "E2", Culture::English, "2.23E-308"),
+            // Subnormal numbers
+            (1e-310, "E2", Culture::English, "1.00E-310"),
+            (5e-324, "E2", Culture::English, "4.94E-324"),
+        ];
+        for (number, format, culture, expected) in values {
+            assert_eq!(number.to_format(format, culture).unwrap(), expected, "{} {}", number, format);
+        }
+        assert_eq!(12345.to_format("E2", Culture::English).unwrap(), "1.23E+4");
+        assert_eq!(1.5.to_format("E", Culture::English), Err(ConversionError::UnableToDisplayFormat));
+        assert_eq!(f64::NAN.to_format("E2", Culture::English), Err(ConversionError::UnableToConvertNumberToString));
+
+        // Round trip with the scientific parsing
+        for culture in [Culture::English, Culture::French, Culture::Italian, Culture::Indian, Culture::Turkish] {
+            for number in [12345.678, -0.000123, 1.5e300] {
+                let formatted = number.to_format("E9", culture).unwrap();
+                let parsed = formatted.as_str().to_number_culture::<f64>(culture).unwrap();
+                assert!((parsed - number).abs() <= number.abs() * 1e-9, "{} {:?} {}", formatted, culture, parsed);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_to_format_exponent() {
+        let scientific = ExponentFormat::scientific(2);
+        let engineering = ExponentFormat::engineering(3);
+
+        assert_eq!(12345.to_format_exponent(&scientific, Culture::English).unwrap(), "1.23E+4");
+        assert_eq!(12345.to_format_exponent(&engineering, Culture::English).unwrap(), "12.345E+3");
+        assert_eq!(12345.to_format_exponent(&scientific, Culture::French).unwrap(), "1,23E+4");
+
+        let values = vec![
+            // Negative exponents
+            (0.00123, scientific, "1.23E-3"),
+            (0.00123, engineering, "1.230E-3"),
+            (0.000123, engineering, "123.000E-6"),
+            // Negative significand
+            (-12345.0, scientific, "-1.23E+4"),
+            (-0.05, ExponentFormat::scientific(1), "-5.0E-2"),
+            // Zero
+            (0.0, scientific, "0.00E+0"),
+            (0.0, engineering, "0.000E+0"),
+            // Rounding to the next exponent
+            (9.999, scientific, "1.00E+1"),
+            (999_999.0, ExponentFormat::engineering(2), "1.00E+6"),
+            (1.0, ExponentFormat::scientific(0), "1E+0"),
+            (1.5e300, scientific, "1.50E+300"),
+            // Options
+            (12345.0, scientific.with_capital_e(false), "1.23e+4"),
+            (12345.0, scientific.with_always_show_sign(false), "1.23E4"),
+            (0.00123, scientific.with_always_show_sign(false), "1.23E-3"),
+        ];
+        for (number, format, expected) in values {
+            assert_eq!(number.to_format_exponent(&format, Culture::English).unwrap(), expected, "{} {:?}", number, format);
+        }
+
+        assert_eq!(
+            1.to_format_exponent(&ExponentFormat { base: 0, ..scientific }, Culture::English),
+            Err(ConversionError::UnableToDisplayFormat)
+        );
+        assert_eq!(
+            f64::INFINITY.to_format_exponent(&scientific, Culture::English),
+            Err(ConversionError::UnableToConvertNumberToString)
+        );
+    }
+
+    #[test]
+    pub fn test_to_format_smart() {
+        let values = vec![
+            // Compact notation
+            (12345.0, 3, 10000.0, Culture::English, "12.3K"),
+            (12345.0, 3, 10000.0, Culture::French, "12,3K"),
+            (10000.0, 3, 10000.0, Culture::English, "10.0K"),
+            (999_950.0, 3, 10000.0, Culture::English, "1.00M"),
+            (1_234_567.0, 2, 10000.0, Culture::English, "1.2M"),
+            (-1_234_567.0, 4, 10000.0, Culture::Italian, "-1,235M"),
+            (9_870_000_000.0, 3, 10000.0, Culture::English, "9.87B"),
+            (1_500_000_000_000.0, 2, 10000.0, Culture::English, "1.5T"),
+            (2_500_000_000_000_000.0, 2, 10000.0, Culture::English, "2,500T"),
+            (123_456.0, 3, 1000.0, Culture::English, "123K"),
+            // Fixed-point significant figures
+            (1234.0, 3, 10000.0, Culture::English, "1,230"),
+            (9999.0, 3, 10000.0, Culture::English, "10,000"),
+            (-1234.0, 2, 10000.0, Culture::French, "-1 200"),
+            (0.00123, 3, 10000.0, Culture::English, "0.00123"),
+            (0.001234, 3, 10000.0, Culture::Italian, "0,00123"),
+            (1.5, 3, 10000.0, Culture::English, "1.50"),
+            (12.345, 3, 10000.0, Culture::English, "12.3"),
+            (0.0, 3, 10000.0, Culture::English, "0.00"),
+            (123_456.0, 3, f64::MAX, Culture::Indian, "1,23,000"),
+        ];
+
+        for (number, max_sig_figs, compact_threshold, culture, expected) in values {
+            assert_eq!(
+                number.to_format_smart(max_sig_figs, compact_threshold, culture).unwrap(),
+                expected,
+                "{} with {} sig figs",
+                number,
+                max_sig_figs
+            );
+        }
+
+        assert_eq!(12345.to_format_smart(3, 10000.0, Culture::English).unwrap(), "12.3K");
+        // The decimals needed by the tiny values don't fit in the fixed-point notation
+        assert_eq!(1e-300.to_format_smart(3, 10000.0, Culture::English).unwrap(), "1.00E-300");
+        assert_eq!((-1.234e-280).to_format_smart(2, 10000.0, Culture::French).unwrap(), "-1,2E-280");
+        // The limit is 255 decimals
+        let fixed = 1.5e-253.to_format_smart(3, 10000.0, Culture::English).unwrap();
+        assert_eq!(fixed.len(), "0.".len() + 255);
+        assert!(fixed.ends_with("150"));
+        assert_eq!(1.5e-254.to_format_smart(3, 10000.0, Culture::English).unwrap(), "1.50E-254");
+        assert_eq!(1234_u32.to_format_smart(3, 10000.0, Culture::English).unwrap(), "1,230");
+        assert_eq!(
+            12345.to_format_smart(0, 10000.0, Culture::English),
+            Err(ConversionError::UnableToDisplayFormat)
+        );
+        assert_eq!(
+            f64::INFINITY.to_format_smart(3, 10000.0, Culture::English),
+            Err(ConversionError::UnableToConvertNumberToString)
+        );
+    }
+
+    #[test]
+    pub fn test_format_minus_char() {
+        let minus = |format: FormatOption| format.with_minus_char(MINUS_SIGN);
+        let values = vec![
+            (-1000.0, space_comma(), minus(FormatOption::new(0, 0)), "\u{2212}1 000"),
+            (-1234.567, comma_dot(), minus(FormatOption::new(2, 2)), "\u{2212}1,234.57"),
+            (-999.999, dot_comma(), minus(FormatOption::new(2, 2)), "\u{2212}1.000,00"),
+            (-42.0, comma_dot(), minus(FormatOption::new(0, 0).with_min_integer_digits(4)), "\u{2212}0,042"),
+            (1000.0, space_comma(), minus(FormatOption::new(0, 0)), "1 000"),
+            (-1000.0, space_comma(), FormatOption::new(0, 0), "-1 000"),
+        ];
+
+        for (number, separators, format, expected) in values {
+            assert_eq!(Number::new(number).to_format_options(separators, format).unwrap(), expected);
+        }
+
+        // The output can be read back
+        let number_string = Number::new(-1000)
+            .to_format_options(Culture::French.into(), minus(FormatOption::new(0, 0)))
+            .unwrap();
+        assert_eq!(number_string.as_str().to_number_culture::<i32>(Culture::French).unwrap(), -1000);
+    }
+
+    #[test]
+    pub fn test_to_format_table() {
+        assert_eq!(
+            to_format_table(&[1000.0, 2.5, 1234567.891], "N2", Culture::English),
+            vec!["    1,000.00", "        2.50", "1,234,567.89"]
+        );
+
+        // The minus sign is included in the width
+        assert_eq!(to_format_table(&[-1, 10, -100], "N0", Culture::English), vec!["  -1", "  10", "-100"]);
+        assert_eq!(to_format_table(&[-1000.5, 1000.5], "N1", Culture::Italian), vec!["-1.000,5", " 1.000,5"]);
+        assert_eq!(to_format_table(&[1.0, f64::NAN], "N2", Culture::English), vec!["1.00", " NaN"]);
+        assert_eq!(to_format_table::<i32>(&[], "N2", Culture::English), Vec::<String>::new());
+
+        let column = to_format_table(&[1000000.0, -2.5, 42.0], "N2", Culture::French);
+        let width = column[0].chars().count();
+        assert!(column.iter().all(|cell| cell.chars().count() == width));
+        assert_eq!(column[1].trim_start(), "-2,50");
+
+        // The header is centered over the column, the extra space goes to the right
+        assert_eq!(
+            to_format_table_with_header("Total", &[1000.0, -2.5], "N2", Culture::English),
+            (" Total  ".to_owned(), vec!["1,000.00".to_owned(), "   -2.50".to_owned()])
+        );
+        // The column is widened to the header
+        assert_eq!(
+            to_format_table_with_header("Amount", &[1, -22], "N0", Culture::English),
+            ("Amount".to_owned(), vec!["     1".to_owned(), "   -22".to_owned()])
+        );
+        assert_eq!(
+            to_format_table_with_header::<i32>("Empty", &[], "N0", Culture::English),
+            ("Empty".to_owned(), vec![])
+        );
+    }
+
+    #[test]
+    pub fn test_to_format_options_trait() {
+        let values = vec![
+            (1.5, FormatOption::new(2, 4), Culture::French, "1,50"),
+            (1.56789, FormatOption::new(2, 4), Culture::French, "1,5679"),
+            (1.567, FormatOption::new(2, 4), Culture::French, "1,567"),
+            (1234.5, FormatOption::new(2, 2), Culture::English, "1,234.50"),
+            (1234.5, FormatOption::new(0, 0), Culture::English, "1,235"),
+            (1234567.5, FormatOption::new(1, 3).with_use_grouping(false), Culture::Italian, "1234567,5"),
+            (-42.0, FormatOption::new(1, 1).with_min_integer_digits(4), Culture::English, "-0,042.0"),
+            (1234.5, FormatOption::new(2, 2).with_currency(Culture::French.into()), Culture::French, "1 234,50 €"),
+        ];
+        for (number, options, culture, expected) in values {
+            assert_eq!(ToFormat::to_format_options(number, culture, options).unwrap(), expected);
+        }
+
+        // Same result as the Number wrapper
+        assert_eq!(
+            1000000.to_format_options(Culture::Indian, FormatOption::new(2, 2)).unwrap(),
+            Number::new(1000000).to_format_options(Culture::Indian.into(), FormatOption::new(2, 2)).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_format_use_grouping() {
+        let values = vec![
+            (1234567.891, Culture::French, "F2", "1234567,89"),
+            (1234567.891, Culture::English, "F2", "1234567.89"),
+            (-1234567.891, Culture::Italian, "F1", "-1234567,9"),
+            (1234567.0, Culture::Indian, "F0", "1234567"),
+            (999.999, Culture::English, "F2", "1000.00"),
+            (1234567.891, Culture::French, "N2", "1 234 567,89"),
+        ];
+        for (number, culture, format, expected) in values {
+            assert_eq!(number.to_format(format, culture).unwrap(), expected);
+        }
+        assert_eq!(1234567.to_format("F0", Culture::Indian).unwrap(), "1234567");
+        assert_eq!(1234.5.to_format_separators("F1", comma_dot()).unwrap(), "1234.5");
+        assert_eq!(1.5.to_format("F", Culture::English), Err(ConversionError::UnableToDisplayFormat));
+        assert_eq!(1.5.to_format("F10", Culture::English), Err(ConversionError::UnableToDisplayFormat));
+
+        // With the format options
+        let ungrouped = FormatOption::new(2, 2).with_use_grouping(false);
+        assert_eq!(Number::new(1234567.891).to_format_options(Culture::French.into(), ungrouped).unwrap(), "1234567,89");
+        assert_eq!(
+            Number::new(42).to_format_options(comma_dot(), FormatOption::new(0, 0).with_use_grouping(false).with_min_integer_digits(6)).unwrap(),
+            "000042"
+        );
+        assert_eq!(Number::new(1234567).to_format_options(comma_dot(), FormatOption::new(0, 0)).unwrap(), "1,234,567");
+
+        // The output can be read back
+        let number_string = 1234567.891.to_format("F2", Culture::French).unwrap();
+        assert_eq!(number_string.as_str().to_number_culture::<f64>(Culture::French), Ok(1234567.89));
+    }
+
+    #[test]
+    pub fn test_format_always_show_sign() {
+        let plus = |format: FormatOption| format.with_always_show_sign(true);
+        let values = vec![
+            (1234.5, comma_dot(), plus(FormatOption::new(2, 2)), "+1,234.50"),
+            (-1234.5, comma_dot(), plus(FormatOption::new(2, 2)), "-1,234.50"),
+            (1000.0, space_comma(), plus(FormatOption::new(0, 0)), "+1 000"),
+            (0.004, comma_dot(), plus(FormatOption::new(2, 2)), "0.00"),
+            (0.0, comma_dot(), plus(FormatOption::new(0, 0)), "0"),
+            (0.5, comma_dot(), plus(FormatOption::new(1, 1)), "+0.5"),
+            (-1000.0, space_comma(), plus(FormatOption::new(0, 0)).with_minus_char(MINUS_SIGN), "\u{2212}1 000"),
+            (1234.5, comma_dot(), plus(FormatOption::new(2, 2)).with_currency(Culture::English.into()), "+$1,234.50"),
+            (1234.5, comma_dot(), FormatOption::new(2, 2), "1,234.50"),
+        ];
+
+        for (number, separators, format, expected) in values {
+            assert_eq!(Number::new(number).to_format_options(separators, format).unwrap(), expected);
+        }
+        assert_eq!(Number::new(42u32).to_format_options(Culture::French.into(), plus(FormatOption::new(0, 0))).unwrap(), "+42");
+
+        // The explicit plus sign is read back, for all the cultures
+        for culture in Culture::all() {
+            for number in [1234.5, -1234.5] {
+                let number_string = Number::new(number).to_format_options(culture.into(), plus(FormatOption::new(1, 1))).unwrap();
+                assert_eq!(number_string.as_str().to_number_culture::<f64>(culture), Ok(number), "{}", number_string);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_write_format() {
+        let mut buffer = String::new();
+        1234.5.write_format(&mut buffer, "N2", Culture::French).unwrap();
+        buffer.push_str(" / ");
+        (-1000).write_format(&mut buffer, "N0", Culture::English).unwrap();
+        buffer.push_str(" / ");
+        999.999.write_format(&mut buffer, "N2", Culture::Indian).unwrap();
+        assert_eq!(buffer, "1 234,50 / -1,000 / 1,000.00");
+
+        // The currency, percent, scientific and fixed formats are written too
+        let mut buffer = String::new();
+        1234.5.write_format(&mut buffer, "C2", Culture::English).unwrap();
+        buffer.push_str(" / ");
+        0.125.write_format(&mut buffer, "P1", Culture::French).unwrap();
+        buffer.push_str(" / ");
+        12345.write_format(&mut buffer, "E2", Culture::English).unwrap();
+        buffer.push_str(" / ");
+        1234.5.write_format(&mut buffer, "F1", Culture::English).unwrap();
+        assert_eq!(buffer, "$1,234.50 / 12,5 % / 1.23E+4 / 1234.5");
+
+        let mut bytes: Vec<u8> = Vec::new();
+        100000.write_format_bytes(&mut bytes, "N1", Culture::Indian).unwrap();
+        bytes.push(b';');
+        0.5.write_format_bytes(&mut bytes, "N0", Culture::Italian).unwrap();
+        assert_eq!(String::from_utf8(bytes).unwrap(), "1,00,000.0;1");
+
+        // Same result as to_format
+        for (number, format, culture) in [
+            (1000.66666, "N3", Culture::Italian),
+            (-2.5, "N0", Culture::French),
+            (42.0, "N9", Culture::Turkish),
+            (-1234.5, "C2", Culture::French),
+            (0.145, "P0", Culture::English),
+            (-0.125, "P1", Culture::Turkish),
+            (12345.0, "E2", Culture::English),
+            (1234567.891, "F2", Culture::Indian),
+        ] {
+            let mut buffer = String::new();
+            number.write_format(&mut buffer, format, culture).unwrap();
+            assert_eq!(buffer, number.to_format(format, culture).unwrap());
+        }
+
+        // Every piece of the format is written : sign, currency, padding, grouping
+        let options = FormatOption::new(2, 2)
+            .with_min_integer_digits(5)
+            .with_currency(Currency::from(Culture::French))
+            .with_always_show_sign(true);
+        for (number, expected) in [(1234.56789, "+01 234,57 €"), (-0.5, "-00 000,50 €"), (-0.001, "00 000,00 €"), (0.0, "00 000,00 €")] {
+            let mut buffer = String::new();
+            Number::new(number).write_options(&mut buffer, Culture::French.into(), options.clone()).unwrap();
+            assert_eq!(buffer, expected);
+        }
+        let mut buffer = String::new();
+        let options = FormatOption::new(4, 4).with_group_fraction(true).with_currency(Currency::from(Culture::English));
+        Number::new(-1234.5).write_options(&mut buffer, Culture::English.into(), options).unwrap();
+        assert_eq!(buffer, "-$1,234.500,0");
+
+        // Nothing is written when the format is invalid
+        let mut buffer = String::new();
+        assert_eq!(1.write_format(&mut buffer, "X2", Culture::English), Err(ConversionError::UnableToDisplayFormat));
+        assert!(buffer.is_empty());
+
+        // The write error is reported
+        let mut full: [u8; 2] = [0; 2];
+        assert_eq!(
+            1000.write_format_bytes(&mut full.as_mut_slice(), "N0", Culture::English),
+            Err(ConversionError::WriteFailed)
+        );
+    }
+
+    /// References are dereferenced by the method call, the numbers are Copy
+    #[test]
+    pub fn str_to_format_reference() {
+        let big: &u64 = &1_000_000;
+        assert_eq!(big.to_format("N0", Culture::English).unwrap(), "1,000,000");
+        let negative: &f32 = &-1_000.5;
+        assert_eq!(negative.to_format("N1", Culture::French).unwrap(), "-1 000,5");
+
+        let max: &u128 = &u128::MAX;
+        assert_eq!(
+            max.to_format("N0", Culture::English).unwrap(),
+            "340,282,366,920,938,463,463,374,607,431,768,211,455"
+        );
+        let min: &i128 = &i128::MIN;
+        assert_eq!(
+            min.to_format("N0", Culture::Indian).unwrap(),
+            "-17,01,41,18,34,60,46,92,31,73,16,87,30,37,15,88,41,05,728"
+        );
+        assert_eq!(
+            1_000_000_000_000_000_000_000_u128.to_format("N2", Culture::Italian).unwrap(),
+            "1.000.000.000.000.000.000.000,00"
+        );
+
+        // Nanosecond counts, formatted from a collection without dereferencing
+        let durations: Vec<u64> = vec![1_500, 2_000_000, 3_000_000_000];
+        let formatted = durations
+            .iter()
+            .map(|d| d.to_format("N0", Culture::French).unwrap())
+            .collect::<Vec<String>>();
+        assert_eq!(formatted, vec!["1 500", "2 000 000", "3 000 000 000"]);
+
+        let number = &1234.5;
+        assert_eq!(number.to_format_unit("N1", Culture::English, "ns").unwrap(), "1,234.5 ns");
+        assert_eq!(number.to_format_with_min_integer(5, "N0", Culture::English).unwrap(), "01,235");
+        assert_eq!(number.to_format_smart(2, 1000.0, Culture::English).unwrap(), "1.2K");
+        assert_eq!(number.to_format_with_value("N0", Culture::English).unwrap(), ("1,235".to_owned(), 1235.0));
+        assert_eq!(number.to_format_separators("N1", space_comma()).unwrap(), "1 234,5");
+    }
+
+    /// The whole part is parsed directly, without the culture machinery, so any integer size is supported
+    #[test]
+    pub fn str_to_format_large_numbers() {
+        assert_eq!(i64::MAX.to_format("N0", Culture::English).unwrap(), "9,223,372,036,854,775,807");
+        assert_eq!(i64::MIN.to_format("N0", Culture::French).unwrap(), "-9 223 372 036 854 775 808");
+        assert_eq!(u64::MAX.to_format("N2", Culture::Italian).unwrap(), "18.446.744.073.709.551.615,00");
+        assert_eq!(3_000_000_000_u32.to_format("N0", Culture::Indian).unwrap(), "3,00,00,00,000");
+        assert_eq!(9_999_999_999.5.to_format("N0", Culture::English).unwrap(), "10,000,000,000");
+        assert_eq!(9_999_999_999.999.to_format("N2", Culture::English).unwrap(), "10,000,000,000.00");
+    }
+
+    /// No thousand separator below 1000, for both signs
+    #[test]
+    pub fn str_to_format_thousand_boundary() {
+        let values = vec![
+            (Culture::English, "999", "1,000"),
+            (Culture::French, "999", "1 000"),
+            (Culture::Italian, "999", "1.000"),
+            (Culture::Indian, "999", "1,000"),
+            (Culture::Turkish, "999", "1.000"),
+        ];
+
+        for (culture, below, above) in values {
+            assert_eq!(999.to_format("N0", culture).unwrap(), below);
+            assert_eq!((-999).to_format("N0", culture).unwrap(), format!("-{}", below));
+            assert_eq!(1000.to_format("N0", culture).unwrap(), above);
+            assert_eq!((-1000).to_format("N0", culture).unwrap(), format!("-{}", above));
+
+            // Same boundary when the decimal part is rounded up
+            assert_eq!(999.4.to_format("N0", culture).unwrap(), below);
+            assert_eq!((-999.4).to_format("N0", culture).unwrap(), format!("-{}", below));
+            assert_eq!(999.5.to_format("N0", culture).unwrap(), above);
+            assert_eq!((-999.5).to_format("N0", culture).unwrap(), format!("-{}", above));
+        }
+
+        assert_eq!((-99).to_format("N2", Culture::English).unwrap(), "-99.00");
+        assert_eq!((-999.999).to_format("N2", Culture::English).unwrap(), "-1,000.00");
+        assert_eq!((-100_000).to_format("N0", Culture::Indian).unwrap(), "-1,00,000");
+    }
+
+    #[test]
+    #[allow(clippy::inconsistent_digit_grouping)]
+    pub fn test_format_indian_magnitudes() {
+        // The leftmost group has 1 or 2 digits, the others 2 digits, except the last one (3 digits)
+        let values = vec![
+            (1_000_i64, "1,000"),
+            (10_000, "10,000"),
+            (1_00_000, "1,00,000"),         // 1 lakh
+            (10_00_000, "10,00,000"),       // 10 lakh
+            (1_00_00_000, "1,00,00,000"),   // 1 crore
+            (10_00_00_000, "10,00,00,000"), // 10 crore
+            (1_00_00_00_000, "1,00,00,00,000"),
+            (12_34_56_789, "12,34,56,789"),
+            (1_23_45_678, "1,23,45,678"),
+        ];
+
+        for (number, expected) in values {
+            assert_eq!(number.to_format("N0", Culture::Indian).unwrap(), expected);
+            assert_eq!((-number).to_format("N0", Culture::Indian).unwrap(), format!("-{}", expected));
+            assert_eq!((number as f64 + 0.25).to_format("N2", Culture::Indian).unwrap(), format!("{}.25", expected));
+            assert_eq!(expected.to_number_validated::<i64>(Culture::Indian), Ok(number));
+        }
+
+        // Rounding up to the next lakh and crore
+        assert_eq!(99_999.5.to_format("N0", Culture::Indian).unwrap(), "1,00,000");
+        assert_eq!(99_99_999.999.to_format("N2", Culture::Indian).unwrap(), "1,00,00,000.00");
+        assert_eq!((-9_99_999.5).to_format("N0", Culture::Indian).unwrap(), "-10,00,000");
+
+        // The padding and the minus char don't shift the groups
+        assert_eq!(
+            Number::new(-1_00_000)
+                .to_format_options(Culture::Indian.into(), FormatOption::new(0, 0).with_minus_char(MINUS_SIGN))
+                .unwrap(),
+            "\u{2212}1,00,000"
+        );
+        assert_eq!(1_00_000.to_format_with_min_integer(8, "N0", Culture::Indian).unwrap(), "0,01,00,000");
+    }
+
+    #[test]
+    pub fn test_to_format_json() {
+        assert_eq!(to_format_json(1000).unwrap(), "1000");
+        assert_eq!(to_format_json(-1000.5).unwrap(), "-1000.5");
+        assert_eq!(to_format_json(0.25f32).unwrap(), "0.25");
+        assert_eq!(to_format_json(1e21).unwrap(), "1000000000000000000000");
+        assert_eq!(to_format_json(u64::MAX).unwrap(), "18446744073709551615");
+        // The rationals are written as decimal numbers, not as a fraction
+        assert_eq!(to_format_json(Ratio::new(-3, 4)).unwrap(), "-0.75");
+        assert_eq!(to_format_json(Ratio::new(10, 2)).unwrap(), "5");
+
+        assert_eq!(to_format_json(f64::NAN), Err(ConversionError::UnableToConvertNumberToString));
+        assert_eq!(to_format_json(f64::INFINITY), Err(ConversionError::UnableToConvertNumberToString));
+        assert_eq!(to_format_json(f32::NEG_INFINITY), Err(ConversionError::UnableToConvertNumberToString));
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    pub fn test_to_format_grouped_fraction() {
+        let values = vec![
+            (3.141592653f64, "N9", Culture::French, "3,141 592 653"),
+            (3.141592653, "N9", Culture::English, "3.141,592,653"),
+            (3.14159, "N5", Culture::French, "3,141 59"),
+            (3.1415, "N4", Culture::French, "3,141 5"),
+            (3.141, "N3", Culture::French, "3,141"),
+            (3.14, "N2", Culture::French, "3,14"),
+            (1234.5678, "N4", Culture::Italian, "1.234,567.8"),
+            (-1234.12345678, "N8", Culture::French, "-1 234,123 456 78"),
+            (3.9, "N0", Culture::French, "4"),
+        ];
+
+        for (value, format, culture, expected) in values {
+            assert_eq!(value.to_format_grouped_fraction(format, culture).unwrap(), expected);
+        }
+        assert_eq!(1.to_format_grouped_fraction("N6", Culture::French).unwrap(), "1,000 000");
+
+        // Also available with the format option
+        assert_eq!(
+            Number::new(0.000001).to_format_options(NumberCultureSettings::from((" ", ".")), FormatOption::new(6, 6).with_group_fraction(true)),
+            Ok("0.000 001".to_owned())
+        );
+    }
+
+    #[test]
+    pub fn test_to_format_list() {
+        assert_eq!(
+            to_format_list(&[1000.0, 2000.5, 3000.0], ", ", "N2", Culture::English).unwrap(),
+            "1,000.00, 2,000.50, 3,000.00"
+        );
+        assert_eq!(to_format_list(&[-1000, 25], " ; ", "N0", Culture::French).unwrap(), "-1 000 ; 25");
+        assert_eq!(to_format_list(&[1234.5], ", ", "N1", Culture::Italian).unwrap(), "1.234,5");
+        assert_eq!(to_format_list::<f64>(&[], ", ", "N2", Culture::English).unwrap(), "");
+
+        // The first failing element is returned
+        assert_eq!(
+            to_format_list(&[1.0, f64::NAN, f64::INFINITY], ", ", "N2", Culture::English),
+            Err(ConversionError::ListElement {
+                index: 1,
+                error: Box::new(ConversionError::NotCaptureFoundWhenConvertNumberToString)
+            })
+        );
+        assert_eq!(
+            to_format_list(&[1, 2], ", ", "X2", Culture::English),
+            Err(ConversionError::ListElement { index: 0, error: Box::new(ConversionError::UnableToDisplayFormat) })
+        );
+
+        assert_eq!(
+            to_format_list_or_default(&[1000.0, f64::NAN, -2.5], ", ", "N2", Culture::English, "-"),
+            "1,000.00, -, -2.50"
+        );
+        assert_eq!(to_format_list_or_default::<i32>(&[], ", ", "N2", Culture::English, "-"), "");
+        assert_eq!(to_format_list_or_default(&[1, 2], "/", "X2", Culture::English, "?"), "?/?");
+    }
+
+    #[test]
+    pub fn test_to_format_csv() {
+        // The comma of the english thousand separator would corrupt the CSV without quotes
+        assert_eq!(to_format_csv(1000.5, true, Culture::English).unwrap(), "\"1,000.5\"");
+        assert_eq!(to_format_csv(1000.5, false, Culture::English).unwrap(), "1,000.5");
+        assert_eq!(to_format_csv(-1000000, true, Culture::English).unwrap(), "\"-1,000,000\"");
+        assert_eq!(to_format_csv(10.25, true, Culture::French).unwrap(), "\"10,25\"");
+        assert_eq!(to_format_csv(-1000, true, Culture::French).unwrap(), "\"-1 000\"");
+        assert_eq!(to_format_csv(1000.125, true, Culture::Italian).unwrap(), "\"1.000,125\"");
+
+        // No separator, nothing to quote
+        assert_eq!(to_format_csv(10, true, Culture::English).unwrap(), "10");
+        assert_eq!(to_format_csv(-999, true, Culture::French).unwrap(), "-999");
+
+        assert!(to_format_csv(f64::NAN, true, Culture::English).is_err());
+    }
+
     #[test]
     pub fn test_round_format() {
         assert_eq!(1000.66666.to_format("N2", Culture::French).unwrap(), "1 000,67");
@@ -419,9 +2329,35 @@ use crate::{number_to_string::ToFormat, Culture, errors::ConversionError};
         assert_eq!((-1000.999).to_format("N2", Culture::French).unwrap(), "-1 001,00");
     }
 
-    /// Test of 'apply_decimal_format' function
+    /// Test of 'apply_decimal_format' and 'apply_decimal_digits' functions
     #[test]
     pub fn test_apply_decimal() {
+        let list = vec![
+            ("2", FormatOption::new(4, 4), "2000"),
+            ("265556", FormatOption::new(2, 2), "27"),
+            ("512", FormatOption::new(2, 4), "512"),
+            ("512", FormatOption::new(2, 2), "51"),
+            ("512", FormatOption::new(5, 5), "51200"),
+            ("05", FormatOption::new(2, 2), "05"),
+            ("005", FormatOption::new(2, 2), "01"),
+            ("0049", FormatOption::new(2, 2), "00"),
+            ("0512", FormatOption::new(2, 2), "05"),
+        ];
+
+        for (decimal_value, format, decimal_string) in list {
+            assert_eq!(
+                Number::<i32>::apply_decimal_digits(decimal_value, format).unwrap().0,
+                decimal_string
+            );
+        }
+
+        // The whole part need to be rounded up
+        assert_eq!(
+            Number::<i32>::apply_decimal_digits("996", FormatOption::new(2, 2)),
+            Some(("00".to_owned(), true))
+        );
+
+        // The decimal part given as an integer, without leading zeros
         let list = vec![
             (2, FormatOption::new(4, 4), "2000"),
             (265556, FormatOption::new(2, 2), "27"),
@@ -429,13 +2365,258 @@ use crate::{number_to_string::ToFormat, Culture, errors::ConversionError};
             (512, FormatOption::new(2, 2), "51"),
             (512, FormatOption::new(5, 5), "51200"),
         ];
-
         for (decimal_value, format, decimal_string) in list {
+            assert_eq!(Number::<i32>::apply_decimal_format(decimal_value, format).unwrap().0, decimal_string);
+        }
+        assert_eq!(Number::<i32>::apply_decimal_format(996, FormatOption::new(2, 2)), Some(("00".to_owned(), true)));
+        assert_eq!(Number::<i32>::apply_decimal_format(5, FormatOption::new(0, 0)), None);
+    }
+
+    /// The minus sign is kept for a zero whole part, and not displayed when the number is rounded to zero
+    #[test]
+    pub fn test_format_negative_zero_whole() {
+        let values = vec![
+            (-0.5, "N2", Culture::French, "-0,50"),
+            (-0.5, "N2", Culture::English, "-0.50"),
+            (-0.5, "N1", Culture::Italian, "-0,5"),
+            (-0.5, "N2", Culture::Indian, "-0.50"),
+            (-0.5, "N0", Culture::Turkish, "-1"),
+            (-0.05, "N2", Culture::French, "-0,05"),
+            (-0.05, "N2", Culture::English, "-0.05"),
+            (-0.05, "N1", Culture::Italian, "-0,1"),
+            (-0.05, "N3", Culture::Indian, "-0.050"),
+            (-0.004, "N3", Culture::French, "-0,004"),
+            (-0.004, "N2", Culture::French, "0,00"),
+            (-0.004, "N2", Culture::English, "0.00"),
+            (-0.004, "N0", Culture::Italian, "0"),
+            (-0.0, "N2", Culture::English, "0.00"),
+            // Through the currency, percent, fixed and custom formats
+            (-0.5, "C2", Culture::English, "-$0.50"),
+            (-0.5, "C2", Culture::French, "-0,50 €"),
+            (-0.004, "C2", Culture::English, "$0.00"),
+            (-0.001, "P1", Culture::English, "-0.1%"),
+            (-0.004, "P1", Culture::Turkish, "-%0,4"),
+            (-0.5, "F2", Culture::English, "-0.50"),
+        ];
+        for (number, format, culture, expected) in values {
+            assert_eq!(number.to_format(format, culture).unwrap(), expected, "{} {} {:?}", number, format, culture);
+        }
+
+        assert_eq!((-0.5).to_format_pattern("0.00;(0.00)", Culture::English).unwrap(), "(0.50)");
+        assert_eq!((-0.5).to_format_options(Culture::English, FormatOption::new(2, 2).with_always_show_sign(true)).unwrap(), "-0.50");
+        assert_eq!((-0.5).to_format_options(Culture::English, FormatOption::new(2, 2).with_minus_char(MINUS_SIGN)).unwrap(), "\u{2212}0.50");
+        assert_eq!((-0.5).to_format_with_min_integer(3, "N1", Culture::English).unwrap(), "-000.5");
+        assert_eq!(to_format_table(&[-0.5, 10.0], "N2", Culture::English), vec!["-0.50", "10.00"]);
+
+        // The rationals
+        assert_eq!(num::rational::Ratio::new(-1, 3).to_format("N4", Culture::English).unwrap(), "-0.3333");
+        assert_eq!(num::rational::Ratio::new(-1, 1000).to_format("P1", Culture::English).unwrap(), "-0.1%");
+
+        // Round trip
+        assert_eq!("-0,50".to_number_culture::<f64>(Culture::French).unwrap(), -0.5);
+    }
+
+    /// The two-block grouping starts after the first 3 digits, so the numbers below 1,000 are never grouped
+    #[test]
+    pub fn test_format_indian_small_numbers() {
+        let values = vec![
+            (1, "1", "1.00"),
+            (12, "12", "12.00"),
+            (123, "123", "123.00"),
+            (1234, "1,234", "1,234.00"),
+            (12345, "12,345", "12,345.00"),
+            (123456, "1,23,456", "1,23,456.00"),
+            (-1, "-1", "-1.00"),
+            (-12, "-12", "-12.00"),
+            (-123, "-123", "-123.00"),
+            (-1234, "-1,234", "-1,234.00"),
+            (-12345, "-12,345", "-12,345.00"),
+        ];
+        for (number, n0, n2) in values {
+            assert_eq!(number.to_format("N0", Culture::Indian).unwrap(), n0, "{}", number);
+            assert_eq!(number.to_format("N2", Culture::Indian).unwrap(), n2, "{}", number);
+            assert_eq!((number as f64).to_format("N2", Culture::Indian).unwrap(), n2, "{}", number);
+        }
+
+        // With a decimal part
+        assert_eq!(123.5.to_format("N1", Culture::Indian).unwrap(), "123.5");
+        assert_eq!(1234.5.to_format("N1", Culture::Indian).unwrap(), "1,234.5");
+        assert_eq!((-12.25).to_format("N2", Culture::Indian).unwrap(), "-12.25");
+
+        // The rounding reaches the next group
+        assert_eq!(999.5.to_format("N0", Culture::Indian).unwrap(), "1,000");
+        assert_eq!(9999.5.to_format("N0", Culture::Indian).unwrap(), "10,000");
+        assert_eq!(99999.5.to_format("N0", Culture::Indian).unwrap(), "1,00,000");
+
+        // The zeros of the padding are grouped like the digits
+        assert_eq!(12.to_format_with_min_integer(3, "N0", Culture::Indian).unwrap(), "012");
+        assert_eq!(12.to_format_with_min_integer(4, "N0", Culture::Indian).unwrap(), "0,012");
+    }
+
+    #[test]
+    pub fn test_rounding_mode() {
+        let format = |number: f64, decimals: u8, rounding_mode: RoundingMode| {
+            Number::new(number)
+                .to_format_options(Culture::French.into(), FormatOption::new(decimals, decimals).with_rounding_mode(rounding_mode))
+                .unwrap()
+        };
+
+        // The tie cases
+        let values = vec![
+            (2.345, RoundingMode::HalfUp, "2,35"),
+            (2.345, RoundingMode::HalfEven, "2,34"),
+            (2.355, RoundingMode::HalfEven, "2,36"),
+            (2.345, RoundingMode::HalfDown, "2,34"),
+            (-2.345, RoundingMode::HalfUp, "-2,35"),
+            (-2.345, RoundingMode::HalfEven, "-2,34"),
+            (-2.355, RoundingMode::HalfEven, "-2,36"),
+            (-2.345, RoundingMode::HalfDown, "-2,34"),
+            (2.345, RoundingMode::Floor, "2,34"),
+            (-2.345, RoundingMode::Floor, "-2,35"),
+            (2.345, RoundingMode::Ceil, "2,35"),
+            (-2.345, RoundingMode::Ceil, "-2,34"),
+            (2.345, RoundingMode::Truncate, "2,34"),
+            (-2.345, RoundingMode::Truncate, "-2,34"),
+            // Above the halfway value, only the modes toward zero or an infinity differ
+            (2.3451, RoundingMode::HalfEven, "2,35"),
+            (2.3451, RoundingMode::HalfDown, "2,35"),
+            (2.349, RoundingMode::Truncate, "2,34"),
+            (-2.341, RoundingMode::Floor, "-2,35"),
+            (2.341, RoundingMode::Ceil, "2,35"),
+            // Exact values are never rounded
+            (2.34, RoundingMode::Ceil, "2,34"),
+            (-2.34, RoundingMode::Floor, "-2,34"),
+            // The carry goes through the trailing 9
+            (1.0995, RoundingMode::HalfUp, "1,10"),
+            (1.0995, RoundingMode::Truncate, "1,09"),
+            // The carry into the whole part
+            (9.995, RoundingMode::HalfUp, "10,00"),
+            (9.995, RoundingMode::HalfEven, "10,00"),
+            (9.995, RoundingMode::HalfDown, "9,99"),
+            (9.995, RoundingMode::Truncate, "9,99"),
+            (9.991, RoundingMode::Ceil, "10,00"),
+            (-9.991, RoundingMode::Floor, "-10,00"),
+            (-9.999, RoundingMode::Ceil, "-9,99"),
+        ];
+        for (number, rounding_mode, expected) in values {
+            assert_eq!(format(number, 2, rounding_mode), expected, "{} with {:?}", number, rounding_mode);
+        }
+
+        // Without fraction digit, the whole part is rounded with the same mode
+        let values = vec![
+            (2.5, RoundingMode::HalfUp, "3"),
+            (2.5, RoundingMode::HalfEven, "2"),
+            (3.5, RoundingMode::HalfEven, "4"),
+            (2.5, RoundingMode::HalfDown, "2"),
+            (-2.5, RoundingMode::HalfUp, "-3"),
+            (-2.5, RoundingMode::HalfEven, "-2"),
+            (2.1, RoundingMode::Ceil, "3"),
+            (-2.1, RoundingMode::Floor, "-3"),
+            (2.9, RoundingMode::Truncate, "2"),
+            (1999.5, RoundingMode::HalfEven, "2 000"),
+        ];
+        for (number, rounding_mode, expected) in values {
+            assert_eq!(format(number, 0, rounding_mode), expected, "{} with {:?}", number, rounding_mode);
+        }
+
+        // The rounding of the decimal part
+        let half_even = FormatOption::new(2, 2).with_rounding_mode(RoundingMode::HalfEven);
+        assert_eq!(Number::<i32>::apply_decimal_digits("345", half_even), Some(("34".to_owned(), false)));
+        let floor = FormatOption::new(2, 2).with_rounding_mode(RoundingMode::Floor);
+        assert_eq!(Number::<i32>::round_decimal_digits("341", true, &floor), Some(("35".to_owned(), false)));
+        let ceil = FormatOption::new(2, 2).with_rounding_mode(RoundingMode::Ceil);
+        assert_eq!(Number::<i32>::apply_decimal_digits("991", ceil), Some(("00".to_owned(), true)));
+        assert_eq!(FormatOption::default().rounding_mode, RoundingMode::HalfUp);
+    }
+
+    /// Round the number written with its whole and decimal digits to max_decimals decimals, with big integers
+    /// Return the rounded whole and decimal digits
+    fn reference_round(whole: &str, decimal: &str, max_decimals: usize, is_negative: bool, rounding_mode: RoundingMode) -> (String, String) {
+        use num::{BigUint, Integer, Zero};
+
+        let number = format!("{}{}", whole, decimal).parse::<BigUint>().unwrap();
+        let divisor = BigUint::from(10u8).pow(decimal.len().saturating_sub(max_decimals) as u32);
+        let (quotient, remainder) = number.div_rem(&divisor);
+        let twice_remainder = &remainder * 2u8;
+        let is_rounded_up = !remainder.is_zero()
+            && match rounding_mode {
+                RoundingMode::Truncate => false,
+                RoundingMode::Floor => is_negative,
+                RoundingMode::Ceil => !is_negative,
+                RoundingMode::HalfUp => twice_remainder >= divisor,
+                RoundingMode::HalfDown => twice_remainder > divisor,
+                RoundingMode::HalfEven => twice_remainder > divisor || (twice_remainder == divisor && quotient.is_odd()),
+            };
+        let rounded = quotient + is_rounded_up as u8;
+
+        let nb_decimals = max_decimals.min(decimal.len());
+        let (whole, decimal) = rounded.div_rem(&BigUint::from(10u8).pow(nb_decimals as u32));
+        match nb_decimals {
+            0 => (whole.to_string(), String::new()),
+            _ => (whole.to_string(), format!("{:0>width$}", decimal.to_string(), width = nb_decimals)),
+        }
+    }
+
+    #[test]
+    pub fn test_exact_decimal_rounding() {
+        // The digits near the halfway value are not rounded by a float division
+        let values = vec![
+            ("344999999999999999999", FormatOption::new(2, 2), "34", false),
+            ("345000000000000000001", FormatOption::new(2, 2), "35", false),
+            ("345000000000000000000", FormatOption::new(2, 2).with_rounding_mode(RoundingMode::HalfEven), "34", false),
+            ("99949999999999999999", FormatOption::new(3, 3), "999", false),
+            ("9995", FormatOption::new(3, 3), "000", true),
+        ];
+        for (decimal_string, format, expected, is_rounded_up) in values {
             assert_eq!(
-                Number::<i32>::apply_decimal_format(decimal_value, format).unwrap().0,
+                Number::<i32>::apply_decimal_digits(decimal_string, format),
+                Some((expected.to_owned(), is_rounded_up)),
+                "{}",
                 decimal_string
             );
         }
+
+        assert_eq!(reference_round("9", "995", 2, false, RoundingMode::HalfUp), ("10".to_owned(), "00".to_owned()));
+        assert_eq!(reference_round("2", "345", 2, true, RoundingMode::Floor), ("2".to_owned(), "35".to_owned()));
+        assert_eq!(reference_round("12", "5", 0, false, RoundingMode::HalfEven), ("12".to_owned(), "".to_owned()));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_apply_decimal_digits_exact(
+            whole in "[1-9][0-9]{0,5}",
+            decimal in "[0-9]{1,40}",
+            max_decimals in 1u8..=9,
+            is_negative in proptest::bool::ANY,
+            rounding_mode in proptest::sample::select(enum_iterator::all::<RoundingMode>().collect::<Vec<RoundingMode>>()),
+        ) {
+            let format = FormatOption::new(max_decimals, max_decimals).with_rounding_mode(rounding_mode);
+            let (decimal_format, need_round_up_whole_part) = Number::<i32>::round_decimal_digits(&decimal, is_negative, &format).unwrap();
+
+            let (expected_whole, expected_decimal) = reference_round(&whole, &decimal, max_decimals as usize, is_negative, rounding_mode);
+            let expected_decimal = format!("{:0<width$}", expected_decimal, width = max_decimals as usize);
+            proptest::prop_assert_eq!(decimal_format, expected_decimal);
+            proptest::prop_assert_eq!(need_round_up_whole_part, expected_whole != whole);
+        }
+
+        #[test]
+        fn prop_to_format_options_exact(
+            value in 0f64..1e9,
+            max_decimals in 0u8..=9,
+            rounding_mode in proptest::sample::select(enum_iterator::all::<RoundingMode>().collect::<Vec<RoundingMode>>()),
+        ) {
+            // The number is formatted from its shortest representation, which is the reference too
+            let number_string = value.to_string();
+            let (whole, decimal) = number_string.split_once('.').unwrap_or((number_string.as_str(), ""));
+            let (expected_whole, expected_decimal) = reference_round(whole, decimal, max_decimals as usize, false, rounding_mode);
+            let expected = match max_decimals {
+                0 => expected_whole,
+                _ => format!("{}.{:0<width$}", expected_whole, expected_decimal, width = max_decimals as usize),
+            };
+
+            let format = FormatOption::new(max_decimals, max_decimals).with_rounding_mode(rounding_mode).with_use_grouping(false);
+            proptest::prop_assert_eq!(Number::new(value).to_format_options(Culture::English.into(), format).unwrap(), expected);
+        }
     }
 
     /// Test of 'to_format_options' function with float number
@@ -508,24 +2689,40 @@ use crate::{number_to_string::ToFormat, Culture, errors::ConversionError};
         for (format_str, result) in values_error {
             assert_eq!(Number::<i32>::set_nb_digits(format_str), Err(result));
         }
+
+        assert_eq!(Number::<i32>::read_format("C2", 'C'), Ok(2));
+        assert_eq!(Number::<i32>::read_format("NX", 'N'), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(Number::<i32>::read_format("N2", 'C'), Err(ConversionError::UnableToDisplayFormat));
     }
 
-    /// The the 'apply_thousand_separator' function
+    /// The the 'write_whole' function
     #[test]
-    fn test_apply_thousand_separator() {
+    fn test_write_whole() {
         let values = vec![
-            (1000, Culture::French, "1 000"),
-            (-1000000, Culture::French, "-1 000 000"),
-            (1000, Culture::English, "1,000"),
-            (-1000000, Culture::English, "-1,000,000"),
-            (1000, Culture::Italian, "1.000"),
-            (-1000000, Culture::Italian, "-1.000.000"),
-            (100000, Culture::Indian, "1,00,000"),
-            (10000000, Culture::Indian, "1,00,00,000"),
+            ("1000", 0, Culture::French, "1 000"),
+            ("1000000", 0, Culture::French, "1 000 000"),
+            ("1000", 0, Culture::English, "1,000"),
+            ("1000000", 0, Culture::English, "1,000,000"),
+            ("1000", 0, Culture::Italian, "1.000"),
+            ("1000000", 0, Culture::Italian, "1.000.000"),
+            ("100000", 0, Culture::Indian, "1,00,000"),
+            ("10000000", 0, Culture::Indian, "1,00,00,000"),
+            ("999", 0, Culture::English, "999"),
+            ("999", 0, Culture::Indian, "999"),
+            ("1000", 0, Culture::Indian, "1,000"),
+            ("42", 5, Culture::English, "00,042"),
+            ("42", 6, Culture::Indian, "0,00,042"),
+            ("0", 0, Culture::French, "0"),
         ];
 
-        for (val_i32, culture, val_string) in values {
-            assert_eq!(Number::<i32>::apply_thousand_separator(val_i32, culture.into()), val_string)
+        for (whole_string, min_digits, culture, val_string) in values {
+            let mut grouped = String::new();
+            Number::<i32>::write_whole(&mut grouped, whole_string, min_digits, Some(culture.into())).unwrap();
+            assert_eq!(grouped, val_string);
         }
+
+        let mut ungrouped = String::new();
+        Number::<i32>::write_whole(&mut ungrouped, "1000", 6, None).unwrap();
+        assert_eq!(ungrouped, "001000");
     }
 }
@@ -1,8 +1,14 @@
 use crate::errors::ConversionError;
-use crate::string_to_number::NumberConversion;
+use crate::number_to_string::{FormatOption, Number};
+use crate::string_to_number::{NumberConversion, StringNumber, MINUS_SIGN};
 use crate::Culture;
 use log::{info, warn};
 use regex::{Regex, escape};
+use num::Bounded;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::OnceLock;
 use std::fmt::Display;
 use std::str::FromStr;
 
@@ -19,7 +25,9 @@ impl From<&TypeParsing> for NumberType {
             TypeParsing::WholeSimple | TypeParsing::WholeThousandSeparator => NumberType::WHOLE,
             TypeParsing::DecimalSimple
             | TypeParsing::DecimalThousandSeparator
-            | TypeParsing::DecimalWithoutWholePart => NumberType::DECIMAL,
+            | TypeParsing::DecimalWithoutWholePart
+            | TypeParsing::NumberWithUnit
+            | TypeParsing::Custom => NumberType::DECIMAL,
         }
     }
 }
@@ -27,29 +35,92 @@ impl From<&TypeParsing> for NumberType {
 /// Represent commons separators.
 ///
 /// Can be thousand or decimal separator.
-#[derive(Debug, Clone, PartialEq, Copy)]
+/// The separators are ordered by declaration, the CUSTOM ones last (ordered by char value)
+#[derive(Debug, Clone, PartialEq, Copy, Eq, Hash, PartialOrd, Ord)]
 pub enum Separator {
-    SPACE,
-    DOT,
     COMMA,
+    DOT,
+    SPACE,
+    /// Tabulation, distinct from SPACE (used by some fixed width exports)
+    TAB,
     APOSTROPHE,
+    /// Underscore, used as digit separator by Rust literals and some config formats (1_000_000)
+    UNDERSCORE,
+    /// Middle dot (U+00B7), used as decimal separator in some typographic contexts
+    MIDDLEDOT,
     CUSTOM(char)
 }
 
 impl Separator {
-    fn to_string_regex(&self) -> String {
+    /// The apostrophe separator also match the typographic apostrophes (U+2019 and U+02BC),
+    /// often found in swiss numbers copied from Word or websites
+    fn to_string_regex(self) -> String {
         format!("[{}]", match self {
             Separator::COMMA => escape(","),
             Separator::DOT => escape("."),
             Separator::SPACE => r"\s".to_string(),
-            Separator::APOSTROPHE => escape("'"),
+            Separator::TAB => r"\t".to_string(),
+            Separator::APOSTROPHE => format!("{}\u{2019}\u{02BC}", escape("'")),
+            Separator::UNDERSCORE => escape("_"),
+            Separator::MIDDLEDOT => escape("\u{00B7}"),
             Separator::CUSTOM(c) => escape(c.to_string().as_str())
         })
     }
 
+    /// Return the escaped regex class matching the separator, to compose custom regex
+    /// Ex : Separator::DOT.regex_class() = "[\\.]"
+    pub fn regex_class(&self) -> String {
+        self.to_string_regex()
+    }
+
     pub fn to_owned_string(&self) -> String {
         (*self).into()
     }
+
+    /// Get the separator from a char : the known characters have their dedicated variant, the others are CUSTOM
+    /// (so '_' is UNDERSCORE, never CUSTOM('_'), which is parsed the same way)
+    /// Ref 'test_separator_from_char'
+    pub fn from_char(c: char) -> Separator {
+        match c {
+            ',' => Separator::COMMA,
+            '.' => Separator::DOT,
+            ' ' => Separator::SPACE,
+            '\t' => Separator::TAB,
+            '\'' => Separator::APOSTROPHE,
+            '_' => Separator::UNDERSCORE,
+            '\u{00B7}' => Separator::MIDDLEDOT,
+            c => Separator::CUSTOM(c),
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        self.into()
+    }
+
+    /// Return true for the whitespace separators (SPACE, TAB, or a custom whitespace like the narrow no-break space U+202F)
+    pub fn is_whitespace(&self) -> bool {
+        match self {
+            Separator::SPACE | Separator::TAB => true,
+            Separator::CUSTOM(c) => c.is_whitespace(),
+            _ => false,
+        }
+    }
+
+    /// Return true for the punctuation separators (COMMA, DOT, APOSTROPHE, UNDERSCORE, MIDDLEDOT, or a custom ASCII punctuation)
+    pub fn is_punctuation(&self) -> bool {
+        match self {
+            Separator::COMMA | Separator::DOT | Separator::APOSTROPHE | Separator::UNDERSCORE | Separator::MIDDLEDOT => true,
+            Separator::CUSTOM(c) => c.is_ascii_punctuation(),
+            Separator::SPACE | Separator::TAB => false,
+        }
+    }
+}
+
+/// Display the separator character
+impl Display for Separator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
 }
 
 // /// Get string slice from Separator
@@ -74,7 +145,10 @@ impl From<Separator> for String {
             Separator::COMMA => ",".to_owned(),
             Separator::DOT => ".".to_owned(),
             Separator::SPACE => " ".to_owned(),
+            Separator::TAB => "\t".to_owned(),
             Separator::APOSTROPHE => "'".to_owned(),
+            Separator::UNDERSCORE => "_".to_owned(),
+            Separator::MIDDLEDOT => "\u{00B7}".to_owned(),
             Separator::CUSTOM(c) => c.to_string(),
         }
     }
@@ -87,7 +161,10 @@ impl From<Separator> for char {
             Separator::COMMA => ',',
             Separator::DOT => '.',
             Separator::SPACE => ' ',
+            Separator::TAB => '\t',
             Separator::APOSTROPHE => '\'',
+            Separator::UNDERSCORE => '_',
+            Separator::MIDDLEDOT => '\u{00B7}',
             Separator::CUSTOM(c) => c,
         }
     }
@@ -102,6 +179,10 @@ impl TryFrom<&'static str> for Separator {
             "," => Ok(Separator::COMMA),
             "." => Ok(Separator::DOT),
             " " => Ok(Separator::SPACE),
+            "\t" => Ok(Separator::TAB),
+            "'" => Ok(Separator::APOSTROPHE),
+            "_" => Ok(Separator::UNDERSCORE),
+            "\u{00B7}" => Ok(Separator::MIDDLEDOT),
             // I'm pretty sure we can have a huge better syntax here...
             s if s.len() == 1 => Ok(Separator::CUSTOM(s.to_string().chars().collect::<Vec<char>>()[0])),
             _ => Err(ConversionError::SeparatorNotFound)
@@ -109,7 +190,7 @@ impl TryFrom<&'static str> for Separator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ThousandGrouping {
     /// The standard grouping is the most common thousand split. We group the number by blocks of 3
     /// Ex : X XXX XXX XXX
@@ -118,7 +199,7 @@ pub enum ThousandGrouping {
     TwoBlock
 }
 
-/// To be compatible with thousands crate
+/// The group sizes of the whole part, from the right (read by Number::is_group_end)
 impl From<ThousandGrouping> for &[u8] {
     fn from(val: ThousandGrouping) -> Self {
         match val {
@@ -129,7 +210,7 @@ impl From<ThousandGrouping> for &[u8] {
 }
 
 /// The type of parsing. Represent all kind of basic number format
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypeParsing {
     /**
      * X / +X / -X
@@ -151,6 +232,32 @@ pub enum TypeParsing {
      * X|ThousandSeparator|XXX|DecimalSeparator|XX / +X|ThousandSeparator|XXX|DecimalSeparator|XX / -X|ThousandSeparator|XXX|DecimalSeparator|XX
      */
     DecimalThousandSeparator,
+    /**
+     * Any of the previous formats, optionally followed by whitespaces and a unit : X kg / X,XXX EUR / X%
+     * Not part of the default culture patterns, see NumberConversion::to_number_with_unit
+     */
+    NumberWithUnit,
+    /**
+     * A user supplied regex, see ParsingPattern::custom
+     */
+    Custom,
+}
+
+impl TypeParsing {
+    /// How constrained the format is, used to choose between several matching patterns of the same priority :
+    /// the thousand separator patterns (fixed size groups) are more specific than the simple ones.
+    /// The unit and custom patterns have no known structure and are the least specific
+    /// Ref 'test_pattern_precedence'
+    pub fn specificity(&self) -> u8 {
+        match self {
+            Self::DecimalThousandSeparator => 5,
+            Self::WholeThousandSeparator => 4,
+            Self::DecimalSimple => 3,
+            Self::DecimalWithoutWholePart => 2,
+            Self::WholeSimple => 1,
+            Self::NumberWithUnit | Self::Custom => 0,
+        }
+    }
 }
 
 impl Display for TypeParsing {
@@ -161,12 +268,34 @@ impl Display for TypeParsing {
             Self::DecimalWithoutWholePart => "Decimal_Without_Whole_Part",
             Self::WholeThousandSeparator => "Whole_Thousand_Separator",
             Self::DecimalThousandSeparator => "Decimal_Thousand_Separator",
+            Self::NumberWithUnit => "Number_With_Unit",
+            Self::Custom => "Custom",
         };
 
         write!(f, "{}", name)
     }
 }
 
+/// The named capture groups of the generated regexes
+const NUMBER_CAPTURE_NAMES: [&str; 3] = ["sign", "whole", "decimal"];
+
+/// Suffix the named capture groups of the regex content with the index, so several contents can be joined in one regex
+/// Ex : "(?P<sign>[\-\+]?)" = "(?P<sign1>[\-\+]?)" with index 1
+fn index_capture_names(content: &str, index: usize) -> String {
+    NUMBER_CAPTURE_NAMES.iter().fold(content.to_owned(), |content, name| {
+        content.replace(&format!("?P<{}>", name), &format!("?P<{}{}>", name, index))
+    })
+}
+
+/// The parts of a string number captured by a regex pattern, see RegexPattern::captures
+/// The whole part keeps its thousand separators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberCaptures<'t> {
+    pub sign: Sign,
+    pub whole: Option<&'t str>,
+    pub decimal: Option<&'t str>,
+}
+
 /// Regex use to try to convert string to number
 #[derive(Debug, Clone)]
 pub struct RegexPattern {
@@ -174,6 +303,8 @@ pub struct RegexPattern {
     prefix: Regex,
     content: Regex,
     suffix: Regex,
+    /// The full regex (prefix, content, suffix), compiled once
+    anchored: Regex,
 }
 
 impl RegexPattern {
@@ -181,36 +312,40 @@ impl RegexPattern {
         type_parsing: &TypeParsing,
         culture_settings: Option<NumberCultureSettings>,
     ) -> Result<RegexPattern, ConversionError> {
+        // There is no built-in regex for the custom patterns, see 'from_regex'
+        if type_parsing == &TypeParsing::Custom {
+            return Err(ConversionError::RegexBuilder);
+        }
+
+        // Only the whole simple pattern can be built without culture settings
         if type_parsing != &TypeParsing::WholeSimple && culture_settings.is_none() {
-            panic!("The regex pattern need to have culture settings set");
+            return Err(ConversionError::RegexBuilder);
         }
 
         //Indian
-        // ^[\-\+]?([0-9]{0,3})([,][0-9]{2})*([,][0-9]{3}){1}
+        // ^[\-\+]?([0-9]{1,2})([,][0-9]{2})*([,][0-9]{3}){1}
 
         let regex_content = match type_parsing {
-            TypeParsing::WholeSimple => Regex::new(r"[\-\+]?\d+([0-9]{3})*"),
+            TypeParsing::WholeSimple => Regex::new(r"(?P<sign>[\-\+]?)(?P<whole>\d+([0-9]{3})*)"),
             TypeParsing::DecimalSimple => Regex::new(
                 format!(
                     "{}{}{}",
-                    r"[\-\+]?[0-9]+",
+                    r"(?P<sign>[\-\+]?)(?P<whole>[0-9]+)",
                     culture_settings
                         .unwrap()
-                        .decimal_separator
-                        .to_string_regex(),
-                    r"[0-9]{1,}"
+                        .into_decimal_separator_regex(),
+                    r"(?P<decimal>[0-9]{1,})"
                 )
                 .as_str(),
             ),
             TypeParsing::DecimalWithoutWholePart => Regex::new(
                 format!(
                     "{}{}{}",
-                    r"[\-\+]?",
+                    r"(?P<sign>[\-\+]?)",
                     culture_settings
                         .unwrap()
-                        .decimal_separator
-                        .to_string_regex(),
-                    "[0-9]+"
+                        .into_decimal_separator_regex(),
+                    "(?P<decimal>[0-9]+)"
                 )
                 .as_str(),
             ),
@@ -219,12 +354,11 @@ impl RegexPattern {
                     ThousandGrouping::ThreeBlock => {
                         Regex::new(
                             format!(
-                                "{}({}{})+",
-                                r"[\-\+]?[0-9]+",
+                                "{}({}{})+)",
+                                r"(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}",
                                 culture_settings
                                     .unwrap()
-                                    .thousand_separator
-                                    .to_string_regex(),
+                                    .into_thousand_separator_regex(),
                                 r"[0-9]{3}"
                             )
                             .as_str(),
@@ -232,73 +366,152 @@ impl RegexPattern {
                     },
                     ThousandGrouping::TwoBlock => {
                         Regex::new(
-                            format!("{}{}{}{}{}", r"[\-\+]?([0-9]{0,3})(", culture_settings
+                            format!("{}{}{}{}{}", r"(?P<sign>[\-\+]?)(?P<whole>([0-9]{1,2})(", culture_settings
                             .unwrap()
-                            .thousand_separator
-                            .to_string_regex(), r"[0-9]{2})*(", culture_settings
+                            .into_thousand_separator_regex(), r"[0-9]{2})*(", culture_settings
                             .unwrap()
-                            .thousand_separator
-                            .to_string_regex(), r"[0-9]{3}){1}")
+                            .into_thousand_separator_regex(), r"[0-9]{3}){1})")
                             .as_str(),
                         )
                     },
                 }
             },
             TypeParsing::DecimalThousandSeparator => {
-                // [\-\+]?([0-9]{0,3})([,][0-9]{2})*([,][0-9]{3}){1}
+                // [\-\+]?([0-9]{1,2})([,][0-9]{2})*([,][0-9]{3}){1}
                 match culture_settings.unwrap().thousand_grouping {
                     ThousandGrouping::ThreeBlock => {
                         Regex::new(
                             format!(
-                                "{}({}{})+{}[0-9]*",
-                                r"[\-\+]?[0-9]+",
+                                "{}({}{})+){}(?P<decimal>[0-9]*)",
+                                r"(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}",
                                 culture_settings
                                     .unwrap()
-                                    .thousand_separator
-                                    .to_string_regex(),
+                                    .into_thousand_separator_regex(),
                                 r"[0-9]{3}",
                                 culture_settings
                                     .unwrap()
-                                    .decimal_separator
-                                    .to_string_regex()
+                                    .into_decimal_separator_regex()
                             )
                             .as_str(),
                         )
                     },
                     ThousandGrouping::TwoBlock => {
                         Regex::new(
-                            format!("{}{}{}{}{}{}[0-9]*", r"[\-\+]?([0-9]{0,3})(", culture_settings
+                            format!("{}{}{}{}{}{}(?P<decimal>[0-9]*)", r"(?P<sign>[\-\+]?)(?P<whole>([0-9]{1,2})(", culture_settings
                             .unwrap()
-                            .thousand_separator
-                            .to_string_regex(), r"[0-9]{2})*(", culture_settings
+                            .into_thousand_separator_regex(), r"[0-9]{2})*(", culture_settings
                             .unwrap()
-                            .thousand_separator
-                            .to_string_regex(), r"[0-9]{3}){1}", culture_settings
+                            .into_thousand_separator_regex(), r"[0-9]{3}){1})", culture_settings
                             .unwrap()
-                            .decimal_separator
-                            .to_string_regex())
+                            .into_decimal_separator_regex())
                             .as_str(),
                         )
                     },
                 }
 
             },
+            TypeParsing::Custom => unreachable!(),
+            TypeParsing::NumberWithUnit => {
+                // The most specific formats first, the unit is made of letters and symbols (kg, EUR, €, %, km/h, m²)
+                // The group names must be unique : they are suffixed by the index of the format (sign0, whole0 etc.)
+                let numbers = [
+                    TypeParsing::DecimalThousandSeparator,
+                    TypeParsing::WholeThousandSeparator,
+                    TypeParsing::DecimalSimple,
+                    TypeParsing::DecimalWithoutWholePart,
+                    TypeParsing::WholeSimple,
+                ]
+                .iter()
+                .enumerate()
+                .map(|(index, t)| RegexPattern::new(t, culture_settings).map(|r| index_capture_names(r.content.as_str(), index)))
+                .collect::<Result<Vec<String>, ConversionError>>()?;
+
+                Regex::new(
+                    format!(
+                        r"(?P<number>{})(?:\s*(?P<unit>[\p{{L}}\p{{S}}%‰/²³]+))?",
+                        numbers.join("|")
+                    )
+                    .as_str(),
+                )
+            },
         }
         .map_err(|_| ConversionError::RegexBuilder)?;
 
+        RegexPattern::anchor(type_parsing.to_owned(), regex_content)
+    }
+
+    /// Create a custom regex pattern from a user supplied regex, which must match the whole string number
+    /// Return RegexBuilder if the regex is invalid
+    /// Ref 'test_custom_pattern'
+    pub fn from_regex(regex: &str) -> Result<RegexPattern, ConversionError> {
+        let content = Regex::new(regex).map_err(|e| {
+            warn!("Invalid custom regex {} : {:?}", regex, e);
+            ConversionError::RegexBuilder
+        })?;
+
+        RegexPattern::anchor(TypeParsing::Custom, content)
+    }
+
+    /// Build the pattern with the anchored regex (^(?:content)$), the group keeps an alternation of the content inside the anchors
+    fn anchor(type_parsing: TypeParsing, content: Regex) -> Result<RegexPattern, ConversionError> {
+        let prefix = Regex::new(r"^").unwrap();
+        let suffix = Regex::new(r"$").unwrap();
+        let anchored = Regex::new(format!("{}(?:{}){}", prefix, content, suffix).as_str()).map_err(|_| ConversionError::RegexBuilder)?;
+
         Ok(RegexPattern {
-            type_parsing: type_parsing.to_owned(),
-            prefix: Regex::new(r"^").unwrap(),
-            content: regex_content,
-            suffix: Regex::new(r"$").unwrap(),
+            type_parsing,
+            prefix,
+            content,
+            suffix,
+            anchored,
+        })
+    }
+
+    /// Return the sign, whole and decimal parts of the string number (None if the regex doesn't match).
+    /// The parts are read from the named groups 'sign', 'whole' and 'decimal' (or suffixed by an index, 'whole0')
+    /// A custom regex without these groups only returns the positive sign
+    /// Ref 'test_regex_captures'
+    pub fn captures<'t>(&self, text: &'t str) -> Option<NumberCaptures<'t>> {
+        let regex = &self.anchored;
+        let captures = regex.captures(text)?;
+
+        // The first group which matched a non empty part
+        let part = |name: &str| {
+            regex
+                .capture_names()
+                .flatten()
+                .filter(|group| group.strip_prefix(name).is_some_and(|index| index.chars().all(|c| c.is_ascii_digit())))
+                .find_map(|group| captures.name(group).filter(|m| !m.as_str().is_empty()))
+                .map(|m| m.as_str())
+        };
+
+        Some(NumberCaptures {
+            sign: match part("sign") {
+                Some("-") => Sign::Negative,
+                _ => Sign::Positive,
+            },
+            whole: part("whole"),
+            decimal: part("decimal"),
         })
     }
 
     /// Return if the string number has been matched by the regex
     pub fn is_match(&self, text: &str) -> bool {
-        let full_regex =
-            Regex::new(format!("{}{}{}", self.prefix, self.content, self.suffix).as_str()).unwrap();
-        full_regex.is_match(text)
+        self.anchored.is_match(text)
+    }
+
+    /// Return the number of characters matched from the start of the text before the regex stopped matching
+    /// (the length of the longest prefix matched by the regex content, the whole text when the regex matches)
+    /// Ref 'test_explain'
+    pub fn matched_prefix_len(&self, text: &str) -> usize {
+        if self.is_match(text) {
+            return text.chars().count();
+        }
+
+        Regex::new(format!("{}(?:{})", self.prefix, self.content).as_str())
+            .ok()
+            .and_then(|regex| regex.find(text))
+            .map_or(0, |m| m.as_str().chars().count())
     }
 
     pub fn get_type_parsing(&self) -> &TypeParsing {
@@ -306,7 +519,13 @@ impl RegexPattern {
     }
 
     pub fn get_regex(&self) -> Regex {
-        Regex::new(format!("{}{}{}", self.prefix, self.content, self.suffix).as_str()).unwrap()
+        self.anchored.clone()
+    }
+
+    /// Return the anchored regex as a string, the one used by 'is_match' (ex: for a client side validation)
+    /// Ref 'test_regex_for'
+    pub fn as_regex_string(&self) -> String {
+        format!("{}(?:{}){}", self.prefix, self.content, self.suffix)
     }
 }
 
@@ -317,6 +536,8 @@ pub struct ParsingPattern {
     name: String,
     regex: RegexPattern,
     number_type: NumberType,
+    /// The pattern with the highest priority is chosen when several patterns match (default 0)
+    priority: i32,
 }
 
 impl Display for ParsingPattern {
@@ -335,9 +556,44 @@ impl ParsingPattern {
             name: format!("{}_{}", name.to_uppercase(), &type_parsing),
             regex: RegexPattern::new(&type_parsing, culture_settings)?,
             number_type: NumberType::from(&type_parsing),
+            priority: 0,
+        })
+    }
+
+    /// Create a pattern from a user supplied regex, the number type can't be deduced from the regex so it has to be given.
+    /// The part to convert can be captured with the named group 'number' (ex: to ignore a unit), otherwise the whole string is converted
+    /// Ex : ParsingPattern::custom("percent", r"(?P<number>[0-9]+(\.[0-9]+)?)\s*%", NumberType::DECIMAL)
+    /// Ref 'test_custom_pattern'
+    pub fn custom(name: &str, regex: &str, number_type: NumberType) -> Result<ParsingPattern, ConversionError> {
+        Ok(ParsingPattern {
+            name: format!("{}_{}", name.to_uppercase(), TypeParsing::Custom),
+            regex: RegexPattern::from_regex(regex)?,
+            number_type,
+            priority: 0,
         })
     }
 
+    /// Set the priority of the pattern, used when several patterns match the same string number
+    /// Ref 'test_pattern_priority'
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// The key ordering the patterns which match the same string number, the greatest one is chosen :
+    /// the priority, then the specificity of the type parsing, then the longest regex
+    fn match_order_key(&self) -> (i32, u8, usize) {
+        (
+            self.priority,
+            self.regex.type_parsing.specificity(),
+            self.regex.anchored.as_str().len(),
+        )
+    }
+
     pub fn get_regex(&self) -> &RegexPattern {
         &self.regex
     }
@@ -349,6 +605,12 @@ impl ParsingPattern {
     pub fn name(&self) -> &str {
         self.name.as_ref()
     }
+
+    /// Describe the pattern with its type parsing and regex, for debugging purpose
+    /// Ex : [EN_Decimal_Simple] Decimal_Simple ^(?:(?P<sign>[\-\+]?)(?P<whole>[0-9]+)[\.](?P<decimal>[0-9]{1,}))$
+    pub fn describe(&self) -> String {
+        format!("{} {} {}", self, self.regex.type_parsing, self.regex.get_regex().as_str())
+    }
 }
 
 /// Represent the current thousand and decimal separator
@@ -360,7 +622,33 @@ pub struct NumberCultureSettings {
 }
 
 impl NumberCultureSettings {
+    /// Settings of the English culture (1,000.5)
+    /// Ref 'test_number_culture_settings_consts'
+    pub const ENGLISH: NumberCultureSettings = NumberCultureSettings::const_new(Separator::COMMA, Separator::DOT, ThousandGrouping::ThreeBlock);
+    /// Settings of the French culture (1 000,5)
+    pub const FRENCH: NumberCultureSettings = NumberCultureSettings::const_new(Separator::SPACE, Separator::COMMA, ThousandGrouping::ThreeBlock);
+    /// Settings of the Italian culture (1.000,5)
+    pub const ITALIAN: NumberCultureSettings = NumberCultureSettings::const_new(Separator::DOT, Separator::COMMA, ThousandGrouping::ThreeBlock);
+    /// Settings of the Turkish culture (1.000,5)
+    pub const TURKISH: NumberCultureSettings = NumberCultureSettings::const_new(Separator::DOT, Separator::COMMA, ThousandGrouping::ThreeBlock);
+    /// Settings of the Indian culture (1,00,000.5)
+    pub const INDIAN: NumberCultureSettings = NumberCultureSettings::const_new(Separator::COMMA, Separator::DOT, ThousandGrouping::TwoBlock);
+
+    /// Const constructor of the built-in settings (the separators are known to be different)
+    const fn const_new(
+        thousand_separator: Separator,
+        decimal_separator: Separator,
+        thousand_grouping: ThousandGrouping,
+    ) -> NumberCultureSettings {
+        NumberCultureSettings {
+            thousand_separator,
+            decimal_separator,
+            thousand_grouping,
+        }
+    }
+
     /// Create a new instance
+    /// Panic if the thousand and decimal separators are the same, see 'try_new' for the non panicking version
     pub fn new(
         thousand_separator: Separator,
         decimal_separator: Separator,
@@ -374,6 +662,31 @@ impl NumberCultureSettings {
         }
     }
 
+    /// Create a new instance, return DuplicateSeparators if the thousand and decimal separators are the same
+    /// Ref 'test_number_culture_settings_try_new'
+    pub fn try_new(
+        thousand_separator: Separator,
+        decimal_separator: Separator,
+    ) -> Result<NumberCultureSettings, ConversionError> {
+        if thousand_separator == decimal_separator {
+            return Err(ConversionError::DuplicateSeparators { separator: thousand_separator });
+        }
+
+        Ok(NumberCultureSettings::new(thousand_separator, decimal_separator))
+    }
+
+    /// Return the settings with the thousand and decimal separators swapped, the grouping is kept
+    /// (ex: to read a dataset exported with the separators of another culture, English 1,000.5 = Italian 1.000,5).
+    /// Return InvalidDecimalSeparator if the thousand separator is a whitespace, which can't be a decimal separator (ex: French)
+    /// Ref 'test_swap_separators'
+    pub fn swap_separators(&self) -> Result<NumberCultureSettings, ConversionError> {
+        if self.thousand_separator.to_char().is_whitespace() {
+            return Err(ConversionError::InvalidDecimalSeparator { separator: self.thousand_separator });
+        }
+
+        Ok(NumberCultureSettings::try_new(self.decimal_separator, self.thousand_separator)?.with_grouping(self.thousand_grouping))
+    }
+
     /// Set the thousand grouping value (didn't want to expose it in the constructor)
     pub fn with_grouping(mut self, thousand_grouping: ThousandGrouping) -> Self {
         self.thousand_grouping = thousand_grouping;
@@ -389,7 +702,7 @@ impl NumberCultureSettings {
     }
 
     pub fn into_thousand_separator_regex(&self) -> String {
-        self.thousand_separator.to_string_regex()
+        NumberCultureSettings::separator_regex(self.thousand_separator, self.decimal_separator)
     }
 
     pub fn decimal_separator(&self) -> Separator {
@@ -401,12 +714,100 @@ impl NumberCultureSettings {
     }
 
     pub fn into_decimal_separator_regex(&self) -> String {
-        self.decimal_separator.to_string_regex()
+        NumberCultureSettings::separator_regex(self.decimal_separator, self.thousand_separator)
+    }
+
+    /// The regex of the separator. SPACE matches all the whitespaces, except the tabulation when the other separator is TAB
+    /// Ref 'test_separator_tab'
+    fn separator_regex(separator: Separator, other: Separator) -> String {
+        match (separator, other) {
+            (Separator::SPACE, Separator::TAB) => String::from(r"[\s&&[^\t]]"),
+            _ => separator.to_string_regex(),
+        }
     }
 
     pub fn thousand_grouping(&self) -> ThousandGrouping {
         self.thousand_grouping
     }
+
+    /// Format the integer with these settings, to display an example (ex: in a locale selector)
+    /// Ex : French = "1 234 567" for 1234567
+    /// Ref 'test_format_example'
+    pub fn format_example(&self, n: i64) -> Result<String, ConversionError> {
+        Number::new(n).to_format_options(*self, FormatOption::new(0, 0))
+    }
+
+    /// Format the float with these settings and the given number of decimals, to display an example
+    /// Ex : French = "1 234,57" for 1234.567 with 2 decimals
+    /// Ref 'test_format_example'
+    pub fn format_example_float(&self, n: f64, decimal_places: u8) -> Result<String, ConversionError> {
+        Number::new(n).to_format_options(*self, FormatOption::new(decimal_places, decimal_places))
+    }
+
+    /// Display the float formatted with 2 decimals and the value parsed back from it, to check the round trip
+    /// Ex : French = "1 234,57 = 1234.57" for 1234.567
+    /// Ref 'test_format_example'
+    pub fn display_example_pair(&self, n: f64) -> String {
+        let round_trip = self.format_example_float(n, 2).and_then(|formatted| {
+            let parsed = formatted.as_str().to_number_separators::<f64>(*self)?;
+            Ok(format!("{} = {}", formatted, parsed))
+        });
+
+        round_trip.unwrap_or_else(|e| e.to_string())
+    }
+
+    /// The Excel / LibreOffice format of these settings, with the given number of decimals
+    /// Ex : English = "#,##0.00", French = "# ##0,00", Indian = "#,##,##0.00" for 2 decimals
+    /// Ref 'test_format_spec'
+    pub fn to_format_spec(&self, decimal_places: u8) -> String {
+        let thousand = self.thousand_separator.to_char();
+        let whole = match self.thousand_grouping {
+            ThousandGrouping::ThreeBlock => format!("#{}##0", thousand),
+            ThousandGrouping::TwoBlock => format!("#{}##{}##0", thousand, thousand),
+        };
+
+        match decimal_places {
+            0 => whole,
+            _ => format!("{}{}{}", whole, self.decimal_separator.to_char(), "0".repeat(decimal_places as usize)),
+        }
+    }
+
+    /// Read the settings from an Excel / LibreOffice format (see 'to_format_spec').
+    /// Without decimal part ("#,##0"), the decimal separator is DOT after a COMMA thousand separator, COMMA otherwise.
+    /// Return SeparatorNotFound without thousand separator, and InvalidFormatPattern with the position of a misplaced separator
+    /// Ref 'test_format_spec'
+    pub fn from_format_spec(spec: &str) -> Result<NumberCultureSettings, ConversionError> {
+        let chars = spec.chars().collect::<Vec<char>>();
+        let invalid = |position: usize| ConversionError::InvalidFormatPattern { position };
+        let is_digit = |position: usize| chars.get(position).is_some_and(|c| *c == '#' || *c == '0');
+
+        // The separators are the characters between the digit placeholders
+        let separators = (0..chars.len()).filter(|position| !is_digit(*position)).collect::<Vec<usize>>();
+        if let Some(position) = separators.iter().find(|position| **position == 0 || !is_digit(*position - 1) || !is_digit(*position + 1)) {
+            return Err(invalid(*position));
+        }
+        let thousand = chars[*separators.first().ok_or(ConversionError::SeparatorNotFound)?];
+
+        // The decimal separator is the last one, after the thousand separators
+        let (thousands, decimal) = match separators.iter().position(|position| chars[*position] != thousand) {
+            Some(index) if index + 1 < separators.len() => return Err(invalid(separators[index + 1])),
+            Some(index) => (&separators[..index], Some(chars[separators[index]])),
+            None => (&separators[..], None),
+        };
+        let whole_end = decimal.map_or(chars.len(), |_| separators[thousands.len()]);
+
+        // The last group has 3 digits, and the others 3 (ThreeBlock) or 2 (TwoBlock)
+        let bounds = thousands.iter().copied().chain([whole_end]).collect::<Vec<usize>>();
+        let groups = bounds.windows(2).map(|bound| bound[1] - bound[0] - 1).collect::<Vec<usize>>();
+        let grouping = match groups.split_last() {
+            Some((3, others)) if !others.is_empty() && others.iter().all(|size| *size == 2) => ThousandGrouping::TwoBlock,
+            Some((3, others)) if others.iter().all(|size| *size == 3) => ThousandGrouping::ThreeBlock,
+            _ => return Err(invalid(thousands[thousands.len() - 1])),
+        };
+
+        let decimal = decimal.unwrap_or(if thousand == ',' { '.' } else { ',' });
+        Ok(NumberCultureSettings::try_new(Separator::from_char(thousand), Separator::from_char(decimal))?.with_grouping(grouping))
+    }
 }
 
 
@@ -422,10 +823,11 @@ impl From<(&'static str, &'static str)> for NumberCultureSettings {
 impl From<Culture> for NumberCultureSettings {
     fn from(culture: Culture) -> Self {
         match culture {
-            Culture::English => NumberCultureSettings::new(Separator::COMMA, Separator::DOT),
-            Culture::French => NumberCultureSettings::new(Separator::SPACE, Separator::COMMA),
-            Culture::Italian => NumberCultureSettings::new(Separator::DOT, Separator::COMMA),
-            Culture::Indian => NumberCultureSettings::new(Separator::COMMA, Separator::DOT).with_grouping(ThousandGrouping::TwoBlock),
+            Culture::English => NumberCultureSettings::ENGLISH,
+            Culture::French => NumberCultureSettings::FRENCH,
+            Culture::Italian => NumberCultureSettings::ITALIAN,
+            Culture::Turkish => NumberCultureSettings::TURKISH,
+            Culture::Indian => NumberCultureSettings::INDIAN,
         }
     }
 }
@@ -434,20 +836,25 @@ impl From<Culture> for NumberCultureSettings {
 #[derive(Debug, Clone)]
 pub struct CulturePattern {
     name: String,
-    value: Culture,
+    /// None for a custom culture (a name which isn't a Culture code)
+    value: Option<Culture>,
+    settings: NumberCultureSettings,
     patterns: Vec<ParsingPattern>,
 }
 
 impl CulturePattern {
     /// Create a new language pattern
-    /// This struct is use to parse a string number from the given culture
+    /// This struct is use to parse a string number from the given culture.
+    /// The name is the culture code ("fr"), or the name of a custom culture (see ConvertString::with_custom_culture)
+    /// Ref 'test_custom_culture'
     pub fn new(
         name: &str,
         culture_settings: NumberCultureSettings,
     ) -> Result<CulturePattern, ConversionError> {
         Ok(CulturePattern {
             name: String::from(name),
-            value: name.try_into().unwrap(),
+            value: name.try_into().ok(),
+            settings: culture_settings,
             patterns: vec![
                 ParsingPattern::build(
                     String::from(name),
@@ -481,8 +888,14 @@ impl CulturePattern {
         &self.name
     }
 
-    pub fn get_culture(&self) -> &Culture {
-        &self.value
+    /// The culture of the pattern, None for a custom culture
+    pub fn get_culture(&self) -> Option<&Culture> {
+        self.value.as_ref()
+    }
+
+    /// The separators used to build the patterns, and to clean the string number before conversion
+    pub fn get_settings(&self) -> &NumberCultureSettings {
+        &self.settings
     }
 
     pub fn get_patterns(&self) -> &Vec<ParsingPattern> {
@@ -491,9 +904,11 @@ impl CulturePattern {
 }
 
 /// All pattern defined to try to convert string to number
+#[derive(Debug, Clone)]
 pub struct NumberPatterns {
     common_pattern: Vec<ParsingPattern>,
-    culture_pattern: Vec<CulturePattern>,
+    /// Culture patterns, indexed by the culture code ("en", "fr" etc.)
+    culture_pattern: HashMap<String, CulturePattern>,
     math_pattern: Vec<ParsingPattern>,
 }
 
@@ -502,30 +917,80 @@ impl NumberPatterns {
         NumberPatterns::default()
     }
 
-    /// Return all culture pattern
+    /// Return all culture pattern, sorted by culture code
     pub fn get_all_culture_pattern(&self) -> Vec<CulturePattern> {
-        self.culture_pattern.to_vec()
+        let mut culture_patterns = self.culture_pattern.values().cloned().collect::<Vec<CulturePattern>>();
+        culture_patterns.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+        culture_patterns
     }
 
     /// Try to return the culture pattern from the following culture
     pub fn get_culture_pattern(&self, culture: &Culture) -> Option<CulturePattern> {
-        self.get_all_culture_pattern()
-            .into_iter()
-            .find(|c| c.get_culture() == culture)
+        let code: &str = (*culture).into();
+        self.culture_pattern.get(code).cloned()
+    }
+
+    /// Try to return the culture pattern from its name : a culture code ("fr") or the name of a custom culture
+    /// Ref 'test_custom_culture'
+    pub fn get_culture_pattern_by_name(&self, name: &str) -> Option<&CulturePattern> {
+        self.culture_pattern.get(name)
     }
 
+    /// Add a culture pattern (replace the existing one with the same name)
     pub fn add_culture_pattern(&mut self, pattern: CulturePattern) {
-        self.culture_pattern.push(pattern);
+        self.culture_pattern.insert(pattern.get_name().to_owned(), pattern);
+    }
+
+    /// Replace the culture pattern of the following culture and return the previous one
+    /// Useful to change the separators accepted by a culture at runtime
+    /// Ref 'test_replace_culture_pattern'
+    pub fn replace_culture_pattern(&mut self, culture: Culture, pattern: CulturePattern) -> Option<CulturePattern> {
+        let code: &str = culture.into();
+        self.culture_pattern.insert(code.to_owned(), pattern)
+    }
+
+    /// Remove the culture pattern of the following culture and return it
+    pub fn remove_culture_pattern(&mut self, culture: &Culture) -> Option<CulturePattern> {
+        let code: &str = (*culture).into();
+        self.culture_pattern.remove(code)
     }
 
     pub fn get_common_pattern(&self) -> Vec<ParsingPattern> {
         self.common_pattern.to_vec()
     }
 
+    /// Iterate over the patterns searched for the culture, without cloning them : the common patterns, then the culture ones
+    pub(crate) fn iter_patterns(&self, culture: &Culture) -> impl Iterator<Item = &ParsingPattern> {
+        self.iter_patterns_by_name((*culture).into())
+    }
+
+    /// Like 'iter_patterns', with the culture code or the name of a custom culture
+    pub(crate) fn iter_patterns_by_name(&self, name: &str) -> impl Iterator<Item = &ParsingPattern> {
+        let culture_patterns = self.culture_pattern.get(name).map(|pattern| pattern.get_patterns().iter());
+
+        self.common_pattern.iter().chain(culture_patterns.into_iter().flatten())
+    }
+
     pub fn add_common_pattern(&mut self, pattern: ParsingPattern) {
         self.common_pattern.push(pattern);
     }
 
+    /// Return one anchored regex matching the string numbers accepted for the culture :
+    /// the alternation of the common patterns and of the culture patterns (None if the culture has no pattern)
+    /// Ref 'test_regex_for'
+    pub fn culture_regex(&self, culture: &Culture) -> Option<String> {
+        let culture_pattern = self.get_culture_pattern(culture)?;
+        let contents = self
+            .common_pattern
+            .iter()
+            .chain(culture_pattern.get_patterns())
+            .enumerate()
+            .map(|(index, pattern)| format!("(?:{})", index_capture_names(pattern.regex.content.as_str(), index)))
+            .collect::<Vec<String>>();
+
+        Some(format!("^(?:{})$", contents.join("|")))
+    }
+
     pub fn get_math_pattern(&self) -> Vec<ParsingPattern> {
         self.math_pattern.to_vec()
     }
@@ -539,7 +1004,7 @@ impl Default for NumberPatterns {
     fn default() -> Self {
         let mut patterns = NumberPatterns {
             common_pattern: vec![],
-            culture_pattern: vec![],
+            culture_pattern: HashMap::new(),
             math_pattern: vec![],
         };
 
@@ -558,34 +1023,114 @@ impl Default for NumberPatterns {
 }
 
 /// Structure to convert a string to number
-pub struct ConvertString {
-    string_num: String,
+///
+/// The string number is borrowed, and the default patterns are built once and shared by all the instances,
+/// so creating a ConvertString doesn't allocate (unless the string is sanitized or custom patterns are given).
+/// It's Send + Sync, and cloning it only copies the references (unless it owns custom patterns)
+/// Ref 'test_convert_string_threads'
+#[derive(Clone)]
+pub struct ConvertString<'a> {
+    string_num: Cow<'a, str>,
     culture: Option<Culture>,
-    all_patterns: NumberPatterns,
+    /// The name of a custom culture pattern, used instead of the culture
+    custom_culture: Option<String>,
+    all_patterns: Cow<'a, NumberPatterns>,
 }
 
-impl ConvertString {
+impl<'a> ConvertString<'a> {
     /// Create a new ConvertString instance
-    pub fn new(string_num: &str, culture: Option<Culture>) -> ConvertString {
+    /// Without culture, the patterns of all the cultures are tried (in Culture order), see 'matched_culture'
+    pub fn new(string_num: &'a str, culture: Option<Culture>) -> ConvertString<'a> {
+        // The typographic minus sign is read as the ASCII one, the string is only copied when needed
+        let string_num = if string_num.contains(MINUS_SIGN) {
+            Cow::Owned(string_num.replace(MINUS_SIGN, "-"))
+        } else {
+            Cow::Borrowed(string_num)
+        };
+
         ConvertString {
-            string_num: String::from(string_num),
+            string_num,
             culture,
-            all_patterns: ConvertString::load_patterns(),
+            custom_culture: None,
+            all_patterns: Cow::Borrowed(ConvertString::load_patterns()),
         }
     }
 
-    /// Load all patterns
-    fn load_patterns() -> NumberPatterns {
-        NumberPatterns::default()
+    /// Remove the invisible characters (zero width and bidi control characters) from the string number before matching.
+    /// See string_to_number::INVISIBLE_CHARACTERS
+    pub fn with_sanitize(mut self, sanitize: bool) -> Self {
+        if sanitize {
+            self.string_num = Cow::Owned(crate::string_to_number::sanitize(&self.string_num));
+        }
+        self
+    }
+
+    /// Use the following patterns instead of the default ones (ex: a culture pattern replaced at runtime)
+    /// Ref 'test_replace_culture_pattern'
+    pub fn with_patterns(mut self, patterns: NumberPatterns) -> Self {
+        self.all_patterns = Cow::Owned(patterns);
+        self
+    }
+
+    /// Use the culture pattern registered with this name in the patterns (see 'with_patterns' and
+    /// NumberPatterns::add_culture_pattern) instead of the culture. It's the way to parse with a custom culture.
+    /// The matched culture is the culture of the pattern (None for a custom culture)
+    /// Ref 'test_custom_culture'
+    pub fn with_custom_culture(mut self, name: &str) -> Self {
+        self.custom_culture = Some(name.to_owned());
+        self
+    }
+
+    /// Like 'with_patterns', but the patterns are borrowed, to share a custom pattern set between instances
+    /// Ref 'test_convert_string_borrowed'
+    pub fn with_borrowed_patterns(mut self, patterns: &'a NumberPatterns) -> Self {
+        self.all_patterns = Cow::Borrowed(patterns);
+        self
+    }
+
+    /// Load all patterns. The default patterns are built only once
+    pub(crate) fn load_patterns() -> &'static NumberPatterns {
+        static DEFAULT_PATTERNS: OnceLock<NumberPatterns> = OnceLock::new();
+        DEFAULT_PATTERNS.get_or_init(NumberPatterns::default)
     }
 
     /// Return the pattern selected for conversion
     pub fn get_current_pattern(&self) -> Option<ParsingPattern> {
-        ConvertString::find_pattern(
-            &self.string_num,
-            &self.culture.unwrap_or_default(),
-            &self.all_patterns,
-        )
+        self.find_match().map(|(_, pattern)| pattern)
+    }
+
+    /// The culture pattern of the custom culture, if any
+    fn custom_culture_pattern(&self) -> Option<&CulturePattern> {
+        self.custom_culture
+            .as_ref()
+            .and_then(|name| self.all_patterns.get_culture_pattern_by_name(name))
+    }
+
+    /// Return the culture of the pattern selected for conversion : the given culture,
+    /// or without culture the first culture (in Culture order) with a matching pattern
+    /// Ref 'test_convert_string_any_culture'
+    pub fn matched_culture(&self) -> Option<Culture> {
+        self.find_match().and_then(|(culture, _)| culture)
+    }
+
+    /// Find the matching pattern with the custom culture, the culture, or with all the cultures if none has been given
+    fn find_match(&self) -> Option<(Option<Culture>, ParsingPattern)> {
+        if let Some(name) = &self.custom_culture {
+            let culture = self.custom_culture_pattern().and_then(|pattern| pattern.get_culture().copied());
+            return ConvertString::sort_matching_patterns(&self.string_num, self.all_patterns.iter_patterns_by_name(name))
+                .into_iter()
+                .next()
+                .map(|pattern| (culture, pattern));
+        }
+
+        let find = |culture: Culture| {
+            ConvertString::find_pattern(&self.string_num, &culture, &self.all_patterns).map(|pattern| (Some(culture), pattern))
+        };
+
+        match self.culture {
+            Some(culture) => find(culture),
+            None => enum_iterator::all::<Culture>().find_map(find),
+        }
     }
 
     /// Get culture pattern from culture
@@ -593,45 +1138,71 @@ impl ConvertString {
         culture: &Culture,
         patterns: &NumberPatterns,
     ) -> Option<CulturePattern> {
-        patterns
-            .get_all_culture_pattern()
-            .into_iter()
-            .find(|c| c.get_culture() == culture)
+        patterns.get_culture_pattern(culture)
     }
 
     /// Find a matching pattern for the given string num
+    /// When several patterns match, the one with the highest priority is chosen, then the most specific
+    /// (see TypeParsing::specificity, then the longest regex), then the first inserted (common patterns before the culture ones)
+    /// The built-in patterns don't compete for a whole number : an ungrouped number ("1000") is only matched by
+    /// the common Whole_Simple pattern, a grouped one ("1,000") only by the culture Whole_Thousand_Separator pattern,
+    /// which requires well formed groups (the leftmost one included), so a misgrouped number ("10,00") has no pattern
+    /// Ref 'test_grouped_and_ungrouped'
     pub fn find_pattern(
         string_num: &str,
         culture: &Culture,
         patterns: &NumberPatterns,
     ) -> Option<ParsingPattern> {
-        //First, we search in common pattern (not currency dependent) and currency pattern
-        let mut all_patterns = patterns.get_common_pattern();
-
-        let pattern_culture = ConvertString::find_culture_pattern(&culture, &patterns);
-
-        if pattern_culture.is_none() {
-            warn!("{}", ConversionError::PatternCultureNotFound.message());
-        } else {
-            all_patterns.extend(pattern_culture.unwrap().get_patterns().clone());
-        }
-
-        // Return the pattern which match
-        match all_patterns
+        match ConvertString::find_all_patterns(string_num, culture, patterns)
             .into_iter()
-            .find(|p| p.get_regex().is_match(string_num))
+            .next()
         {
             Some(pp) => {
                 info!("Input = {} / Pattern found = {}", &string_num, &pp);
-                return Some(pp);
+                Some(pp)
             }
             None => {
                 info!("No Pattern found for '{}'", &string_num);
-                return None;
+                None
             }
         }
     }
 
+    /// Return all the patterns which match the given string num, the first one being the pattern chosen by find_pattern
+    /// Patterns are sorted by priority, then by specificity (type parsing, then longest regex), then by insertion order (common patterns first)
+    /// Ref 'test_pattern_priority', 'test_pattern_precedence'
+    pub fn find_all_patterns(
+        string_num: &str,
+        culture: &Culture,
+        patterns: &NumberPatterns,
+    ) -> Vec<ParsingPattern> {
+        //First, we search in common pattern (not currency dependent) and currency pattern
+        let mut all_patterns = patterns.get_common_pattern();
+
+        let pattern_culture = ConvertString::find_culture_pattern(culture, patterns);
+
+        if let Some(pattern_culture) = pattern_culture {
+            all_patterns.extend(pattern_culture.get_patterns().clone());
+        } else {
+            warn!("{}", ConversionError::PatternCultureNotFound.message());
+        }
+
+        ConvertString::sort_matching_patterns(string_num, all_patterns.iter())
+    }
+
+    /// Return the patterns which match the string num, sorted like 'find_all_patterns'
+    fn sort_matching_patterns<'p>(string_num: &str, patterns: impl Iterator<Item = &'p ParsingPattern>) -> Vec<ParsingPattern> {
+        let mut matching_patterns = patterns
+            .filter(|p| p.get_regex().is_match(string_num))
+            .cloned()
+            .collect::<Vec<ParsingPattern>>();
+
+        // Stable sort, so the insertion order is kept for equal patterns
+        matching_patterns.sort_by_key(|p| std::cmp::Reverse(p.match_order_key()));
+
+        matching_patterns
+    }
+
     /// Return true is the string has been succesfully converted into number
     pub fn is_numeric(&self) -> bool {
         self.get_current_pattern().is_some()
@@ -655,453 +1226,2526 @@ impl ConvertString {
         false
     }
 
-    pub fn to_number<N: num::Num + Display + FromStr>(&self) -> Result<N, ConversionError> {
-        if let Some(culture) = self.culture {
-            self.string_num.as_str().to_number_culture::<N>(culture)
-        } else {
-            self.string_num.as_str().to_number::<N>()
+    /// Return the culture independent representation of the string number, without going through a number :
+    /// no thousand separator, dot as decimal separator, minus sign kept and all the digits kept (ex: "1 234,560" French = "1234.560")
+    /// Return UnableToConvertStringToNumber if the string doesn't match a pattern (ex: misplaced separators)
+    /// Ref 'test_get_normalized'
+    pub fn get_normalized(&self) -> Result<String, ConversionError> {
+        if self.get_current_pattern().is_none() {
+            return Err(ConversionError::UnableToConvertStringToNumber);
         }
+
+        // The pattern has matched, so the thousand separators are well placed and can be removed
+        let cleaned = self.clean();
+        Ok(cleaned.strip_prefix('+').unwrap_or(&cleaned).to_owned())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::NumberPatterns;
-    use super::NumberType;
-    use super::Separator;
-    use crate::errors::ConversionError;
-    use crate::pattern::ConvertString;
-    use crate::pattern::CulturePattern;
-    use crate::pattern::TypeParsing;
-    use crate::Culture;
-    use crate::NumberCultureSettings;
-    use regex::Regex;
+    /// Return the canonical representation of the string number : like 'get_normalized', but the whole part is always written
+    /// (ex: ",25" French = "0.25", "-.5" English = "-0.5"), and a trailing decimal separator is removed ("1,000." English = "1000")
+    /// Ref 'test_canonical_string'
+    pub fn canonical_string(&self) -> Result<String, ConversionError> {
+        let parts = self.split_parts()?;
+        let sign = match parts.sign {
+            Sign::Negative => "-",
+            Sign::Positive => "",
+        };
 
-    #[test]
-    fn test_number_type() {
-        assert_eq!(
-            NumberType::DECIMAL,
-            NumberType::from(&TypeParsing::DecimalSimple)
-        );
-        assert_eq!(
-            NumberType::DECIMAL,
-            NumberType::from(&TypeParsing::DecimalThousandSeparator)
-        );
-        assert_eq!(
-            NumberType::DECIMAL,
-            NumberType::from(&TypeParsing::DecimalWithoutWholePart)
-        );
-        assert_eq!(
-            NumberType::WHOLE,
-            NumberType::from(&TypeParsing::WholeSimple)
-        );
+        Ok(match parts.decimal.filter(|decimal| !decimal.is_empty()) {
+            Some(decimal) => format!("{}{}.{}", sign, parts.whole, decimal),
+            None => format!("{}{}", sign, parts.whole),
+        })
     }
 
-    #[test]
-    fn test_regex() {
-        let r = Regex::new(r"[\-\+]?\d+([0-9]{3})*").unwrap();
-        assert!(r.is_match("10,2"));
+    /// Split the string number into its sign, whole part and decimal part, from the matched pattern
+    /// Ref 'test_split_parts'
+    pub fn split_parts(&self) -> Result<NumberParts, ConversionError> {
+        let normalized = self.get_normalized()?;
+        let (sign, unsigned) = match normalized.strip_prefix('-') {
+            Some(unsigned) => (Sign::Negative, unsigned),
+            None => (Sign::Positive, normalized.as_str()),
+        };
+        let (whole, decimal) = match unsigned.split_once('.') {
+            Some((whole, decimal)) => (whole, Some(decimal.to_owned())),
+            None => (unsigned, None),
+        };
+
+        Ok(NumberParts {
+            sign,
+            whole: if whole.is_empty() { String::from("0") } else { whole.to_owned() },
+            decimal,
+        })
     }
 
-    #[test]
-    fn test_separator() {
-        let comma_str: char = Separator::COMMA.into();
-        assert_eq!(',', comma_str);
-        assert_eq!(Separator::SPACE, " ".try_into().unwrap());
-        assert_eq!(
-            Err(ConversionError::SeparatorNotFound),
-            Separator::try_from("i_am_not_well_formatted")
-        );
+    /// Return the sign of the string number from the matched pattern, without converting it (None if not numeric)
+    /// Ref 'test_sign'
+    pub fn sign(&self) -> Option<Sign> {
+        self.split_parts().ok().map(|parts| parts.sign)
+    }
 
-        assert_eq!(Separator::DOT.to_owned_string(), String::from("."));
+    /// Return true if the string number is numeric and has the minus sign (even "-0")
+    pub fn is_negative(&self) -> bool {
+        self.sign() == Some(Sign::Negative)
+    }
 
-        assert_eq!(Separator::COMMA.to_string_regex(), String::from("[,]"));
-        assert_eq!(Separator::DOT.to_string_regex(), String::from("[\\.]"));
-        assert_eq!(Separator::SPACE.to_string_regex(), String::from(r"[\s]"));
-        assert_eq!(Separator::SPACE.to_string_regex(), String::from("[\\s]"));
+    /// Return true if the string number is numeric, with the plus sign or no sign
+    pub fn is_positive(&self) -> bool {
+        self.sign() == Some(Sign::Positive)
     }
 
-    #[test]
-    fn test_parsing_pattern_fr() {
-        let optionnal_fr_pattern = NumberPatterns::default().get_culture_pattern(&Culture::French);
+    /// Return true if the string is numeric and its thousand grouping is consistent with the culture :
+    /// every group except the leftmost has exactly the expected number of digits (see StringNumber::validate_grouping)
+    /// Ref 'test_is_well_grouped'
+    pub fn is_well_grouped(&self) -> bool {
+        if !self.is_numeric() {
+            return false;
+        }
+
+        // The string is numeric, so without culture there is a matched culture
+        let settings = self
+            .culture_settings()
+            .unwrap_or_else(|| Culture::default().into());
+        StringNumber::new_with_settings(self.string_num.to_string(), settings)
+            .validate_grouping()
+            .is_ok()
+    }
+
+    /// Return the number of digits after the decimal separator (0 for whole numbers), None if the string is not numeric
+    /// Ref 'test_fraction_digit_count'
+    pub fn fraction_digit_count(&self) -> Option<usize> {
+        let pattern = self.get_current_pattern()?;
+        if pattern.get_number_type() == &NumberType::WHOLE {
+            return Some(0);
+        }
+
+        // Once cleaned, the decimal separator is always a dot
+        let cleaned = self.clean();
+        Some(
+            cleaned
+                .split_once('.')
+                .map_or(0, |(_, decimal)| decimal.chars().filter(|c| c.is_ascii_digit()).count()),
+        )
+    }
+
+    /// Return the number of digits of the whole part, without the thousand separators, None if the string is not numeric.
+    /// The leading zeros are not counted : "007" has 1 digit, "0,5" (French) has 0 digit
+    /// Ref 'test_whole_and_decimal_digits'
+    pub fn whole_digits(&self) -> Option<usize> {
+        let parts = self.split_parts().ok()?;
+        Some(parts.whole.trim_start_matches('0').len())
+    }
+
+    /// Return the number of digits of the decimal part, trailing zeros included, None if the string is not numeric.
+    /// Ex : "12 345,6700" (French) has 4 decimal digits
+    /// Ref 'test_whole_and_decimal_digits'
+    pub fn decimal_digits(&self) -> Option<usize> {
+        let parts = self.split_parts().ok()?;
+        Some(parts.decimal.map_or(0, |decimal| decimal.len()))
+    }
+
+    /// Return the total number of digits of the canonical string number (see 'canonical_string'), None if the string is not numeric.
+    /// Unlike 'whole_digits', the leading zeros are counted (ex: an account number "00123456" has 8 digits)
+    /// Ref 'test_count_digits'
+    pub fn count_digits(&self) -> Option<usize> {
+        self.canonical_digit_counts().map(|(integer, fractional)| integer + fractional)
+    }
+
+    /// Return the number of digits of the integer part of the canonical string number, None if the string is not numeric.
+    /// A missing integer part is written "0" by 'canonical_string', so ",5" (French) has 1 integer digit
+    /// Ref 'test_count_digits'
+    pub fn integer_digit_count(&self) -> Option<usize> {
+        self.canonical_digit_counts().map(|(integer, _)| integer)
+    }
+
+    /// Return the number of digits of the fractional part of the canonical string number (trailing zeros included),
+    /// None if the string is not numeric
+    /// Ref 'test_count_digits'
+    pub fn fractional_digit_count(&self) -> Option<usize> {
+        self.canonical_digit_counts().map(|(_, fractional)| fractional)
+    }
+
+    /// Count the digits of the integer and fractional parts of the canonical string number
+    fn canonical_digit_counts(&self) -> Option<(usize, usize)> {
+        let canonical = self.canonical_string().ok()?;
+        let unsigned = canonical.strip_prefix('-').unwrap_or(&canonical);
+        let (integer, fractional) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        Some((integer.chars().count(), fractional.chars().count()))
+    }
+
+    /// Return true if the string number can be converted to the type N (the conversion is done, the value is dropped).
+    ///
+    /// Unlike 'fits_in', there is no bound needed on N, and an integer type rejects any decimal part ("1.00" in i32)
+    /// Ref 'test_fits'
+    pub fn fits<N: num::Num + Display + FromStr>(&self) -> bool {
+        self.to_number::<N>().is_ok()
+    }
+
+    /// Return true if the string number fits in the type N, without converting it.
+    ///
+    /// The digits are compared to the bounds of the type, so a 25 digits integer doesn't fit in i64 but fits in i128.
+    /// For the integer types, the decimal part must be zero ("1.50" doesn't fit in i32, "1.00" does).
+    /// For the float types only the magnitude is checked, the decimal part is rounded by the conversion.
+    /// Ref 'test_fits_in'
+    pub fn fits_in<N: num::Num + Bounded + Display>(&self) -> bool {
+        let parts = match self.split_parts() {
+            Ok(parts) => parts,
+            Err(_) => return false,
+        };
+
+        // The integer division truncates 1 / 2 to 0
+        let is_integer = (N::one() / (N::one() + N::one())).is_zero();
+        let has_fraction = parts.decimal.as_ref().is_some_and(|decimal| decimal.chars().any(|c| c != '0'));
+        if is_integer && has_fraction {
+            return false;
+        }
+
+        let whole = parts.whole.trim_start_matches('0');
+        if whole.is_empty() {
+            return true;
+        }
+
+        let bound = match parts.sign {
+            Sign::Positive => N::max_value().to_string(),
+            Sign::Negative => N::min_value().to_string(),
+        };
+        // The unsigned minimum is 0, it has no minus sign
+        let bound = match (parts.sign, bound.strip_prefix('-')) {
+            (Sign::Negative, None) => return false,
+            (_, Some(bound)) => bound.to_owned(),
+            (_, None) => bound,
+        };
+        let bound = bound.split('.').next().unwrap_or_default();
+
+        match whole.len().cmp(&bound.len()) {
+            Ordering::Less => true,
+            Ordering::Equal => whole <= bound,
+            Ordering::Greater => false,
+        }
+    }
+
+    /// Convert the string number with the parts captured by the matched pattern, so the conversion uses exactly
+    /// the separators validated by the pattern. Without matching pattern (or for a custom pattern without the parts),
+    /// the string is cleaned with the culture separators
+    /// Ref 'test_to_number_matched_pattern'
+    pub fn to_number<N: num::Num + Display + FromStr>(&self) -> Result<N, ConversionError> {
+        if let Some(captured) = self.captured_number() {
+            return captured.as_str().to_number::<N>();
+        }
+
+        let string_num = self.number_capture();
+        if let Some(settings) = self.culture_settings() {
+            string_num.to_number_separators::<N>(settings)
+        } else {
+            string_num.to_number::<N>()
+        }
+    }
+
+    /// Build the culture independent string number ("-1000.5") from the parts captured by the matched pattern
+    fn captured_number(&self) -> Option<String> {
+        let pattern = self.get_current_pattern()?;
+        let captures = pattern.get_regex().captures(&self.string_num)?;
+        if captures.whole.is_none() && captures.decimal.is_none() {
+            return None;
+        }
+
+        // Only the digits are kept, the thousand separators have been validated by the pattern
+        let digits = |part: Option<&str>| part.unwrap_or_default().chars().filter(|c| c.is_numeric()).collect::<String>();
+        let sign = match captures.sign {
+            Sign::Negative => "-",
+            Sign::Positive => "",
+        };
+        let decimal = digits(captures.decimal);
+
+        Some(match decimal.is_empty() {
+            true => format!("{}{}", sign, digits(captures.whole)),
+            false => format!("{}{}.{}", sign, digits(captures.whole), decimal),
+        })
+    }
+
+    /// Return the part captured by the 'number' group of the matched pattern (custom patterns), or the whole string
+    fn number_capture(&self) -> &str {
+        self.get_current_pattern()
+            .and_then(|pattern| {
+                pattern
+                    .get_regex()
+                    .get_regex()
+                    .captures(&self.string_num)
+                    .and_then(|captures| captures.name("number"))
+                    .map(|number| number.range())
+            })
+            .map_or(self.string_num.as_ref(), |range| &self.string_num[range])
+    }
+
+    /// Return the separators of the culture (or of the matched culture without culture), from the loaded culture pattern if any
+    fn culture_settings(&self) -> Option<NumberCultureSettings> {
+        if self.custom_culture.is_some() {
+            return self.custom_culture_pattern().map(|pattern| *pattern.get_settings());
+        }
+
+        self.culture.or_else(|| self.matched_culture()).map(|culture| {
+            self.all_patterns
+                .get_culture_pattern(&culture)
+                .map(|pattern| *pattern.get_settings())
+                .unwrap_or_else(|| culture.into())
+        })
+    }
+
+    /// Convert to number, without failing when the number has to be truncated or rounded.
+    /// Return the number, and true if some digits have been lost (decimal part dropped for integer types,
+    /// precision lost for float types). The digits are compared as strings, not as floats.
+    /// It still fails for non numeric strings, or numbers which doesn't fit in the type.
+    /// Ref 'test_to_number_lossy'
+    pub fn to_number_lossy<N: num::Num + Display + FromStr>(&self) -> Result<(N, bool), ConversionError> {
+        if !self.is_numeric() {
+            return Err(ConversionError::UnableToConvertStringToNumber);
+        }
+
+        let cleaned = self.clean();
+        let (whole, decimal) = cleaned.split_once('.').unwrap_or((cleaned.as_str(), ""));
+        let number = match StringNumber::new(cleaned.clone()).to_number::<N>() {
+            Ok(number) => number,
+            // Integer type, the decimal part is dropped
+            Err(ConversionError::UnableToConvertStringToNumber) if !decimal.is_empty() => {
+                StringNumber::new(whole.to_owned()).to_number::<N>()?
+            }
+            Err(e) => return Err(e),
+        };
+
+        let has_lost_digits = ConvertString::normalize_digits(&number.to_string())
+            != ConvertString::normalize_digits(&cleaned);
+        Ok((number, has_lost_digits))
+    }
+
+    /// Explain why the string number is (or isn't) numeric : the patterns tried for the culture, the nearest miss,
+    /// and the culture whose patterns would have matched. The culture is the given one, or the matched one without culture
+    /// (the default culture when no culture matches)
+    /// Ref 'test_explain'
+    pub fn explain(&self) -> ParseDiagnostics {
+        let culture = self.culture.or_else(|| self.matched_culture()).unwrap_or_default();
+        let attempts = self
+            .all_patterns
+            .iter_patterns(&culture)
+            .map(|pattern| PatternAttempt {
+                name: pattern.name().to_owned(),
+                matched: pattern.get_regex().is_match(&self.string_num),
+                matched_up_to: pattern.get_regex().matched_prefix_len(&self.string_num),
+            })
+            .collect::<Vec<PatternAttempt>>();
+
+        let is_numeric = attempts.iter().any(|attempt| attempt.matched);
+        let nearest_miss = match is_numeric {
+            true => None,
+            // The first pattern which went the furthest
+            false => attempts
+                .iter()
+                .rev()
+                .max_by_key(|attempt| attempt.matched_up_to)
+                .cloned(),
+        };
+        let suggested_culture = match is_numeric {
+            true => None,
+            false => enum_iterator::all::<Culture>()
+                .filter(|other| other != &culture)
+                .find(|other| ConvertString::find_pattern(&self.string_num, other, &self.all_patterns).is_some()),
+        };
+
+        ParseDiagnostics {
+            input: self.string_num.to_string(),
+            culture,
+            attempts,
+            nearest_miss,
+            suggested_culture,
+        }
+    }
+
+    /// Convert to number, and return the default value if the conversion fails
+    /// Ref 'test_convert_string_to_number_or'
+    pub fn to_number_or<N: num::Num + Display + FromStr>(&self, default: N) -> N {
+        self.to_number().unwrap_or(default)
+    }
+
+    /// Convert to number, and compute the value from the error if the conversion fails
+    /// Ref 'test_convert_string_to_number_or'
+    pub fn to_number_or_else<N: num::Num + Display + FromStr, F: FnOnce(ConversionError) -> N>(&self, f: F) -> N {
+        self.to_number().unwrap_or_else(f)
+    }
+
+    /// Convert to number, None if the conversion fails
+    /// Ref 'test_convert_string_to_number_or'
+    pub fn to_number_ok<N: num::Num + Display + FromStr>(&self) -> Option<N> {
+        self.to_number().ok()
+    }
+
+    /// Convert to f64 whatever the number type (whole or decimal), None if the string is not numeric.
+    /// Best effort conversion for a preview : big integers lose their precision
+    /// Ref 'test_to_f64_lossy'
+    pub fn to_f64_lossy(&self) -> Option<f64> {
+        if !self.is_numeric() {
+            return None;
+        }
+
+        self.to_number::<f64>().ok()
+    }
+
+    /// Summarize the conversion in a human readable sentence : the matched culture and pattern, and the canonical form
+    /// Ex : "'1,000.5' is numeric in English (pattern EN_Decimal_Thousand_Separator) = 1000.5"
+    /// Ref 'test_convert_string_debug'
+    pub fn describe(&self) -> String {
+        let culture_name = |culture: Option<Culture>| match (&self.custom_culture, culture) {
+            (Some(name), _) => name.to_owned(),
+            (None, Some(culture)) => culture.display_name().to_owned(),
+            (None, None) => String::from("any culture"),
+        };
+
+        match self.find_match() {
+            Some((culture, pattern)) => match self.canonical_string() {
+                Ok(canonical) => format!("'{}' is numeric in {} (pattern {}) = {}", self.string_num, culture_name(culture), pattern.name(), canonical),
+                Err(_) => format!("'{}' is numeric in {} (pattern {})", self.string_num, culture_name(culture), pattern.name()),
+            },
+            None => format!("'{}' is not numeric in {}", self.string_num, culture_name(self.culture)),
+        }
+    }
+
+    /// Return the string number cleaned from its culture separators (the string actually parsed by to_number)
+    fn clean(&self) -> String {
+        match self.culture_settings() {
+            Some(settings) => StringNumber::new_with_settings(self.string_num.to_string(), settings),
+            None => StringNumber::new(self.string_num.to_string()),
+        }
+        .clean()
+    }
+
+    /// Remove the non significant characters of a cleaned string number (sign +, leading and trailing zeros)
+    fn normalize_digits(string_num: &str) -> String {
+        let (sign, unsigned) = match string_num.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", string_num.trim_start_matches('+')),
+        };
+        let (whole, decimal) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        let whole = whole.trim_start_matches('0');
+        let decimal = decimal.trim_end_matches('0');
+        let digits = match (whole.is_empty(), decimal.is_empty()) {
+            (true, true) => return String::from("0"),
+            (_, true) => whole.to_owned(),
+            (true, _) => format!("0.{}", decimal),
+            _ => format!("{}.{}", whole, decimal),
+        };
+
+        format!("{}{}", sign, digits)
+    }
+}
+
+/// Show the parsed components rather than the patterns, to debug a conversion
+/// Ex : ConvertString { raw: "1,000.5", culture: Some(English), numeric: true, pattern: "EN_Decimal_Thousand_Separator", canonical: "1000.5" }
+/// Ref 'test_convert_string_debug'
+impl std::fmt::Debug for ConvertString<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pattern = self.get_current_pattern();
+        let pattern_name = pattern.as_ref().map(|pattern| pattern.name());
+        let canonical = self.canonical_string().ok();
+
+        let mut debug = f.debug_struct("ConvertString");
+        debug.field("raw", &self.string_num).field("culture", &self.culture);
+        if let Some(custom_culture) = &self.custom_culture {
+            debug.field("custom_culture", custom_culture);
+        }
+        debug.field("numeric", &pattern.is_some());
+        match (pattern_name, canonical) {
+            (Some(pattern_name), Some(canonical)) => debug.field("pattern", &pattern_name).field("canonical", &canonical),
+            (Some(pattern_name), None) => debug.field("pattern", &pattern_name).field("canonical", &None::<String>),
+            _ => debug.field("pattern", &None::<String>).field("canonical", &None::<String>),
+        };
+        debug.finish()
+    }
+}
+
+/// The sign of a number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sign {
+    Positive,
+    Negative,
+}
+
+/// The parts of a string number, without separators
+/// Ex : "-1 234,56" in French = (Negative, "1234", Some("56"))
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberParts {
+    pub sign: Sign,
+    /// The digits of the whole part ("0" when the string has no whole part, ex: ",5")
+    pub whole: String,
+    /// The digits of the decimal part, None for whole numbers
+    pub decimal: Option<String>,
+}
+
+/// All the information about a string number conversion
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseContext<N> {
+    /// The converted number
+    pub value: N,
+    /// The string number given in input
+    pub original: String,
+    /// The string number actually parsed, after the separators have been cleaned
+    pub cleaned: String,
+    /// The culture used for the conversion : the given one, or the matched one without culture
+    /// (None for the culture independent conversion, when no pattern matches)
+    pub culture_used: Option<Culture>,
+    /// The name of the matching pattern, if any
+    pub pattern_name: Option<String>,
+}
+
+/// A pattern tried by ConvertString::explain
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternAttempt {
+    /// The name of the pattern (ex: EN_Whole_Thousand_Separator)
+    pub name: String,
+    pub matched: bool,
+    /// The number of characters matched before the pattern stopped matching (the whole input when matched)
+    pub matched_up_to: usize,
+}
+
+/// The diagnostics of a string number parsing, see ConvertString::explain
+/// The Display is a human readable message (ex: to explain an import error)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseDiagnostics {
+    /// The string number given in input
+    pub input: String,
+    /// The culture whose patterns have been tried
+    pub culture: Culture,
+    /// All the patterns tried, common patterns first
+    pub attempts: Vec<PatternAttempt>,
+    /// The pattern which went the furthest in the input, when no pattern matched
+    pub nearest_miss: Option<PatternAttempt>,
+    /// Another culture with a matching pattern, when no pattern matched
+    pub suggested_culture: Option<Culture>,
+}
+
+impl ParseDiagnostics {
+    /// Return true if a pattern matched the string number
+    pub fn is_numeric(&self) -> bool {
+        self.attempts.iter().any(|attempt| attempt.matched)
+    }
+}
+
+impl Display for ParseDiagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(attempt) = self.attempts.iter().find(|attempt| attempt.matched) {
+            return write!(f, "'{}' is a valid {} number (pattern {})", self.input, self.culture.display_name(), attempt.name);
+        }
+
+        write!(f, "'{}' is not a valid {} number", self.input, self.culture.display_name())?;
+        if let Some(nearest) = &self.nearest_miss {
+            match self.input.chars().nth(nearest.matched_up_to) {
+                Some(c) => write!(
+                    f,
+                    ", the nearest pattern {} stopped at position {} ('{}')",
+                    nearest.name, nearest.matched_up_to, c
+                )?,
+                None => write!(f, ", the nearest pattern {} stopped at the end of the input", nearest.name)?,
+            }
+        }
+        if let Some(culture) = self.suggested_culture {
+            write!(f, ". It looks like {} formatting", culture.display_name())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The kind of a valid string number, see 'validate'
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberInfo {
+    pub number_type: NumberType,
+    pub type_parsing: TypeParsing,
+    pub sign: Sign,
+    /// The number of digits of the whole part, leading zeros excluded (see ConvertString::whole_digits)
+    pub whole_digits: usize,
+    /// The number of digits of the decimal part, trailing zeros included (see ConvertString::decimal_digits)
+    pub decimal_digits: usize,
+}
+
+/// Return the kind of the string number if it is valid in the culture, None otherwise.
+/// Lighter than ConvertString : the built-in patterns are shared and the string is only copied to read the typographic minus sign.
+/// The pattern is chosen as in ConvertString::find_pattern (priority, then the type specificity, then the longest regex, then the first one)
+/// Ref 'test_validate'
+pub fn validate(s: &str, culture: Culture) -> Option<NumberInfo> {
+    let s = if s.contains(MINUS_SIGN) {
+        Cow::Owned(s.replace(MINUS_SIGN, "-"))
+    } else {
+        Cow::Borrowed(s)
+    };
+    let s = s.as_ref();
+
+    let pattern = ConvertString::load_patterns()
+        .iter_patterns(&culture)
+        .filter(|pattern| pattern.regex.is_match(s))
+        .fold(None, |best: Option<&ParsingPattern>, pattern| match best {
+            Some(best) if best.match_order_key() >= pattern.match_order_key() => Some(best),
+            _ => Some(pattern),
+        })?;
+    let captures = pattern.regex.captures(s)?;
+
+    Some(NumberInfo {
+        number_type: pattern.get_number_type().clone(),
+        type_parsing: pattern.regex.get_type_parsing().clone(),
+        sign: captures.sign,
+        whole_digits: captures.whole.map_or(0, |whole| {
+            whole.chars().filter(|c| c.is_numeric()).skip_while(|c| *c == '0').count()
+        }),
+        decimal_digits: captures.decimal.map_or(0, |decimal| decimal.chars().count()),
+    })
+}
+
+/// Convert the string number, and return the value with the conversion information (cleaned string, pattern etc.)
+/// Useful to log what happened in a parsing pipeline
+/// Ref 'test_parse_with_context'
+pub fn parse_with_context<N: num::Num + Display + FromStr>(
+    s: &str,
+    culture: Option<Culture>,
+) -> Result<ParseContext<N>, ConversionError> {
+    let convert = ConvertString::new(s, culture);
+
+    Ok(ParseContext {
+        value: convert.to_number::<N>()?,
+        original: s.to_owned(),
+        cleaned: convert.clean(),
+        culture_used: culture.or_else(|| convert.matched_culture()),
+        pattern_name: convert.get_current_pattern().map(|p| p.name().to_owned()),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use super::NumberPatterns;
+    use super::NumberType;
+    use super::Separator;
+    use crate::errors::ConversionError;
+    use crate::pattern::{parse_with_context, validate, ConvertString, NumberInfo, NumberParts, ParseContext, ParsingPattern, Sign};
+    use crate::pattern::CulturePattern;
+    use crate::pattern::{NumberCaptures, ParseDiagnostics, PatternAttempt, RegexPattern, ThousandGrouping, TypeParsing};
+    use crate::Culture;
+    use crate::NumberCultureSettings;
+    use crate::NumberConversion;
+    use crate::ToFormat;
+    use regex::Regex;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_number_type() {
+        assert_eq!(
+            NumberType::DECIMAL,
+            NumberType::from(&TypeParsing::DecimalSimple)
+        );
+        assert_eq!(
+            NumberType::DECIMAL,
+            NumberType::from(&TypeParsing::DecimalThousandSeparator)
+        );
+        assert_eq!(
+            NumberType::DECIMAL,
+            NumberType::from(&TypeParsing::DecimalWithoutWholePart)
+        );
+        assert_eq!(
+            NumberType::DECIMAL,
+            NumberType::from(&TypeParsing::NumberWithUnit)
+        );
+        assert_eq!(
+            NumberType::WHOLE,
+            NumberType::from(&TypeParsing::WholeSimple)
+        );
+    }
+
+    #[test]
+    fn test_regex() {
+        let r = Regex::new(r"[\-\+]?\d+([0-9]{3})*").unwrap();
+        assert!(r.is_match("10,2"));
+    }
+
+    #[test]
+    fn test_separator() {
+        let comma_str: char = Separator::COMMA.into();
+        assert_eq!(',', comma_str);
+        assert_eq!(Separator::SPACE, " ".try_into().unwrap());
+        assert_eq!(
+            Err(ConversionError::SeparatorNotFound),
+            Separator::try_from("i_am_not_well_formatted")
+        );
+
+        assert_eq!(Separator::DOT.to_owned_string(), String::from("."));
+
+        assert_eq!(Separator::COMMA.to_string_regex(), String::from("[,]"));
+        assert_eq!(Separator::DOT.to_string_regex(), String::from("[\\.]"));
+        assert_eq!(Separator::SPACE.to_string_regex(), String::from(r"[\s]"));
+        assert_eq!(Separator::SPACE.to_string_regex(), String::from("[\\s]"));
+        assert_eq!(Separator::APOSTROPHE.to_string_regex(), String::from("['\u{2019}\u{02BC}]"));
+    }
+
+    #[test]
+    fn test_format_spec() {
+        assert_eq!(NumberCultureSettings::from(Culture::English).to_format_spec(2), "#,##0.00");
+        assert_eq!(NumberCultureSettings::from(Culture::French).to_format_spec(2), "# ##0,00");
+        assert_eq!(NumberCultureSettings::from(Culture::Italian).to_format_spec(2), "#.##0,00");
+        assert_eq!(NumberCultureSettings::from(Culture::Indian).to_format_spec(3), "#,##,##0.000");
+        assert_eq!(NumberCultureSettings::from(Culture::English).to_format_spec(0), "#,##0");
+        assert_eq!(NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT).to_format_spec(1), "#'##0.0");
+
+        // Round trip for all the cultures
+        for culture in Culture::all() {
+            let settings = NumberCultureSettings::from(culture);
+            assert_eq!(NumberCultureSettings::from_format_spec(&settings.to_format_spec(2)), Ok(settings), "{:?}", culture);
+        }
+        assert_eq!(NumberCultureSettings::from_format_spec("# ##0,00"), Ok(NumberCultureSettings::FRENCH));
+        assert_eq!(NumberCultureSettings::from_format_spec("#,##0.##"), Ok(NumberCultureSettings::ENGLISH));
+        assert_eq!(NumberCultureSettings::from_format_spec("##,##,##0"), Ok(NumberCultureSettings::INDIAN));
+        assert_eq!(NumberCultureSettings::from_format_spec("#,###,##0"), Ok(NumberCultureSettings::ENGLISH));
+        // The decimal separator is deduced without decimal part
+        assert_eq!(NumberCultureSettings::from_format_spec("#,##0"), Ok(NumberCultureSettings::ENGLISH));
+        assert_eq!(NumberCultureSettings::from_format_spec("#.##0"), Ok(NumberCultureSettings::ITALIAN));
+
+        // Errors
+        assert_eq!(NumberCultureSettings::from_format_spec("0.00"), Err(ConversionError::InvalidFormatPattern { position: 1 }));
+        assert_eq!(NumberCultureSettings::from_format_spec("#,##"), Err(ConversionError::InvalidFormatPattern { position: 1 }));
+        assert_eq!(NumberCultureSettings::from_format_spec("#,##0.00.0"), Err(ConversionError::InvalidFormatPattern { position: 8 }));
+        assert_eq!(NumberCultureSettings::from_format_spec("#,##0 "), Err(ConversionError::InvalidFormatPattern { position: 5 }));
+        assert_eq!(NumberCultureSettings::from_format_spec("#,#,##0"), Err(ConversionError::InvalidFormatPattern { position: 3 }));
+        assert_eq!(NumberCultureSettings::from_format_spec("0000"), Err(ConversionError::SeparatorNotFound));
+        assert_eq!(NumberCultureSettings::from_format_spec(""), Err(ConversionError::SeparatorNotFound));
+    }
+
+    #[test]
+    fn test_number_culture_settings_consts() {
+        assert_eq!(NumberCultureSettings::FRENCH, NumberCultureSettings::from(Culture::French));
+        assert_eq!(NumberCultureSettings::ENGLISH, NumberCultureSettings::new(Separator::COMMA, Separator::DOT));
+        assert_eq!(NumberCultureSettings::ITALIAN, NumberCultureSettings::new(Separator::DOT, Separator::COMMA));
+        assert_eq!(NumberCultureSettings::TURKISH, NumberCultureSettings::from(Culture::Turkish));
+        assert_eq!(
+            NumberCultureSettings::INDIAN,
+            NumberCultureSettings::new(Separator::COMMA, Separator::DOT).with_grouping(ThousandGrouping::TwoBlock)
+        );
+        for culture in enum_iterator::all::<Culture>() {
+            let settings = NumberCultureSettings::from(culture);
+            assert_ne!(settings.thousand_separator(), settings.decimal_separator());
+        }
+
+        // Usable in a const context
+        const SETTINGS: NumberCultureSettings = NumberCultureSettings::ENGLISH;
+        assert_eq!("1,000.5".to_number_separators::<f64>(SETTINGS), Ok(1000.5));
+    }
+
+    #[test]
+    fn test_number_culture_settings_try_new() {
+        assert_eq!(
+            NumberCultureSettings::try_new(Separator::COMMA, Separator::DOT),
+            Ok(NumberCultureSettings::new(Separator::COMMA, Separator::DOT))
+        );
+        assert_eq!(
+            NumberCultureSettings::try_new(Separator::DOT, Separator::DOT),
+            Err(ConversionError::DuplicateSeparators { separator: Separator::DOT })
+        );
+        assert_eq!(
+            NumberCultureSettings::try_new(Separator::CUSTOM('|'), Separator::CUSTOM('|')),
+            Err(ConversionError::DuplicateSeparators { separator: Separator::CUSTOM('|') })
+        );
+    }
+
+    #[test]
+    fn test_swap_separators() {
+        let values = vec![
+            (Culture::English, Ok(NumberCultureSettings::ITALIAN)),
+            (Culture::Italian, Ok(NumberCultureSettings::ENGLISH)),
+            (Culture::Turkish, Ok(NumberCultureSettings::ENGLISH)),
+            (
+                Culture::Indian,
+                Ok(NumberCultureSettings::new(Separator::DOT, Separator::COMMA).with_grouping(ThousandGrouping::TwoBlock)),
+            ),
+            (Culture::French, Err(ConversionError::InvalidDecimalSeparator { separator: Separator::SPACE })),
+        ];
+        for (culture, expected) in values {
+            let settings = NumberCultureSettings::from(culture);
+            assert_eq!(settings.swap_separators(), expected, "{:?}", culture);
+
+            // Swapping twice gives the original settings
+            if let Ok(swapped) = settings.swap_separators() {
+                assert_eq!(swapped.swap_separators(), Ok(settings));
+            }
+        }
+
+        // Custom separators
+        let settings = NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT);
+        let swapped = settings.swap_separators().unwrap();
+        assert_eq!(swapped.thousand_separator(), Separator::DOT);
+        assert_eq!(swapped.decimal_separator(), Separator::APOSTROPHE);
+        assert_eq!(swapped.swap_separators(), Ok(settings));
+
+        let settings = NumberCultureSettings::new(Separator::UNDERSCORE, Separator::CUSTOM('|'));
+        assert_eq!(
+            settings.swap_separators(),
+            Ok(NumberCultureSettings::new(Separator::CUSTOM('|'), Separator::UNDERSCORE))
+        );
+        assert_eq!(
+            NumberCultureSettings::new(Separator::TAB, Separator::COMMA).swap_separators(),
+            Err(ConversionError::InvalidDecimalSeparator { separator: Separator::TAB })
+        );
+        // The custom whitespaces too (no-break space, narrow no-break space)
+        for separator in [Separator::CUSTOM('\u{a0}'), Separator::CUSTOM('\u{202f}')] {
+            assert_eq!(
+                NumberCultureSettings::new(separator, Separator::COMMA).swap_separators(),
+                Err(ConversionError::InvalidDecimalSeparator { separator })
+            );
+        }
+        // A whitespace can still be the thousand separator of the swapped settings
+        assert_eq!(
+            NumberCultureSettings::new(Separator::DOT, Separator::SPACE).swap_separators(),
+            Ok(NumberCultureSettings::new(Separator::SPACE, Separator::DOT))
+        );
+
+        // A swapped export is read back
+        let swapped = NumberCultureSettings::ENGLISH.swap_separators().unwrap();
+        assert_eq!("1.234.567,5".to_number_separators::<f64>(swapped).unwrap(), 1234567.5);
+        assert_eq!(1234567.5.to_format_separators("N1", swapped).unwrap(), "1.234.567,5");
+    }
+
+    #[test]
+    fn test_format_example() {
+        let values = vec![
+            (Culture::English, "1,234,567", "1,234.57", "-12.500", "1,234.57 = 1234.57"),
+            (Culture::French, "1 234 567", "1 234,57", "-12,500", "1 234,57 = 1234.57"),
+            (Culture::Italian, "1.234.567", "1.234,57", "-12,500", "1.234,57 = 1234.57"),
+            (Culture::Turkish, "1.234.567", "1.234,57", "-12,500", "1.234,57 = 1234.57"),
+            (Culture::Indian, "12,34,567", "1,234.57", "-12.500", "1,234.57 = 1234.57"),
+        ];
+
+        for (culture, whole, float, negative, pair) in values {
+            let settings = NumberCultureSettings::from(culture);
+            assert_eq!(settings.format_example(1234567), Ok(whole.to_owned()), "{:?}", culture);
+            assert_eq!(settings.format_example_float(1234.567, 2), Ok(float.to_owned()), "{:?}", culture);
+            assert_eq!(settings.format_example_float(-12.5, 3), Ok(negative.to_owned()), "{:?}", culture);
+            assert_eq!(settings.display_example_pair(1234.567), pair, "{:?}", culture);
+        }
+
+        assert_eq!(NumberCultureSettings::from(Culture::English).format_example(-42), Ok("-42".to_owned()));
+    }
+
+    #[test]
+    fn test_separator_collections() {
+        let names: HashMap<Separator, &str> = HashMap::from([
+            (Separator::COMMA, "comma"),
+            (Separator::SPACE, "space"),
+            (Separator::CUSTOM('|'), "pipe"),
+        ]);
+        assert_eq!(names.get(&Separator::COMMA), Some(&"comma"));
+        assert_eq!(names.get(&Separator::CUSTOM('|')), Some(&"pipe"));
+        assert_eq!(names.get(&Separator::CUSTOM('#')), None);
+
+        let mut separators = vec![
+            Separator::CUSTOM('b'),
+            Separator::MIDDLEDOT,
+            Separator::SPACE,
+            Separator::CUSTOM('a'),
+            Separator::APOSTROPHE,
+            Separator::DOT,
+            Separator::COMMA,
+        ];
+        separators.sort();
+        assert_eq!(
+            separators,
+            vec![
+                Separator::COMMA,
+                Separator::DOT,
+                Separator::SPACE,
+                Separator::APOSTROPHE,
+                Separator::MIDDLEDOT,
+                Separator::CUSTOM('a'),
+                Separator::CUSTOM('b'),
+            ]
+        );
+        assert!(Separator::APOSTROPHE < Separator::CUSTOM('\0'));
+
+        let groupings: HashSet<ThousandGrouping> = HashSet::from([
+            ThousandGrouping::ThreeBlock,
+            ThousandGrouping::TwoBlock,
+            ThousandGrouping::ThreeBlock,
+        ]);
+        assert_eq!(groupings.len(), 2);
+
+        let types: HashSet<TypeParsing> = NumberPatterns::default()
+            .get_culture_pattern(&Culture::English)
+            .unwrap()
+            .get_patterns()
+            .iter()
+            .map(|p| p.get_regex().get_type_parsing().clone())
+            .collect();
+        assert_eq!(types.len(), 4);
+        assert!(types.contains(&TypeParsing::DecimalThousandSeparator));
+    }
+
+    #[test]
+    fn test_separator_try_from_apostrophe() {
+        assert_eq!(Separator::try_from("'"), Ok(Separator::APOSTROPHE));
+        assert_ne!(Separator::try_from("'"), Ok(Separator::CUSTOM('\'')));
+
+        // The settings use the apostrophe separator, which also match the typographic apostrophes
+        let settings = NumberCultureSettings::from(("'", "."));
+        assert_eq!(settings.thousand_separator(), Separator::APOSTROPHE);
+        assert_eq!(settings, NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT));
+        assert_eq!("1\u{2019}000.5".to_number_separators::<f64>(settings).unwrap(), 1000.5);
+    }
+
+    #[test]
+    fn test_separator_from_char() {
+        let values = vec![
+            (',', Separator::COMMA, false, true),
+            ('.', Separator::DOT, false, true),
+            (' ', Separator::SPACE, true, false),
+            ('\'', Separator::APOSTROPHE, false, true),
+            ('·', Separator::MIDDLEDOT, false, true),
+            ('|', Separator::CUSTOM('|'), false, true),
+            ('\u{202F}', Separator::CUSTOM('\u{202F}'), true, false),
+            ('x', Separator::CUSTOM('x'), false, false),
+        ];
+
+        for (c, separator, is_whitespace, is_punctuation) in values {
+            assert_eq!(Separator::from_char(c), separator);
+            assert_eq!(separator.to_char(), c);
+            assert_eq!(separator.to_string(), c.to_string());
+            assert_eq!(separator.is_whitespace(), is_whitespace, "{:?}", separator);
+            assert_eq!(separator.is_punctuation(), is_punctuation, "{:?}", separator);
+        }
+
+        assert_eq!(format!("1{}000", Separator::COMMA), "1,000");
+    }
+
+    #[test]
+    fn test_separator_tab() {
+        assert_eq!(Separator::from_char('\t'), Separator::TAB);
+        assert_eq!(Separator::try_from("\t"), Ok(Separator::TAB));
+        assert_eq!(Separator::TAB.to_char(), '\t');
+        assert_eq!(Separator::TAB.regex_class(), r"[\t]");
+        assert!(Separator::TAB.is_whitespace());
+        assert!(!Separator::TAB.is_punctuation());
+        assert_ne!(Separator::TAB, Separator::SPACE);
+
+        let tab_dot = NumberCultureSettings::new(Separator::TAB, Separator::DOT);
+        assert_eq!("1\t000".to_number_separators::<i32>(tab_dot), Ok(1000));
+        assert_eq!("-1\t234\t567.5".to_number_separators::<f64>(tab_dot), Ok(-1234567.5));
+        assert_eq!("1 000".to_number_separators::<i32>(tab_dot), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(1234567.to_format_separators("N1", tab_dot).unwrap(), "1\t234\t567.0");
+
+        let pattern = ParsingPattern::build(String::from("tsv"), TypeParsing::WholeThousandSeparator, Some(tab_dot)).unwrap();
+        assert!(pattern.get_regex().is_match("1\t000"));
+        assert!(!pattern.get_regex().is_match("1 000"));
+
+        // SPACE doesn't swallow the tabulation when TAB is the other separator
+        let tab_space = NumberCultureSettings::new(Separator::TAB, Separator::SPACE);
+        assert_eq!(tab_space.into_decimal_separator_regex(), r"[\s&&[^\t]]");
+        assert_eq!("1\t000 5".to_number_separators::<f64>(tab_space), Ok(1000.5));
+        let space_tab = NumberCultureSettings::new(Separator::SPACE, Separator::TAB);
+        assert_eq!("1 000\t5".to_number_separators::<f64>(space_tab), Ok(1000.5));
+        let pattern = ParsingPattern::build(String::from("tsv"), TypeParsing::DecimalThousandSeparator, Some(space_tab)).unwrap();
+        assert!(pattern.get_regex().is_match("1 000\t5"));
+        assert!(!pattern.get_regex().is_match("1\t000\t5"));
+
+        // Otherwise SPACE still matches all the whitespaces, and the tabulations are removed without settings
+        assert_eq!("1\t000,5".to_number_culture::<f64>(Culture::French), Ok(1000.5));
+        assert_eq!("1\t000".to_number::<i32>(), Ok(1000));
+    }
+
+    #[test]
+    fn test_separator_regex_class() {
+        assert_eq!(Separator::DOT.regex_class(), "[\\.]");
+        assert_eq!(Separator::COMMA.regex_class(), "[,]");
+        assert_eq!(Separator::SPACE.regex_class(), r"[\s]");
+        assert_eq!(Separator::CUSTOM('*').regex_class(), r"[\*]");
+
+        // Can be used to compose a custom regex
+        let regex = Regex::new(&format!(r"^[0-9]+{}[0-9]{{2}}$", Separator::DOT.regex_class())).unwrap();
+        assert!(regex.is_match("10.50"));
+        assert!(!regex.is_match("10,50"));
+        assert!(!regex.is_match("10a50"));
+    }
+
+    #[test]
+    fn test_separator_middledot() {
+        use crate::ToFormat;
+
+        assert_eq!(Separator::MIDDLEDOT, "\u{00B7}".try_into().unwrap());
+        assert_eq!(char::from(Separator::MIDDLEDOT), '\u{00B7}');
+        assert_eq!(Separator::MIDDLEDOT.to_owned_string(), String::from("·"));
+        assert_eq!(Separator::MIDDLEDOT.to_string_regex(), String::from("[·]"));
+
+        let settings = NumberCultureSettings::new(Separator::COMMA, Separator::MIDDLEDOT);
+        assert_eq!("1·5".to_number_separators::<f64>(settings).unwrap(), 1.5);
+        assert_eq!("1,000·25".to_number_separators::<f64>(settings).unwrap(), 1000.25);
+        assert_eq!(1.5.to_format_separators("N1", settings).unwrap(), "1·5");
+        assert_eq!(1000.25.to_format_separators("N2", settings).unwrap(), "1,000·25");
+    }
+
+    #[test]
+    fn test_parsing_pattern_fr() {
+        let optionnal_fr_pattern = NumberPatterns::default().get_culture_pattern(&Culture::French);
+
+        //We need to have an fr pattern
+        assert!(optionnal_fr_pattern.is_some());
+        let fr_pattern = optionnal_fr_pattern.unwrap();
+        assert_eq!(fr_pattern.get_name(), "fr");
+        assert!(!fr_pattern.get_patterns().is_empty());
+    }
+
+    #[test]
+    fn test_parsing_pattern_en() {
+        let optionnal_en_pattern = NumberPatterns::default().get_culture_pattern(&Culture::English);
+
+        //We need to have an en pattern
+        assert!(optionnal_en_pattern.is_some());
+        let en_pattern = optionnal_en_pattern.unwrap();
+        assert_eq!(en_pattern.get_name(), "en");
+        assert!(!en_pattern.get_patterns().is_empty());
+    }
+
+    #[test]
+    fn test_parsing_pattern_it() {
+        let optionnal_en_pattern = NumberPatterns::default().get_culture_pattern(&Culture::Italian);
+
+        //We need to have an it pattern
+        assert!(optionnal_en_pattern.is_some());
+        let en_pattern = optionnal_en_pattern.unwrap();
+        assert_eq!(en_pattern.get_name(), "it");
+        assert!(!en_pattern.get_patterns().is_empty());
+    }
+
+    #[test]
+    fn test_parsing_pattern_describe() {
+        let en_pattern = NumberPatterns::default().get_culture_pattern(&Culture::English).unwrap();
+        let en_decimal_simple = en_pattern
+            .get_patterns()
+            .iter()
+            .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
+            .unwrap();
+
+        let description = en_decimal_simple.describe();
+        assert!(description.contains("[EN_Decimal_Simple]"));
+        assert!(description.contains(&TypeParsing::DecimalSimple.to_string()));
+        assert!(description.contains(en_decimal_simple.regex.content.as_str()));
+        assert_eq!(description, r"[EN_Decimal_Simple] Decimal_Simple ^(?:(?P<sign>[\-\+]?)(?P<whole>[0-9]+)[\.](?P<decimal>[0-9]{1,}))$");
+
+        // Display is unchanged
+        assert_eq!(en_decimal_simple.to_string(), "[EN_Decimal_Simple]");
+    }
+
+    #[test]
+    fn test_culture_pattern_lookup() {
+        let mut patterns = NumberPatterns::default();
+
+        // Every culture is reachable, and the list is sorted by code
+        for culture in enum_iterator::all::<Culture>() {
+            let code: &str = culture.into();
+            assert_eq!(patterns.get_culture_pattern(&culture).unwrap().get_name(), code);
+        }
+        let codes = patterns
+            .get_all_culture_pattern()
+            .iter()
+            .map(|c| c.get_name().to_owned())
+            .collect::<Vec<String>>();
+        assert_eq!(codes, vec!["en", "fr", "id", "it", "tr"]);
+
+        // Adding twice the same culture replace it
+        patterns.add_culture_pattern(CulturePattern::new("fr", Culture::French.into()).unwrap());
+        assert_eq!(patterns.get_all_culture_pattern().len(), codes.len());
+
+        let removed = patterns.remove_culture_pattern(&Culture::French);
+        assert_eq!(removed.unwrap().get_culture(), Some(&Culture::French));
+        assert!(patterns.get_culture_pattern(&Culture::French).is_none());
+        assert!(patterns.remove_culture_pattern(&Culture::French).is_none());
+        assert_eq!(patterns.get_all_culture_pattern().len(), codes.len() - 1);
+
+        // Without the french pattern, only the common one can match
+        assert!(ConvertString::find_pattern("10", &Culture::French, &patterns).is_some());
+        assert!(ConvertString::find_pattern("10,5", &Culture::French, &patterns).is_none());
+    }
+
+    #[test]
+    fn test_custom_culture() {
+        let settings = NumberCultureSettings::new(Separator::APOSTROPHE, Separator::COMMA).with_grouping(ThousandGrouping::TwoBlock);
+        let custom = CulturePattern::new("xx", settings).unwrap();
+        assert_eq!(custom.get_name(), "xx");
+        assert_eq!(custom.get_culture(), None);
+
+        let mut patterns = NumberPatterns::default();
+        patterns.add_culture_pattern(custom);
+        assert_eq!(patterns.get_culture_pattern_by_name("xx").unwrap().get_settings(), &settings);
+        assert_eq!(patterns.get_culture_pattern_by_name("fr").unwrap().get_culture(), Some(&Culture::French));
+        assert!(patterns.get_culture_pattern_by_name("yy").is_none());
+
+        let convert = ConvertString::new("-12'34'567,5", None).with_custom_culture("xx").with_patterns(patterns.clone());
+        assert!(convert.is_numeric());
+        assert_eq!(convert.to_number::<f64>(), Ok(-1234567.5));
+        assert_eq!(convert.matched_culture(), None);
+        assert_eq!(
+            ConvertString::new("1'000", None).with_custom_culture("xx").with_borrowed_patterns(&patterns).to_number::<i32>(),
+            Ok(1000)
+        );
+        assert_eq!(
+            ConvertString::new(",5", Some(Culture::English)).with_custom_culture("xx").with_borrowed_patterns(&patterns).to_number::<f64>(),
+            Ok(0.5)
+        );
+
+        // Only the patterns of the custom culture are used
+        assert!(!ConvertString::new("1,234.5", None).with_custom_culture("xx").with_borrowed_patterns(&patterns).is_numeric());
+        assert!(!ConvertString::new("1'234'567", None).with_custom_culture("xx").with_borrowed_patterns(&patterns).is_numeric());
+        // A built-in culture can be selected by its code
+        let french = ConvertString::new("1 234,5", None).with_custom_culture("fr").with_borrowed_patterns(&patterns);
+        assert_eq!(french.to_number::<f64>(), Ok(1234.5));
+        assert_eq!(french.matched_culture(), Some(Culture::French));
+
+        // Unknown culture : only the common patterns
+        let unknown = ConvertString::new("1000", None).with_custom_culture("yy").with_borrowed_patterns(&patterns);
+        assert_eq!(unknown.to_number::<i32>(), Ok(1000));
+        assert!(!ConvertString::new("1'000", None).with_custom_culture("yy").with_borrowed_patterns(&patterns).is_numeric());
+    }
+
+    #[test]
+    fn test_generated_regex_culture() {
+        let french_culture =
+            CulturePattern::new("fr", Culture::French.into()).unwrap();
+        let english_culture =
+            CulturePattern::new("en", Culture::English.into()).unwrap();
+        let italian_culture =
+            CulturePattern::new("it", Culture::Italian.into()).unwrap();
+
+        assert_eq!(french_culture.get_name(), "fr");
+        assert_eq!(english_culture.get_name(), "en");
+        assert_eq!(italian_culture.get_name(), "it");
+
+        assert_eq!(french_culture.get_culture(), Some(&Culture::French));
+        assert_eq!(english_culture.get_culture(), Some(&Culture::English));
+        assert_eq!(italian_culture.get_culture(), Some(&Culture::Italian));
+
+        let fr_decimal_simple = french_culture
+            .get_patterns()
+            .iter()
+            .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
+            .unwrap();
+        assert_eq!(fr_decimal_simple.name, String::from("FR_Decimal_Simple"));
+        assert_eq!(
+            fr_decimal_simple.regex.content.as_str(),
+            r"(?P<sign>[\-\+]?)(?P<whole>[0-9]+)[,](?P<decimal>[0-9]{1,})",
+            "Error french culture DecimalSimple"
+        );
+
+        assert_eq!(
+            french_culture
+                .get_patterns()
+                .iter()
+                .find(|f| f.regex.type_parsing == TypeParsing::DecimalWithoutWholePart)
+                .unwrap()
+                .regex
+                .content
+                .as_str(),
+            r"(?P<sign>[\-\+]?)[,](?P<decimal>[0-9]+)",
+            "Error french culture DecimalWithoutWholePart"
+        );
+        assert_eq!(
+            french_culture
+                .get_patterns()
+                .iter()
+                .find(|f| f.regex.type_parsing == TypeParsing::WholeThousandSeparator)
+                .unwrap()
+                .regex
+                .content
+                .as_str(),
+            r"(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}([\s][0-9]{3})+)",
+            "Error french culture WholeThousandSeparator"
+        );
+        assert_eq!(
+            french_culture
+                .get_patterns()
+                .iter()
+                .find(|f| f.regex.type_parsing == TypeParsing::DecimalThousandSeparator)
+                .unwrap()
+                .regex
+                .content
+                .as_str(),
+            r"(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}([\s][0-9]{3})+)[,](?P<decimal>[0-9]*)",
+            "Error french culture DecimalThousandSeparator"
+        );
+
+        assert_eq!(
+            english_culture
+                .get_patterns()
+                .iter()
+                .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
+                .unwrap()
+                .regex
+                .content
+                .as_str(),
+            r"(?P<sign>[\-\+]?)(?P<whole>[0-9]+)[\.](?P<decimal>[0-9]{1,})",
+            "Error english culture DecimalSimple"
+        );
+        assert_eq!(
+            english_culture
+                .get_patterns()
+                .iter()
+                .find(|f| f.regex.type_parsing == TypeParsing::DecimalWithoutWholePart)
+                .unwrap()
+                .regex
+                .content
+                .as_str(),
+            r"(?P<sign>[\-\+]?)[\.](?P<decimal>[0-9]+)",
+            "Error english culture DecimalWithoutWholePart"
+        );
+
+        let en_whole_thousand_separator = english_culture
+            .get_patterns()
+            .iter()
+            .find(|f| f.regex.type_parsing == TypeParsing::WholeThousandSeparator)
+            .unwrap();
+        assert_eq!(
+            en_whole_thousand_separator.name,
+            String::from("EN_Whole_Thousand_Separator")
+        );
+        assert_eq!(
+            en_whole_thousand_separator.regex.content.as_str(),
+            r"(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}([,][0-9]{3})+)",
+            "Error english culture WholeThousandSeparator"
+        );
+        assert_eq!(
+            english_culture
+                .get_patterns()
+                .iter()
+                .find(|f| f.regex.type_parsing == TypeParsing::DecimalThousandSeparator)
+                .unwrap()
+                .regex
+                .content
+                .as_str(),
+            r"(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}([,][0-9]{3})+)[\.](?P<decimal>[0-9]*)",
+            "Error english culture DecimalThousandSeparator"
+        );
+
+        assert_eq!(
+            italian_culture
+                .get_patterns()
+                .iter()
+                .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
+                .unwrap()
+                .regex
+                .content
+                .as_str(),
+            r"(?P<sign>[\-\+]?)(?P<whole>[0-9]+)[,](?P<decimal>[0-9]{1,})",
+            "Error italian culture DecimalSimple"
+        );
+        assert_eq!(
+            italian_culture
+                .get_patterns()
+                .iter()
+                .find(|f| f.regex.type_parsing == TypeParsing::DecimalWithoutWholePart)
+                .unwrap()
+                .regex
+                .content
+                .as_str(),
+            r"(?P<sign>[\-\+]?)[,](?P<decimal>[0-9]+)",
+            "Error italian culture DecimalWithoutWholePart"
+        );
+        assert_eq!(
+            italian_culture
+                .get_patterns()
+                .iter()
+                .find(|f| f.regex.type_parsing == TypeParsing::WholeThousandSeparator)
+                .unwrap()
+                .regex
+                .content
+                .as_str(),
+            r"(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}([\.][0-9]{3})+)",
+            "Error italian culture WholeThousandSeparator"
+        );
+
+        let it_decimal_thousand_separator = italian_culture
+            .get_patterns()
+            .iter()
+            .find(|f| f.regex.type_parsing == TypeParsing::DecimalThousandSeparator)
+            .unwrap();
+        assert_eq!(
+            it_decimal_thousand_separator.name,
+            String::from("IT_Decimal_Thousand_Separator")
+        );
+        assert_eq!(
+            it_decimal_thousand_separator.regex.content.as_str(),
+            r"(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}([\.][0-9]{3})+)[,](?P<decimal>[0-9]*)",
+            "Error italian culture DecimalThousandSeparator"
+        );
+    }
+
+    // #[test]
+    // fn test_number_culture_settings() {
+    //     assert_eq!(
+    //         NumberCultureSettings::from((" ", ",", ThousandGrouping::ThreeBlock)),
+    //         Culture::french_culture()
+    //     );
+    //     assert_eq!(
+    //         NumberCultureSettings::from(Culture::English),
+    //         Culture::english_culture()
+    //     );
+    // }
+
+    #[test]
+    fn test_common_number() {
+        let convert = ConvertString::new("10,2", Some(Culture::French));
+        assert!(convert.is_float());
+    }
+
+    #[test]
+    fn test_number_french() {
+        let list = vec![
+            ("10", 10, 10.0, NumberType::WHOLE),
+            ("+10", 10, 10.0, NumberType::WHOLE),
+            ("-102", -102, -102., NumberType::WHOLE),
+            ("+1 000", 1000, 1000.0, NumberType::WHOLE),
+            ("2 500 563", 2500563, 2_500_563.0, NumberType::WHOLE),
+            ("-200000", -200000, -200000.0, NumberType::WHOLE),
+            (",25", 0, 0.25, NumberType::DECIMAL),
+            ("10,2", 10, 10.2, NumberType::DECIMAL),
+            ("0,25", 0, 0.25, NumberType::DECIMAL),
+            ("-10,5", -10, -10.5, NumberType::DECIMAL),
+            ("1000,89", 1000, 1000.89, NumberType::DECIMAL),
+            (
+                "+1 000,4564654654654",
+                1000,
+                1000.4564654654654,
+                NumberType::DECIMAL,
+            ),
+            (
+                "1000,4564654654654",
+                1000,
+                1000.4564654654654,
+                NumberType::DECIMAL,
+            ),
+        ];
+        test_number(Some(Culture::French), list);
+    }
+
+    #[test]
+    fn test_number_english() {
+        let list = vec![
+            ("10", 10, 10.0, NumberType::WHOLE),
+            ("-102", -102, -102., NumberType::WHOLE),
+            ("1,000", 1000, 1000.0, NumberType::WHOLE),
+            ("-200000", -200000, -200000.0, NumberType::WHOLE),
+            ("2,500,563", 2500563, 2_500_563.0, NumberType::WHOLE),
+            ("10.2", 10, 10.2, NumberType::DECIMAL),
+            ("0.4", 0, 0.4, NumberType::DECIMAL),
+            ("0.25", 0, 0.25, NumberType::DECIMAL),
+            ("-10.5", -10, -10.5, NumberType::DECIMAL),
+            ("1000.89", 1000, 1000.89, NumberType::DECIMAL),
+            (
+                "1,000.4564654654654",
+                1000,
+                1000.4564654654654,
+                NumberType::DECIMAL,
+            ),
+            (
+                "1000.4564654654654",
+                1000,
+                1000.4564654654654,
+                NumberType::DECIMAL,
+            ),
+        ];
+        test_number(Some(Culture::English), list);
+    }
+
+    #[test]
+    fn test_number_italian() {
+        let list = vec![
+            ("10", 10, 10.0, NumberType::WHOLE),
+            ("-102", -102, -102., NumberType::WHOLE),
+            ("1.000", 1000, 1000.0, NumberType::WHOLE),
+            ("-200000", -200000, -200000.0, NumberType::WHOLE),
+            ("2.500.563", 2500563, 2_500_563.0, NumberType::WHOLE),
+            ("10,2", 10, 10.2, NumberType::DECIMAL),
+            ("0,4", 0, 0.4, NumberType::DECIMAL),
+            ("0,25", 0, 0.25, NumberType::DECIMAL),
+            ("-10,5", -10, -10.5, NumberType::DECIMAL),
+            ("1000,89", 1000, 1000.89, NumberType::DECIMAL),
+            (
+                "1.000,4564654654654",
+                1000,
+                1000.4564654654654,
+                NumberType::DECIMAL,
+            ),
+            (
+                "1.000,4564654654654",
+                1000,
+                1000.4564654654654,
+                NumberType::DECIMAL,
+            ),
+        ];
+        test_number(Some(Culture::Italian), list);
+    }
+
+    fn test_number(culture: Option<Culture>, list: Vec<(&str, i32, f32, NumberType)>) {
+        for (string_num, int_value, float_value, number_type) in list {
+            let convert = ConvertString::new(string_num, culture.to_owned());
+
+            //All input are valid number
+            assert!(convert.is_numeric(), "Numeric number expected");
+            assert_eq!(
+                convert.is_integer(),
+                number_type == NumberType::WHOLE,
+                "Number should be a {}",
+                if number_type == NumberType::WHOLE {
+                    "integer"
+                } else {
+                    "decimal"
+                }
+            );
+            assert_eq!(
+                convert.is_float(),
+                number_type == NumberType::DECIMAL,
+                "Number should be a {}",
+                if number_type == NumberType::WHOLE {
+                    "integer"
+                } else {
+                    "float"
+                }
+            );
+
+            let to_integer = convert.to_number::<i32>();
+            if number_type == NumberType::WHOLE {
+                assert!(to_integer.is_ok(), "to_number() return Err instead of Ok");
+                assert_eq!(
+                    convert.to_number::<i32>().unwrap(),
+                    int_value,
+                    "to_integer() conversion failed for {}",
+                    string_num
+                );
+            } else {
+                assert!(to_integer.is_err(), "to_number() return Ok instead of Err");
+                assert_eq!(
+                    convert.to_number::<i32>(),
+                    Err(ConversionError::UnableToConvertStringToNumber)
+                );
+            }
+
+            let to_float = convert.to_number::<f32>();
+            assert!(to_float.is_ok(), "to_float() return Err instead of Ok");
+            assert_eq!(
+                convert.to_number::<f32>().unwrap(),
+                float_value,
+                "to_float() conversion failed for {}",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_unauthorized() {
+        let list = vec!["1..0", "1.,0", ",1.0", "+-0.2", "20 00", "-0,2245,45"];
+        let cultures = &[
+            None,
+            Some(Culture::English),
+            Some(Culture::French),
+            Some(Culture::Italian),
+            Some(Culture::Turkish),
+        ];
+
+        for string_num in list {
+            for culture in cultures.iter() {
+                let convert = ConvertString::new(string_num, culture.to_owned());
+                assert!(!convert.is_numeric(), "Numeric shouldn't be parsed");
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_string_sanitize() {
+        let list = vec![
+            ("\u{FEFF}1,000.5", Culture::English, 1000.5),
+            ("1\u{200B},000", Culture::English, 1000.0),
+            ("\u{202B}-1 000,25\u{202C}", Culture::French, -1000.25),
+            ("\u{2068}10\u{200E},5\u{2069}", Culture::French, 10.5),
+        ];
+
+        for (string_num, culture, float_value) in list {
+            assert!(!ConvertString::new(string_num, Some(culture)).is_numeric());
+
+            let convert = ConvertString::new(string_num, Some(culture)).with_sanitize(true);
+            assert!(convert.is_numeric(), "{:?} should be numeric once sanitized", string_num);
+            assert_eq!(convert.to_number::<f64>().unwrap(), float_value);
+        }
+    }
+
+    #[test]
+    fn test_to_number_lossy() {
+        let convert = |string_num: &'static str, culture| ConvertString::new(string_num, Some(culture));
+
+        assert_eq!(convert("10,999", Culture::French).to_number_lossy::<i32>(), Ok((10, true)));
+        assert_eq!(convert("-10,5", Culture::French).to_number_lossy::<i32>(), Ok((-10, true)));
+        assert_eq!(convert("-0,5", Culture::French).to_number_lossy::<i32>(), Ok((0, true)));
+        assert_eq!(convert("10,000", Culture::French).to_number_lossy::<i32>(), Ok((10, false)));
+        assert_eq!(convert("1 000", Culture::French).to_number_lossy::<i32>(), Ok((1000, false)));
+        assert_eq!(convert("+010", Culture::English).to_number_lossy::<i32>(), Ok((10, false)));
+
+        // Float precision
+        assert_eq!(convert("1 000,50", Culture::French).to_number_lossy::<f64>(), Ok((1000.5, false)));
+        assert_eq!(convert(",25", Culture::French).to_number_lossy::<f32>(), Ok((0.25, false)));
+        assert_eq!(convert("1.123456789", Culture::English).to_number_lossy::<f32>(), Ok((1.1234568, true)));
+        assert_eq!(convert("1.123456789", Culture::English).to_number_lossy::<f64>(), Ok((1.123456789, false)));
+        assert_eq!(convert("16,777,217", Culture::English).to_number_lossy::<f32>(), Ok((16777216.0, true)));
+
+        // Structurally invalid, or too big for the type
+        assert_eq!(
+            convert("abc", Culture::English).to_number_lossy::<i32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            convert("300", Culture::English).to_number_lossy::<i8>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        // Misgrouped : the ungrouped pattern stops at the separator
+        let diagnostics = ConvertString::new("10,00", Some(Culture::English)).explain();
+        assert!(!diagnostics.is_numeric());
+        assert_eq!(diagnostics.culture, Culture::English);
+        assert_eq!(diagnostics.attempts.len(), 5);
+        assert!(diagnostics.attempts.iter().all(|attempt| !attempt.matched));
+        assert_eq!(
+            diagnostics.nearest_miss,
+            Some(PatternAttempt { name: String::from("COMMON_Whole_Simple"), matched: false, matched_up_to: 2 })
+        );
+        assert_eq!(diagnostics.suggested_culture, Some(Culture::French));
+        assert_eq!(
+            diagnostics.to_string(),
+            "'10,00' is not a valid English number, the nearest pattern COMMON_Whole_Simple stopped at position 2 (','). \
+             It looks like French (France) formatting"
+        );
+
+        // Another culture
+        let diagnostics = ConvertString::new("1.234,5", Some(Culture::English)).explain();
+        assert_eq!(diagnostics.nearest_miss.map(|attempt| attempt.matched_up_to), Some(5));
+        assert_eq!(diagnostics.suggested_culture, Some(Culture::Italian));
+
+        // Nothing matches in any culture
+        let diagnostics = ConvertString::new("12a", Some(Culture::French)).explain();
+        assert_eq!(diagnostics.suggested_culture, None);
+        assert_eq!(
+            diagnostics.to_string(),
+            "'12a' is not a valid French (France) number, the nearest pattern COMMON_Whole_Simple stopped at position 2 ('a')"
+        );
+
+        // Valid number
+        let diagnostics: ParseDiagnostics = ConvertString::new("1,000", Some(Culture::English)).explain();
+        assert!(diagnostics.is_numeric());
+        assert_eq!(diagnostics.nearest_miss, None);
+        assert_eq!(diagnostics.to_string(), "'1,000' is a valid English number (pattern EN_Whole_Thousand_Separator)");
+
+        // Without culture, the matched culture is explained
+        assert_eq!(ConvertString::new("1 000", None).explain().culture, Culture::French);
+        assert_eq!(ConvertString::new("abc", None).explain().culture, Culture::English);
+
+        assert_eq!(RegexPattern::new(&TypeParsing::WholeSimple, None).unwrap().matched_prefix_len("123x5"), 3);
+        assert_eq!(RegexPattern::new(&TypeParsing::WholeSimple, None).unwrap().matched_prefix_len("x"), 0);
+    }
+
+    #[test]
+    fn test_convert_string_to_number_or() {
+        let convert = ConvertString::new("1 000,5", Some(Culture::French));
+        assert_eq!(convert.to_number_or(0.0), 1000.5);
+        assert_eq!(convert.to_number_or::<i32>(-1), -1);
+        assert_eq!(convert.to_number_or_else::<i32, _>(|e| if e == ConversionError::UnableToConvertStringToNumber { -1 } else { -2 }), -1);
+        assert_eq!(convert.to_number_ok::<f32>(), Some(1000.5));
+        assert_eq!(convert.to_number_ok::<u8>(), None);
+
+        let convert = ConvertString::new("abc", Some(Culture::English));
+        assert_eq!(convert.to_number_or(0.0), 0.0);
+        assert!(convert.to_number_or_else(|_| f64::NAN).is_nan());
+        assert_eq!(convert.to_number_ok::<f64>(), None);
+    }
+
+    #[test]
+    fn test_to_f64_lossy() {
+        assert_eq!(ConvertString::new("1,000", Some(Culture::English)).to_f64_lossy(), Some(1000.0));
+        assert_eq!(ConvertString::new("-1 000,25", Some(Culture::French)).to_f64_lossy(), Some(-1000.25));
+        assert_eq!(ConvertString::new(",5", Some(Culture::Italian)).to_f64_lossy(), Some(0.5));
+        assert_eq!(ConvertString::new("1.5", None).to_f64_lossy(), Some(1.5));
+        assert_eq!(
+            ConvertString::new("123,456,789,012,345,678,901", Some(Culture::English)).to_f64_lossy(),
+            Some(1.2345678901234568e20)
+        );
+        assert_eq!(ConvertString::new("abc", Some(Culture::English)).to_f64_lossy(), None);
+        assert_eq!(ConvertString::new("10,00", Some(Culture::English)).to_f64_lossy(), None);
+    }
+
+    #[test]
+    fn test_convert_string_minus_sign() {
+        let convert = ConvertString::new("\u{2212}1 000", Some(Culture::French));
+        assert!(convert.is_numeric());
+        assert!(convert.is_integer());
+        assert_eq!(convert.to_number::<i32>().unwrap(), -1000);
+
+        let convert = ConvertString::new("\u{2212}1,000.25", Some(Culture::English));
+        assert!(convert.is_float());
+        assert_eq!(convert.to_number::<f64>().unwrap(), -1000.25);
+        assert_eq!(convert.fraction_digit_count(), Some(2));
+    }
+
+    #[test]
+    fn test_convert_string_borrowed() {
+        // The default patterns are shared
+        assert!(std::ptr::eq(ConvertString::load_patterns(), ConvertString::load_patterns()));
+
+        let input = String::from("1,000.5 2,000 abc .25");
+        let tokens = input.split(' ').map(|token| ConvertString::new(token, Some(Culture::English)));
+        let floats = tokens.filter(|c| c.is_float()).map(|c| c.to_number::<f64>().unwrap()).collect::<Vec<f64>>();
+        assert_eq!(floats, vec![1000.5, 0.25]);
+
+        // A custom pattern set can be borrowed by several instances
+        let mut patterns = NumberPatterns::default();
+        patterns.replace_culture_pattern(
+            Culture::French,
+            CulturePattern::new("fr", NumberCultureSettings::new(Separator::DOT, Separator::COMMA)).unwrap(),
+        );
+        for (token, value) in [("1.234,5", 1234.5), ("10,5", 10.5)] {
+            let convert = ConvertString::new(token, Some(Culture::French)).with_borrowed_patterns(&patterns);
+            assert!(convert.is_float());
+            assert_eq!(convert.to_number::<f64>().unwrap(), value);
+        }
+    }
 
-        //We need to have an fr pattern
-        assert!(optionnal_fr_pattern.is_some());
-        let fr_pattern = optionnal_fr_pattern.unwrap();
-        assert_eq!(fr_pattern.get_name(), "fr");
-        assert!(fr_pattern.get_patterns().len() > 0);
+    #[test]
+    fn test_convert_string_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ConvertString>();
+        assert_send_sync::<NumberPatterns>();
+
+        let mut patterns = NumberPatterns::default();
+        patterns.replace_culture_pattern(
+            Culture::French,
+            CulturePattern::new("fr", NumberCultureSettings::new(Separator::DOT, Separator::COMMA)).unwrap(),
+        );
+
+        let inputs = ["1.234,5", "10,5", "-2.000", "abc"];
+        let results = std::thread::scope(|scope| {
+            let handles = inputs
+                .iter()
+                .map(|input| {
+                    let convert = ConvertString::new(input, Some(Culture::French)).with_borrowed_patterns(&patterns);
+                    scope.spawn(move || convert.to_number::<f64>().ok())
+                })
+                .collect::<Vec<_>>();
+            handles.into_iter().map(|handle| handle.join().unwrap()).collect::<Vec<Option<f64>>>()
+        });
+        assert_eq!(results, vec![Some(1234.5), Some(10.5), Some(-2000.0), None]);
+
+        // The clone shares the borrowed string and patterns
+        let convert = ConvertString::new("1.234,5", Some(Culture::French)).with_borrowed_patterns(&patterns);
+        let cloned = convert.clone();
+        assert_eq!(cloned.to_number::<f64>(), convert.to_number::<f64>());
+        assert!(std::thread::spawn(move || ConvertString::new("1,5", Some(Culture::French)).is_float())
+            .join()
+            .unwrap());
     }
 
     #[test]
-    fn test_parsing_pattern_en() {
-        let optionnal_en_pattern = NumberPatterns::default().get_culture_pattern(&Culture::English);
+    fn test_sign() {
+        let values = vec![
+            ("-0,5", Some(Culture::French), Some(Sign::Negative)),
+            ("-1 000", Some(Culture::French), Some(Sign::Negative)),
+            ("\u{2212}1,000.5", Some(Culture::English), Some(Sign::Negative)),
+            ("+1,000.5", Some(Culture::English), Some(Sign::Positive)),
+            ("1,000.5", Some(Culture::English), Some(Sign::Positive)),
+            ("-,5", Some(Culture::Italian), Some(Sign::Negative)),
+            ("-0", None, Some(Sign::Negative)),
+            ("12", None, Some(Sign::Positive)),
+            ("-abc", Some(Culture::English), None),
+            ("", None, None),
+        ];
 
-        //We need to have an en pattern
-        assert!(optionnal_en_pattern.is_some());
-        let en_pattern = optionnal_en_pattern.unwrap();
-        assert_eq!(en_pattern.get_name(), "en");
-        assert!(en_pattern.get_patterns().len() > 0);
+        for (string_num, culture, sign) in values {
+            let convert = ConvertString::new(string_num, culture);
+            assert_eq!(convert.sign(), sign, "{}", string_num);
+            assert_eq!(convert.is_negative(), sign == Some(Sign::Negative), "{}", string_num);
+            assert_eq!(convert.is_positive(), sign == Some(Sign::Positive), "{}", string_num);
+        }
+
+        // The sign is known even if the conversion to the type fails
+        let convert = ConvertString::new("-0,5", Some(Culture::French));
+        assert!(convert.to_number::<i32>().is_err());
+        assert!(convert.is_negative());
     }
 
     #[test]
-    fn test_parsing_pattern_it() {
-        let optionnal_en_pattern = NumberPatterns::default().get_culture_pattern(&Culture::Italian);
+    fn test_split_parts() {
+        let parts = |sign, whole: &str, decimal: Option<&str>| NumberParts {
+            sign,
+            whole: whole.to_owned(),
+            decimal: decimal.map(|d| d.to_owned()),
+        };
+        let values = vec![
+            ("1 234,56", Some(Culture::French), parts(Sign::Positive, "1234", Some("56"))),
+            ("-1 234,56", Some(Culture::French), parts(Sign::Negative, "1234", Some("56"))),
+            ("+1,000", Some(Culture::English), parts(Sign::Positive, "1000", None)),
+            ("-10", Some(Culture::Italian), parts(Sign::Negative, "10", None)),
+            (",050", Some(Culture::Italian), parts(Sign::Positive, "0", Some("050"))),
+            ("-.5", Some(Culture::English), parts(Sign::Negative, "0", Some("5"))),
+            ("1,00,000.10", Some(Culture::Indian), parts(Sign::Positive, "100000", Some("10"))),
+            ("42.0", None, parts(Sign::Positive, "42", Some("0"))),
+        ];
 
-        //We need to have an it pattern
-        assert!(optionnal_en_pattern.is_some());
-        let en_pattern = optionnal_en_pattern.unwrap();
-        assert_eq!(en_pattern.get_name(), "it");
-        assert!(en_pattern.get_patterns().len() > 0);
+        for (string_num, culture, number_parts) in values {
+            assert_eq!(ConvertString::new(string_num, culture).split_parts().unwrap(), number_parts, "{}", string_num);
+        }
+
+        assert_eq!(
+            ConvertString::new("1.234,56", Some(Culture::English)).split_parts(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
     }
 
     #[test]
-    fn test_generated_regex_culture() {
-        let french_culture =
-            CulturePattern::new("fr", Culture::French.into()).unwrap();
-        let english_culture =
-            CulturePattern::new("en", Culture::English.into()).unwrap();
-        let italian_culture =
-            CulturePattern::new("it", Culture::Italian.into()).unwrap();
+    fn test_canonical_string() {
+        let values = vec![
+            (",25", Some(Culture::French), "0.25"),
+            ("-,25", Some(Culture::French), "-0.25"),
+            ("+.5", Some(Culture::English), "0.5"),
+            ("-.500", Some(Culture::English), "-0.500"),
+            ("1 234,560", Some(Culture::French), "1234.560"),
+            ("1,000.", Some(Culture::English), "1000"),
+            ("-1,00,000", Some(Culture::Indian), "-100000"),
+            ("42", None, "42"),
+        ];
 
-        assert_eq!(french_culture.get_name(), "fr");
-        assert_eq!(english_culture.get_name(), "en");
-        assert_eq!(italian_culture.get_name(), "it");
+        for (string_num, culture, canonical) in values {
+            assert_eq!(ConvertString::new(string_num, culture).canonical_string().unwrap(), canonical, "{}", string_num);
+        }
+        assert_eq!(
+            ConvertString::new("abc", Some(Culture::French)).canonical_string(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
 
-        assert_eq!(french_culture.get_culture(), &Culture::French);
-        assert_eq!(english_culture.get_culture(), &Culture::English);
-        assert_eq!(italian_culture.get_culture(), &Culture::Italian);
+        // The number is displayed with the leading zero too
+        assert_eq!(",25".to_number_culture::<f64>(Culture::French), Ok(0.25));
+        assert_eq!(0.25.to_format("N2", Culture::French).unwrap(), "0,25");
+        assert_eq!(0.25.to_format("N3", Culture::English).unwrap(), "0.250");
+        assert_eq!(0.5.to_format("N1", Culture::Italian).unwrap(), "0,5");
+    }
 
-        let fr_decimal_simple = french_culture
-            .get_patterns()
-            .into_iter()
-            .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
-            .unwrap();
-        assert_eq!(fr_decimal_simple.name, String::from("FR_Decimal_Simple"));
+    #[test]
+    fn test_convert_string_debug() {
         assert_eq!(
-            fr_decimal_simple.regex.content.as_str(),
-            r"[\-\+]?[0-9]+[,][0-9]{1,}",
-            "Error french culture DecimalSimple"
+            format!("{:?}", ConvertString::new("1,000.5", Some(Culture::English))),
+            r#"ConvertString { raw: "1,000.5", culture: Some(English), numeric: true, pattern: "EN_Decimal_Thousand_Separator", canonical: "1000.5" }"#
+        );
+        assert_eq!(
+            format!("{:?}", ConvertString::new("-,25", Some(Culture::French))),
+            r#"ConvertString { raw: "-,25", culture: Some(French), numeric: true, pattern: "FR_Decimal_Without_Whole_Part", canonical: "-0.25" }"#
+        );
+        assert_eq!(
+            format!("{:?}", ConvertString::new("42", None)),
+            r#"ConvertString { raw: "42", culture: None, numeric: true, pattern: "COMMON_Whole_Simple", canonical: "42" }"#
+        );
+        assert_eq!(
+            format!("{:?}", ConvertString::new("abc", Some(Culture::English))),
+            r#"ConvertString { raw: "abc", culture: Some(English), numeric: false, pattern: None, canonical: None }"#
+        );
+        assert_eq!(
+            format!("{:?}", ConvertString::new("10,00", Some(Culture::English))),
+            r#"ConvertString { raw: "10,00", culture: Some(English), numeric: false, pattern: None, canonical: None }"#
         );
 
+        let mut patterns = NumberPatterns::default();
+        patterns.add_culture_pattern(
+            CulturePattern::new("swiss", NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT)).unwrap(),
+        );
+        let convert = ConvertString::new("1'000.5", None).with_patterns(patterns).with_custom_culture("swiss");
         assert_eq!(
-            french_culture
-                .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalWithoutWholePart)
-                .unwrap()
-                .regex
-                .content
-                .as_str(),
-            r"[\-\+]?[,][0-9]+",
-            "Error french culture DecimalWithoutWholePart"
+            format!("{:?}", convert),
+            r#"ConvertString { raw: "1'000.5", culture: None, custom_culture: "swiss", numeric: true, pattern: "SWISS_Decimal_Thousand_Separator", canonical: "1000.5" }"#
         );
+
+        // The human readable summary
         assert_eq!(
-            french_culture
-                .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::WholeThousandSeparator)
-                .unwrap()
-                .regex
-                .content
-                .as_str(),
-            r"[\-\+]?[0-9]+([\s][0-9]{3})+",
-            "Error french culture WholeThousandSeparator"
+            ConvertString::new("1,000.5", Some(Culture::English)).describe(),
+            "'1,000.5' is numeric in English (pattern EN_Decimal_Thousand_Separator) = 1000.5"
         );
         assert_eq!(
-            french_culture
-                .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalThousandSeparator)
-                .unwrap()
-                .regex
-                .content
-                .as_str(),
-            r"[\-\+]?[0-9]+([\s][0-9]{3})+[,][0-9]*",
-            "Error french culture DecimalThousandSeparator"
+            ConvertString::new("1 234,5", None).describe(),
+            "'1 234,5' is numeric in French (France) (pattern FR_Decimal_Thousand_Separator) = 1234.5"
+        );
+        assert_eq!(ConvertString::new("abc", Some(Culture::Italian)).describe(), "'abc' is not numeric in Italian (Italy)");
+        assert_eq!(ConvertString::new("abc", None).describe(), "'abc' is not numeric in any culture");
+        assert_eq!(convert.describe(), "'1'000.5' is numeric in swiss (pattern SWISS_Decimal_Thousand_Separator) = 1000.5");
+    }
+
+    #[test]
+    fn test_get_normalized() {
+        let values = vec![
+            ("1 234,560", Some(Culture::French), "1234.560"),
+            ("-1 234,560", Some(Culture::French), "-1234.560"),
+            ("+1,234.5", Some(Culture::English), "1234.5"),
+            ("1.000.000", Some(Culture::Italian), "1000000"),
+            ("1,00,00,000.00", Some(Culture::Indian), "10000000.00"),
+            ("007", Some(Culture::English), "007"),
+            ("123456789012345678901234567890.123456789", Some(Culture::English), "123456789012345678901234567890.123456789"),
+            ("\u{2212}1 000", Some(Culture::French), "-1000"),
+            ("1000.50", None, "1000.50"),
+        ];
+
+        for (string_num, culture, normalized) in values {
+            assert_eq!(
+                ConvertString::new(string_num, culture).get_normalized().unwrap(),
+                normalized,
+                "{}",
+                string_num
+            );
+        }
+
+        for (string_num, culture) in [
+            ("1,00.5", Culture::English),
+            ("1.234,5", Culture::French),
+            ("1 2345", Culture::French),
+            ("abc", Culture::English),
+        ] {
+            assert_eq!(
+                ConvertString::new(string_num, Some(culture)).get_normalized(),
+                Err(ConversionError::UnableToConvertStringToNumber),
+                "{}",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_well_grouped() {
+        let values = vec![
+            // WholeThousandSeparator
+            ("1,000", Culture::English, true),
+            ("1,00", Culture::English, false),
+            ("2,500,563", Culture::English, true),
+            ("2,5000", Culture::English, false),
+            ("1,00,00,000", Culture::Indian, true),
+            ("100,00,000", Culture::Indian, false),
+            ("1.000.000", Culture::Italian, true),
+            ("1000.000", Culture::Italian, false),
+            // DecimalThousandSeparator
+            ("1 000,5", Culture::French, true),
+            ("-12,345.678", Culture::English, true),
+            ("12,34.5", Culture::English, false),
+            ("1,00,000.25", Culture::Indian, true),
+            // WholeSimple, DecimalSimple and DecimalWithoutWholePart have no thousand separator
+            ("1000000", Culture::English, true),
+            ("1000000.25", Culture::English, true),
+            ("-1000000,25", Culture::French, true),
+            (",25", Culture::Italian, true),
+            (".25", Culture::English, true),
+            // Not numeric
+            ("abc", Culture::English, false),
+            ("1.000,5", Culture::English, false),
+        ];
+
+        for (string_num, culture, is_well_grouped) in values {
+            assert_eq!(
+                ConvertString::new(string_num, Some(culture)).is_well_grouped(),
+                is_well_grouped,
+                "{}",
+                string_num
+            );
+        }
+
+        assert!(ConvertString::new("1,000", None).is_well_grouped());
+        // Without culture, read as a French decimal number
+        assert!(ConvertString::new("1,0000", None).is_well_grouped());
+        assert!(!ConvertString::new("1,0000", Some(Culture::English)).is_well_grouped());
+    }
+
+    #[test]
+    fn test_convert_string_any_culture() {
+        let values = vec![
+            ("1.000,5", Some(Culture::Italian), Some("IT_Decimal_Thousand_Separator"), Some(1000.5)),
+            ("1 000,5", Some(Culture::French), Some("FR_Decimal_Thousand_Separator"), Some(1000.5)),
+            ("1,000.5", Some(Culture::English), Some("EN_Decimal_Thousand_Separator"), Some(1000.5)),
+            ("1,00,000", Some(Culture::Indian), Some("ID_Whole_Thousand_Separator"), Some(100000.0)),
+            // The first culture wins when several cultures match
+            ("1,000", Some(Culture::English), Some("EN_Whole_Thousand_Separator"), Some(1000.0)),
+            ("10,5", Some(Culture::French), Some("FR_Decimal_Simple"), Some(10.5)),
+            ("-42", Some(Culture::English), Some("COMMON_Whole_Simple"), Some(-42.0)),
+            ("abc", None, None, None),
+        ];
+
+        for (string_num, culture, pattern_name, value) in values {
+            let convert = ConvertString::new(string_num, None);
+            assert_eq!(convert.matched_culture(), culture, "{}", string_num);
+            assert_eq!(convert.get_current_pattern().map(|p| p.name().to_owned()).as_deref(), pattern_name);
+            assert_eq!(convert.is_numeric(), value.is_some());
+            assert_eq!(convert.to_number::<f64>().ok(), value, "{}", string_num);
+        }
+
+        // With a culture, only this culture is tried
+        let convert = ConvertString::new("1.000,5", Some(Culture::English));
+        assert!(!convert.is_numeric());
+        assert_eq!(convert.matched_culture(), None);
+        assert_eq!(ConvertString::new("1,5", Some(Culture::Italian)).matched_culture(), Some(Culture::Italian));
+    }
+
+    #[test]
+    fn test_fraction_digit_count() {
+        let values = vec![
+            ("1,000.250", Some(Culture::English), Some(3)),
+            ("1,000", Some(Culture::English), Some(0)),
+            ("abc", Some(Culture::English), None),
+            ("10", Some(Culture::French), Some(0)),
+            ("-10,5", Some(Culture::French), Some(1)),
+            (",125", Some(Culture::Italian), Some(3)),
+            ("1 000,00", Some(Culture::French), Some(2)),
+            ("1,00,000.12345", Some(Culture::Indian), Some(5)),
+            ("1.000,5", Some(Culture::English), None),
+            ("1.25", None, Some(2)),
+            ("", None, None),
+        ];
+
+        for (string_num, culture, count) in values {
+            assert_eq!(ConvertString::new(string_num, culture).fraction_digit_count(), count, "{}", string_num);
+        }
+    }
+
+    #[test]
+    fn test_whole_and_decimal_digits() {
+        let values = vec![
+            ("12 345,6700", Some(Culture::French), Some((5, 4))),
+            ("-1,234.50", Some(Culture::English), Some((4, 2))),
+            ("1,00,000", Some(Culture::Indian), Some((6, 0))),
+            ("007", Some(Culture::English), Some((1, 0))),
+            ("0,5", Some(Culture::French), Some((0, 1))),
+            (",125", Some(Culture::Italian), Some((0, 3))),
+            ("1.25", None, Some((1, 2))),
+            ("abc", Some(Culture::English), None),
+        ];
+
+        for (string_num, culture, digits) in values {
+            let convert = ConvertString::new(string_num, culture);
+            assert_eq!(convert.whole_digits(), digits.map(|(whole, _)| whole), "{}", string_num);
+            assert_eq!(convert.decimal_digits(), digits.map(|(_, decimal)| decimal), "{}", string_num);
+        }
+    }
+
+    #[test]
+    fn test_count_digits() {
+        let values = vec![
+            ("1,000.50", Some(Culture::English), Some((6, 4, 2))),
+            ("-1.000,50", Some(Culture::Italian), Some((6, 4, 2))),
+            ("00123456", Some(Culture::English), Some((8, 8, 0))),
+            ("-007", Some(Culture::French), Some((3, 3, 0))),
+            (",25", Some(Culture::French), Some((3, 1, 2))),
+            ("-.5", Some(Culture::English), Some((2, 1, 1))),
+            ("1,000.", Some(Culture::English), Some((4, 4, 0))),
+            ("1,00,000", Some(Culture::Indian), Some((6, 6, 0))),
+            ("12 345,6700", Some(Culture::French), Some((9, 5, 4))),
+            ("42", None, Some((2, 2, 0))),
+            ("abc", Some(Culture::English), None),
+            ("10,00", Some(Culture::English), None),
+        ];
+
+        for (string_num, culture, digits) in values {
+            let convert = ConvertString::new(string_num, culture);
+            assert_eq!(convert.count_digits(), digits.map(|(total, _, _)| total), "{}", string_num);
+            assert_eq!(convert.integer_digit_count(), digits.map(|(_, integer, _)| integer), "{}", string_num);
+            assert_eq!(convert.fractional_digit_count(), digits.map(|(_, _, fractional)| fractional), "{}", string_num);
+        }
+    }
+
+    #[test]
+    fn test_fits_in() {
+        let big = ConvertString::new("1234567890123456789012345", Some(Culture::English));
+        assert!(!big.fits_in::<i64>());
+        assert!(!big.fits_in::<u64>());
+        assert!(big.fits_in::<i128>());
+        assert!(big.fits_in::<f32>());
+
+        // Bounds are inclusive
+        assert!(ConvertString::new("127", Some(Culture::English)).fits_in::<i8>());
+        assert!(!ConvertString::new("128", Some(Culture::English)).fits_in::<i8>());
+        assert!(ConvertString::new("-128", Some(Culture::English)).fits_in::<i8>());
+        assert!(!ConvertString::new("-129", Some(Culture::English)).fits_in::<i8>());
+        assert!(ConvertString::new("255", Some(Culture::English)).fits_in::<u8>());
+        assert!(ConvertString::new("0,000,255", Some(Culture::English)).fits_in::<u8>());
+        assert!(ConvertString::new("2 147 483 647", Some(Culture::French)).fits_in::<i32>());
+        assert!(!ConvertString::new("2 147 483 648", Some(Culture::French)).fits_in::<i32>());
+
+        // Negative values against unsigned types
+        assert!(!ConvertString::new("-1", Some(Culture::English)).fits_in::<u8>());
+        assert!(!ConvertString::new("-1", Some(Culture::English)).fits_in::<u128>());
+        assert!(ConvertString::new("-0", Some(Culture::English)).fits_in::<u8>());
+
+        // Decimal part
+        assert!(!ConvertString::new("1.5", Some(Culture::English)).fits_in::<i32>());
+        assert!(ConvertString::new("1,00", Some(Culture::French)).fits_in::<i32>());
+        assert!(ConvertString::new("1.5", Some(Culture::English)).fits_in::<f32>());
+        assert!(ConvertString::new("-0.5", Some(Culture::English)).fits_in::<f32>());
+
+        // Float magnitude (f32::MAX is ~3.4e38)
+        let e39 = format!("1{}", "0".repeat(39));
+        assert!(!ConvertString::new(&e39, None).fits_in::<f32>());
+        assert!(ConvertString::new(&e39, None).fits_in::<f64>());
+
+        assert!(!ConvertString::new("abc", Some(Culture::English)).fits_in::<i32>());
+    }
+
+    #[test]
+    fn test_fits() {
+        let convert = |s: &'static str| ConvertString::new(s, Some(Culture::English));
+        assert!(convert("300").fits::<i16>());
+        assert!(!convert("300").fits::<i8>());
+        assert!(convert("-1").fits::<i8>());
+        assert!(!convert("-1").fits::<u8>());
+        assert!(convert("65,535").fits::<u16>());
+        assert!(!convert("65,536").fits::<u16>());
+
+        // Decimal part
+        assert!(convert("1.5").fits::<f32>());
+        assert!(!convert("1.5").fits::<i32>());
+        assert!(!convert("1.00").fits::<i32>());
+        assert!(convert("1.00").fits_in::<i32>());
+
+        // Any type read from a string
+        assert!(convert("0.25").fits::<num::rational::Ratio<i32>>());
+        assert!(ConvertString::new("1 000,5", Some(Culture::French)).fits::<f64>());
+        assert!(!convert("abc").fits::<f64>());
+        assert!(!convert("").fits::<i32>());
+    }
+
+    #[test]
+    fn test_replace_culture_pattern() {
+        // Stock French rejects the dot as thousand separator
+        let stock = ConvertString::new("1.234,5", Some(Culture::French));
+        assert!(!stock.is_numeric());
+        assert_eq!(stock.to_number::<f64>(), Err(ConversionError::UnableToConvertStringToNumber));
+
+        let mut patterns = NumberPatterns::default();
+        let previous = patterns.replace_culture_pattern(
+            Culture::French,
+            CulturePattern::new("fr", NumberCultureSettings::new(Separator::DOT, Separator::COMMA)).unwrap(),
         );
+        assert_eq!(previous.unwrap().get_settings(), &NumberCultureSettings::from(Culture::French));
+
+        let modified = ConvertString::new("1.234,5", Some(Culture::French)).with_patterns(patterns.clone());
+        assert!(modified.is_numeric());
+        assert!(modified.is_float());
+        assert_eq!(modified.to_number::<f64>().unwrap(), 1234.5);
+        assert_eq!(modified.to_number_lossy::<i32>().unwrap(), (1234, true));
+
+        // Only French has been modified
+        let english = ConvertString::new("1,234.5", Some(Culture::English)).with_patterns(patterns.clone());
+        assert_eq!(english.to_number::<f64>().unwrap(), 1234.5);
+
+        // The default behavior is untouched
+        assert!(!ConvertString::new("1.234,5", Some(Culture::French)).is_numeric());
+        assert_eq!(ConvertString::new("1 234,5", Some(Culture::French)).to_number::<f64>().unwrap(), 1234.5);
 
+        // Without the culture pattern, nothing match for the culture
+        assert!(patterns.remove_culture_pattern(&Culture::French).is_some());
+        let removed = ConvertString::new("1.234,5", Some(Culture::French)).with_patterns(patterns);
+        assert!(!removed.is_numeric());
+    }
+
+    #[test]
+    fn test_regex_captures() {
+        let captures = |type_parsing: TypeParsing, culture: Culture, text: &'static str| {
+            RegexPattern::new(&type_parsing, Some(culture.into())).unwrap().captures(text)
+        };
+        let parts = |sign: Sign, whole: Option<&'static str>, decimal: Option<&'static str>| {
+            Some(NumberCaptures { sign, whole, decimal })
+        };
+
+        assert_eq!(captures(TypeParsing::WholeSimple, Culture::English, "-1000"), parts(Sign::Negative, Some("1000"), None));
+        assert_eq!(captures(TypeParsing::WholeSimple, Culture::English, "+42"), parts(Sign::Positive, Some("42"), None));
         assert_eq!(
-            english_culture
-                .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
-                .unwrap()
-                .regex
-                .content
-                .as_str(),
-            r"[\-\+]?[0-9]+[\.][0-9]{1,}",
-            "Error english culture DecimalSimple"
+            captures(TypeParsing::DecimalSimple, Culture::French, "10,25"),
+            parts(Sign::Positive, Some("10"), Some("25"))
         );
         assert_eq!(
-            english_culture
-                .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalWithoutWholePart)
-                .unwrap()
-                .regex
-                .content
-                .as_str(),
-            r"[\-\+]?[\.][0-9]+",
-            "Error english culture DecimalWithoutWholePart"
+            captures(TypeParsing::DecimalWithoutWholePart, Culture::Italian, "-,5"),
+            parts(Sign::Negative, None, Some("5"))
+        );
+        assert_eq!(
+            captures(TypeParsing::WholeThousandSeparator, Culture::French, "12 345 678"),
+            parts(Sign::Positive, Some("12 345 678"), None)
+        );
+        assert_eq!(
+            captures(TypeParsing::DecimalThousandSeparator, Culture::English, "-1,234.50"),
+            parts(Sign::Negative, Some("1,234"), Some("50"))
+        );
+        assert_eq!(
+            captures(TypeParsing::DecimalThousandSeparator, Culture::English, "1,234."),
+            parts(Sign::Positive, Some("1,234"), None)
+        );
+        assert_eq!(
+            captures(TypeParsing::DecimalThousandSeparator, Culture::Indian, "1,00,000.25"),
+            parts(Sign::Positive, Some("1,00,000"), Some("25"))
+        );
+        assert_eq!(
+            captures(TypeParsing::NumberWithUnit, Culture::French, "-1 500,5 kg"),
+            parts(Sign::Negative, Some("1 500"), Some("5"))
+        );
+        assert_eq!(captures(TypeParsing::NumberWithUnit, Culture::English, ".5%"), parts(Sign::Positive, None, Some("5")));
+        assert_eq!(captures(TypeParsing::DecimalSimple, Culture::English, "abc"), None);
+
+        // Custom patterns can use the same group names
+        let custom = RegexPattern::from_regex(r"(?P<whole>[0-9]+)h(?P<decimal>[0-9]+)").unwrap();
+        assert_eq!(custom.captures("12h30"), parts(Sign::Positive, Some("12"), Some("30")));
+        let custom = RegexPattern::from_regex(r"#[0-9]+").unwrap();
+        assert_eq!(custom.captures("#12"), parts(Sign::Positive, None, None));
+    }
+
+    #[test]
+    fn test_regex_for() {
+        assert_eq!(
+            Culture::French.regex_for(TypeParsing::DecimalSimple).unwrap(),
+            r"^(?:(?P<sign>[\-\+]?)(?P<whole>[0-9]+)[,](?P<decimal>[0-9]{1,}))$"
+        );
+        assert_eq!(
+            Culture::French.regex_for(TypeParsing::DecimalThousandSeparator).unwrap(),
+            r"^(?:(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}([\s][0-9]{3})+)[,](?P<decimal>[0-9]*))$"
+        );
+        assert_eq!(
+            Culture::English.regex_for(TypeParsing::WholeThousandSeparator).unwrap(),
+            r"^(?:(?P<sign>[\-\+]?)(?P<whole>[0-9]{1,3}([,][0-9]{3})+))$"
+        );
+        assert_eq!(Culture::English.regex_for(TypeParsing::DecimalWithoutWholePart).unwrap(), r"^(?:(?P<sign>[\-\+]?)[\.](?P<decimal>[0-9]+))$");
+        assert_eq!(Culture::English.regex_for(TypeParsing::Custom), Err(ConversionError::RegexBuilder));
+
+        // Same string as the one used by the culture patterns
+        for culture in enum_iterator::all::<Culture>() {
+            for pattern in ConvertString::load_patterns().get_culture_pattern(&culture).unwrap().get_patterns() {
+                assert_eq!(
+                    culture.regex_for(pattern.get_regex().get_type_parsing().clone()).unwrap(),
+                    pattern.get_regex().as_regex_string()
+                );
+            }
+        }
+
+        // The alternation accepts the same strings as ConvertString
+        for culture in enum_iterator::all::<Culture>() {
+            let regex = Regex::new(&culture.regex_all().unwrap()).unwrap();
+            for string_num in ["1000", "-1,000.5", "1 000,5", "1.000,5", ",5", "1,00,000.5", "abc", "1..0", ""] {
+                assert_eq!(
+                    regex.is_match(string_num),
+                    ConvertString::new(string_num, Some(culture)).is_numeric(),
+                    "{:?} {}",
+                    culture,
+                    string_num
+                );
+            }
+        }
+        assert!(Culture::English.regex_all().unwrap().starts_with(r"^(?:(?:(?P<sign0>[\-\+]?)(?P<whole0>\d+([0-9]{3})*))|"));
+    }
+
+    #[test]
+    fn test_to_number_matched_pattern() {
+        // A common pattern with other separators than the culture ones
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(
+            ParsingPattern::build(
+                String::from("swiss"),
+                TypeParsing::DecimalThousandSeparator,
+                Some(NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT)),
+            )
+            .unwrap(),
+        );
+
+        // The pattern has validated the string, so the conversion must succeed (the English separators would fail)
+        for culture in [None, Some(Culture::English), Some(Culture::French)] {
+            let convert = ConvertString::new("-1'000.5", culture).with_borrowed_patterns(&patterns);
+            assert_eq!(convert.get_current_pattern().unwrap().name(), "SWISS_Decimal_Thousand_Separator");
+            assert_eq!(convert.to_number::<f64>(), Ok(-1000.5), "{:?}", culture);
+        }
+        assert_eq!("-1'000.5".to_number_culture::<f64>(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
+
+        // The built-in patterns give the same results as the culture conversion
+        let values = vec![
+            ("1 234,5", Culture::French),
+            ("-1.000", Culture::Italian),
+            ("+,25", Culture::Turkish),
+            ("1,00,000.75", Culture::Indian),
+            ("1,000.", Culture::English),
+            ("１２３", Culture::English),
+        ];
+        for (string_num, culture) in values {
+            assert_eq!(
+                ConvertString::new(string_num, Some(culture)).to_number::<f64>(),
+                string_num.to_number_culture::<f64>(culture),
+                "{}",
+                string_num
+            );
+        }
+        assert_eq!(ConvertString::new("１２３", Some(Culture::English)).to_number::<i32>(), Ok(123));
+    }
+
+    #[test]
+    fn test_custom_pattern() {
+        let percent = ParsingPattern::custom("percent", r"(?P<number>[\-\+]?[0-9]+(\.[0-9]+)?)\s*%", NumberType::DECIMAL).unwrap();
+        assert_eq!(percent.name(), "PERCENT_Custom");
+        assert_eq!(percent.get_regex().get_type_parsing(), &TypeParsing::Custom);
+        assert!(percent.get_regex().is_match("12.5 %"));
+        assert!(!percent.get_regex().is_match("x12.5 %"));
+
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(percent);
+
+        let convert = ConvertString::new("12.5%", Some(Culture::English)).with_patterns(patterns.clone());
+        assert_eq!(convert.get_current_pattern().unwrap().name(), "PERCENT_Custom");
+        assert!(convert.is_float());
+        assert_eq!(convert.to_number::<f64>(), Ok(12.5));
+        assert_eq!(
+            ConvertString::new("-3 %", Some(Culture::English)).with_borrowed_patterns(&patterns).to_number::<f64>(),
+            Ok(-3.0)
+        );
+
+        // The built-in patterns are still used
+        assert_eq!(ConvertString::new("1,000.5", Some(Culture::English)).with_patterns(patterns).to_number::<f64>(), Ok(1000.5));
+        assert!(!ConvertString::new("12.5%", Some(Culture::English)).is_numeric());
+
+        // Without 'number' group, the whole string is converted
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(ParsingPattern::custom("hash", r"#[0-9]+", NumberType::WHOLE).unwrap());
+        let convert = ConvertString::new("#12", None).with_patterns(patterns);
+        assert!(convert.is_integer());
+        assert_eq!(convert.to_number::<i32>(), Err(ConversionError::UnableToConvertStringToNumber));
+
+        // An alternation must match the whole string number too
+        let alternation = ParsingPattern::custom("x", r"[0-9]+|abc", NumberType::WHOLE).unwrap();
+        assert!(alternation.get_regex().is_match("12"));
+        assert!(alternation.get_regex().is_match("abc"));
+        assert!(!alternation.get_regex().is_match("12xyz"));
+        assert!(!alternation.get_regex().is_match("xyzabc"));
+        assert_eq!(alternation.get_regex().as_regex_string(), "^(?:[0-9]+|abc)$");
+
+        assert_eq!(
+            ParsingPattern::custom("invalid", r"([0-9]", NumberType::WHOLE).unwrap_err(),
+            ConversionError::RegexBuilder
+        );
+        assert_eq!(RegexPattern::new(&TypeParsing::Custom, None).unwrap_err(), ConversionError::RegexBuilder);
+        // The culture settings are needed by the patterns with separators
+        assert_eq!(RegexPattern::new(&TypeParsing::DecimalSimple, None).unwrap_err(), ConversionError::RegexBuilder);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            validate("-1 234,50", Culture::French),
+            Some(NumberInfo {
+                number_type: NumberType::DECIMAL,
+                type_parsing: TypeParsing::DecimalThousandSeparator,
+                sign: Sign::Negative,
+                whole_digits: 4,
+                decimal_digits: 2,
+            })
+        );
+        assert_eq!(
+            validate("007", Culture::English),
+            Some(NumberInfo {
+                number_type: NumberType::WHOLE,
+                type_parsing: TypeParsing::WholeSimple,
+                sign: Sign::Positive,
+                whole_digits: 1,
+                decimal_digits: 0,
+            })
+        );
+        assert_eq!(validate(",5", Culture::French).map(|info| info.type_parsing), Some(TypeParsing::DecimalWithoutWholePart));
+        assert_eq!(validate("1,000.5", Culture::French), None);
+        assert_eq!(validate("10,00", Culture::English), None);
+        assert_eq!(validate("abc", Culture::English), None);
+
+        // The sign and the parts are read from the pattern groups
+        let info = validate("+1.5", Culture::English).unwrap();
+        assert_eq!((info.sign, info.whole_digits, info.decimal_digits), (Sign::Positive, 1, 1));
+        let info = validate("\u{2212}1 000,25", Culture::French).unwrap();
+        assert_eq!((info.sign, info.whole_digits, info.decimal_digits), (Sign::Negative, 4, 2));
+        // No built-in pattern reads the units, as ConvertString
+        assert_eq!(validate("1,5 kg", Culture::French), None);
+        assert!(!ConvertString::new("1,5 kg", Some(Culture::French)).is_numeric());
+
+        // Same result as ConvertString
+        let values = vec![
+            ("1,000.", Culture::English),
+            ("+1.5", Culture::English),
+            ("-1,00,000.25", Culture::Indian),
+            ("1.234.567,0", Culture::Italian),
+            ("0,5", Culture::Turkish),
+            ("-12", Culture::French),
+            ("1,000", Culture::English),
+            ("1.000", Culture::Italian),
+            ("\u{2212}1,000.5", Culture::English),
+        ];
+        for (string_num, culture) in values {
+            let convert = ConvertString::new(string_num, Some(culture));
+            let pattern = convert.get_current_pattern().unwrap();
+            let info = validate(string_num, culture).unwrap();
+            assert_eq!(&info.number_type, pattern.get_number_type(), "{}", string_num);
+            assert_eq!(&info.type_parsing, pattern.get_regex().get_type_parsing(), "{}", string_num);
+            assert_eq!(Some(info.sign), convert.sign(), "{}", string_num);
+            assert_eq!(Some(info.whole_digits), convert.whole_digits(), "{}", string_num);
+            assert_eq!(Some(info.decimal_digits), convert.decimal_digits(), "{}", string_num);
+        }
+    }
+
+    #[test]
+    fn test_grouped_and_ungrouped() {
+        let patterns = NumberPatterns::default();
+        let names = |string_num: &str, culture: Culture| {
+            ConvertString::find_all_patterns(string_num, &culture, &patterns)
+                .iter()
+                .map(|p| p.name().to_owned())
+                .collect::<Vec<String>>()
+        };
+
+        // Grouped and ungrouped numbers are accepted, with a single matching pattern
+        let values = vec![
+            ("1000", Culture::English, "COMMON_Whole_Simple", 1000),
+            ("1,000", Culture::English, "EN_Whole_Thousand_Separator", 1000),
+            ("-1,000,000", Culture::English, "EN_Whole_Thousand_Separator", -1000000),
+            ("1 000", Culture::French, "FR_Whole_Thousand_Separator", 1000),
+            ("100000", Culture::Indian, "COMMON_Whole_Simple", 100000),
+            ("1,00,000", Culture::Indian, "ID_Whole_Thousand_Separator", 100000),
+            ("10,000", Culture::Indian, "ID_Whole_Thousand_Separator", 10000),
+        ];
+        for (string_num, culture, name, expected) in values {
+            assert_eq!(names(string_num, culture), vec![name], "{}", string_num);
+            assert_eq!(string_num.to_number_validated::<i32>(culture), Ok(expected), "{}", string_num);
+        }
+
+        // Misgrouped numbers have no pattern and are rejected by the validated conversion
+        let values = vec![
+            ("10,00", Culture::English),
+            ("1,0000", Culture::English),
+            ("1000,000", Culture::English),
+            ("10,00.5", Culture::English),
+            ("1000 000", Culture::French),
+            ("100,000", Culture::Indian),
+            ("1,000,000", Culture::Indian),
+            (",000", Culture::Indian),
+        ];
+        for (string_num, culture) in values {
+            assert!(names(string_num, culture).is_empty(), "{}", string_num);
+            assert!(!ConvertString::new(string_num, Some(culture)).is_numeric(), "{}", string_num);
+            assert!(
+                matches!(string_num.to_number_validated::<f64>(culture), Err(ConversionError::InvalidGrouping { .. })),
+                "{}",
+                string_num
+            );
+        }
+
+        // With a decimal part
+        assert_eq!(names("1000.5", Culture::English), vec!["EN_Decimal_Simple"]);
+        assert_eq!(names("1,000.5", Culture::English), vec!["EN_Decimal_Thousand_Separator"]);
+        assert_eq!("1,000.5".to_number_validated::<f64>(Culture::English), Ok(1000.5));
+    }
+
+    #[test]
+    fn test_ungrouped_whole_number() {
+        let patterns = NumberPatterns::default();
+
+        // An ungrouped number is never read as a grouped one, whatever the number of digits and the culture
+        for culture in Culture::all() {
+            for (string_num, expected) in [("100", 100), ("1000", 1000), ("10000", 10000), ("100000", 100000), ("1000000", 1000000), ("-1000", -1000)] {
+                let matching = ConvertString::find_all_patterns(string_num, &culture, &patterns);
+                assert_eq!(matching.len(), 1, "{} in {:?}", string_num, culture);
+                assert_eq!(matching[0].get_regex().get_type_parsing(), &TypeParsing::WholeSimple);
+
+                let convert = ConvertString::new(string_num, Some(culture));
+                assert_eq!(convert.get_current_pattern().unwrap().name(), "COMMON_Whole_Simple");
+                assert_eq!(convert.to_number::<i32>(), Ok(expected), "{} in {:?}", string_num, culture);
+                assert_eq!(string_num.to_number_culture::<i32>(culture), Ok(expected), "{} in {:?}", string_num, culture);
+            }
+        }
+
+        // The grouped and ungrouped forms have the same value, with their own pattern
+        assert_eq!("1000".to_number_culture::<i32>(Culture::English), Ok(1000));
+        assert_eq!("1,000".to_number_culture::<i32>(Culture::English), Ok(1000));
+        assert_eq!(ConvertString::new("1000", Some(Culture::English)).get_current_pattern().unwrap().name(), "COMMON_Whole_Simple");
+        assert_eq!(
+            ConvertString::new("1,000", Some(Culture::English)).get_current_pattern().unwrap().name(),
+            "EN_Whole_Thousand_Separator"
         );
+        // A lone group of 3 digits is a plain whole number
+        assert_eq!(ConvertString::new("100", Some(Culture::English)).get_current_pattern().unwrap().name(), "COMMON_Whole_Simple");
+        assert!(ConvertString::new("1,00", Some(Culture::English)).get_current_pattern().is_none());
+    }
 
-        let en_whole_thousand_separator = english_culture
-            .get_patterns()
-            .into_iter()
-            .find(|f| f.regex.type_parsing == TypeParsing::WholeThousandSeparator)
-            .unwrap();
+    #[test]
+    fn test_pattern_priority() {
+        let names = |patterns: Vec<ParsingPattern>| {
+            patterns.iter().map(|p| p.name().to_owned()).collect::<Vec<String>>()
+        };
+
+        // By default, the built-in patterns does not compete
+        let patterns = NumberPatterns::default();
         assert_eq!(
-            en_whole_thousand_separator.name,
-            String::from("EN_Whole_Thousand_Separator")
+            names(ConvertString::find_all_patterns("10.5", &Culture::English, &patterns)),
+            vec!["EN_Decimal_Simple"]
+        );
+        assert!(ConvertString::find_all_patterns("abc", &Culture::English, &patterns).is_empty());
+
+        // A low priority pattern is shadowed by the built-in one, even if it is a common pattern
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("low"), TypeParsing::DecimalSimple, Some(Culture::English.into()))
+                .unwrap()
+                .with_priority(-1),
         );
         assert_eq!(
-            en_whole_thousand_separator.regex.content.as_str(),
-            r"[\-\+]?[0-9]+([,][0-9]{3})+",
-            "Error english culture WholeThousandSeparator"
+            names(ConvertString::find_all_patterns("10.5", &Culture::English, &patterns)),
+            vec!["EN_Decimal_Simple", "LOW_Decimal_Simple"]
         );
         assert_eq!(
-            english_culture
-                .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalThousandSeparator)
-                .unwrap()
-                .regex
-                .content
-                .as_str(),
-            r"[\-\+]?[0-9]+([,][0-9]{3})+[\.][0-9]*",
-            "Error english culture DecimalThousandSeparator"
+            ConvertString::find_pattern("10.5", &Culture::English, &patterns).unwrap().name(),
+            "EN_Decimal_Simple"
         );
 
-        assert_eq!(
-            italian_culture
-                .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
+        // A high priority pattern shadows the built-in one
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("currency"), TypeParsing::DecimalSimple, Some(Culture::English.into()))
                 .unwrap()
-                .regex
-                .content
-                .as_str(),
-            r"[\-\+]?[0-9]+[,][0-9]{1,}",
-            "Error italian culture DecimalSimple"
+                .with_priority(10),
         );
         assert_eq!(
-            italian_culture
-                .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalWithoutWholePart)
-                .unwrap()
-                .regex
-                .content
-                .as_str(),
-            r"[\-\+]?[,][0-9]+",
-            "Error italian culture DecimalWithoutWholePart"
+            ConvertString::find_pattern("10.5", &Culture::English, &patterns).unwrap().name(),
+            "CURRENCY_Decimal_Simple"
         );
         assert_eq!(
-            italian_culture
-                .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::WholeThousandSeparator)
-                .unwrap()
-                .regex
-                .content
-                .as_str(),
-            r"[\-\+]?[0-9]+([\.][0-9]{3})+",
-            "Error italian culture WholeThousandSeparator"
+            ConvertString::find_pattern("10", &Culture::English, &patterns).unwrap().name(),
+            "COMMON_Whole_Simple"
         );
+    }
 
-        let it_decimal_thousand_separator = italian_culture
-            .get_patterns()
-            .into_iter()
-            .find(|f| f.regex.type_parsing == TypeParsing::DecimalThousandSeparator)
-            .unwrap();
+    #[test]
+    fn test_pattern_precedence() {
+        let names = |patterns: Vec<ParsingPattern>| {
+            patterns.iter().map(|p| p.name().to_owned()).collect::<Vec<String>>()
+        };
+
+        assert!(TypeParsing::DecimalThousandSeparator.specificity() > TypeParsing::DecimalSimple.specificity());
+        assert!(TypeParsing::WholeThousandSeparator.specificity() > TypeParsing::DecimalSimple.specificity());
+        assert!(TypeParsing::DecimalSimple.specificity() > TypeParsing::WholeSimple.specificity());
+        assert!(TypeParsing::WholeSimple.specificity() > TypeParsing::Custom.specificity());
+
+        // "1,000" is an english thousand or a french decimal : the thousand separator pattern is more specific
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("fr2"), TypeParsing::DecimalSimple, Some(Culture::French.into())).unwrap(),
+        );
         assert_eq!(
-            it_decimal_thousand_separator.name,
-            String::from("IT_Decimal_Thousand_Separator")
+            names(ConvertString::find_all_patterns("1,000", &Culture::English, &patterns)),
+            vec!["EN_Whole_Thousand_Separator", "FR2_Decimal_Simple"]
         );
         assert_eq!(
-            it_decimal_thousand_separator.regex.content.as_str(),
-            r"[\-\+]?[0-9]+([\.][0-9]{3})+[,][0-9]*",
-            "Error italian culture DecimalThousandSeparator"
+            ConvertString::find_pattern("1,000", &Culture::English, &patterns).unwrap().name(),
+            "EN_Whole_Thousand_Separator"
         );
-    }
+        // Same for "1.000" in italian, against an english decimal
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("en2"), TypeParsing::DecimalSimple, Some(Culture::English.into())).unwrap(),
+        );
+        assert_eq!(
+            names(ConvertString::find_all_patterns("1.000", &Culture::Italian, &patterns)),
+            vec!["IT_Whole_Thousand_Separator", "EN2_Decimal_Simple"]
+        );
+        assert_eq!(ConvertString::new("1.000", Some(Culture::Italian)).to_number::<f64>().unwrap(), 1000.0);
 
-    // #[test]
-    // fn test_number_culture_settings() {
-    //     assert_eq!(
-    //         NumberCultureSettings::from((" ", ",", ThousandGrouping::ThreeBlock)),
-    //         Culture::french_culture()
-    //     );
-    //     assert_eq!(
-    //         NumberCultureSettings::from(Culture::English),
-    //         Culture::english_culture()
-    //     );
-    // }
+        // The priority is stronger than the specificity
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("fr2"), TypeParsing::DecimalSimple, Some(Culture::French.into()))
+                .unwrap()
+                .with_priority(1),
+        );
+        assert_eq!(
+            ConvertString::find_pattern("1,000", &Culture::English, &patterns).unwrap().name(),
+            "FR2_Decimal_Simple"
+        );
 
-    #[test]
-    fn test_common_number() {
-        let convert = ConvertString::new("10,2", Some(Culture::French));
-        assert!(convert.is_float());
-    }
+        // A custom pattern is less specific than the built-in ones
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(ParsingPattern::custom("any", r"[0-9,\.]+", NumberType::DECIMAL).unwrap());
+        assert_eq!(
+            names(ConvertString::find_all_patterns("1,000.5", &Culture::English, &patterns)),
+            vec!["EN_Decimal_Thousand_Separator", "ANY_Custom"]
+        );
 
-    #[test]
-    fn test_number_french() {
-        let list = vec![
-            ("10", 10, 10.0, NumberType::WHOLE),
-            ("+10", 10, 10.0, NumberType::WHOLE),
-            ("-102", -102, -102., NumberType::WHOLE),
-            ("+1 000", 1000, 1000.0, NumberType::WHOLE),
-            ("2 500 563", 2500563, 2_500_563.0, NumberType::WHOLE),
-            ("-200000", -200000, -200000.0, NumberType::WHOLE),
-            (",25", 0, 0.25, NumberType::DECIMAL),
-            ("10,2", 10, 10.2, NumberType::DECIMAL),
-            ("0,25", 0, 0.25, NumberType::DECIMAL),
-            ("-10,5", -10, -10.5, NumberType::DECIMAL),
-            ("1000,89", 1000, 1000.89, NumberType::DECIMAL),
-            (
-                "+1 000,4564654654654",
-                1000,
-                1000.4564654654654,
-                NumberType::DECIMAL,
-            ),
-            (
-                "1000,4564654654654",
-                1000,
-                1000.4564654654654,
-                NumberType::DECIMAL,
-            ),
-        ];
-        test_number(Some(Culture::French), list);
-    }
+        // Equal patterns keep the insertion order : the common patterns first, then the culture ones
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("first"), TypeParsing::DecimalSimple, Some(Culture::English.into())).unwrap(),
+        );
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("second"), TypeParsing::DecimalSimple, Some(Culture::English.into())).unwrap(),
+        );
+        assert_eq!(
+            names(ConvertString::find_all_patterns("10.5", &Culture::English, &patterns)),
+            vec!["FIRST_Decimal_Simple", "SECOND_Decimal_Simple", "EN_Decimal_Simple"]
+        );
 
-    #[test]
-    fn test_number_english() {
-        let list = vec![
-            ("10", 10, 10.0, NumberType::WHOLE),
-            ("-102", -102, -102., NumberType::WHOLE),
-            ("1,000", 1000, 1000.0, NumberType::WHOLE),
-            ("-200000", -200000, -200000.0, NumberType::WHOLE),
-            ("2,500,563", 2500563, 2_500_563.0, NumberType::WHOLE),
-            ("10.2", 10, 10.2, NumberType::DECIMAL),
-            ("0.4", 0, 0.4, NumberType::DECIMAL),
-            ("0.25", 0, 0.25, NumberType::DECIMAL),
-            ("-10.5", -10, -10.5, NumberType::DECIMAL),
-            ("1000.89", 1000, 1000.89, NumberType::DECIMAL),
-            (
-                "1,000.4564654654654",
-                1000,
-                1000.4564654654654,
-                NumberType::DECIMAL,
-            ),
-            (
-                "1000.4564654654654",
-                1000,
-                1000.4564654654654,
-                NumberType::DECIMAL,
-            ),
-        ];
-        test_number(Some(Culture::English), list);
+        // The result is deterministic
+        for _ in 0..10 {
+            assert_eq!(
+                ConvertString::find_pattern("10.5", &Culture::English, &patterns).unwrap().name(),
+                "FIRST_Decimal_Simple"
+            );
+        }
     }
 
     #[test]
-    fn test_number_italian() {
+    fn test_parse_with_context() {
         let list = vec![
-            ("10", 10, 10.0, NumberType::WHOLE),
-            ("-102", -102, -102., NumberType::WHOLE),
-            ("1.000", 1000, 1000.0, NumberType::WHOLE),
-            ("-200000", -200000, -200000.0, NumberType::WHOLE),
-            ("2.500.563", 2500563, 2_500_563.0, NumberType::WHOLE),
-            ("10,2", 10, 10.2, NumberType::DECIMAL),
-            ("0,4", 0, 0.4, NumberType::DECIMAL),
-            ("0,25", 0, 0.25, NumberType::DECIMAL),
-            ("-10,5", -10, -10.5, NumberType::DECIMAL),
-            ("1000,89", 1000, 1000.89, NumberType::DECIMAL),
-            (
-                "1.000,4564654654654",
-                1000,
-                1000.4564654654654,
-                NumberType::DECIMAL,
-            ),
-            (
-                "1.000,4564654654654",
-                1000,
-                1000.4564654654654,
-                NumberType::DECIMAL,
-            ),
+            ("10", Culture::English, 10.0, "10", "COMMON_Whole_Simple"),
+            ("-10.5", Culture::English, -10.5, "-10.5", "EN_Decimal_Simple"),
+            (",25", Culture::French, 0.25, ".25", "FR_Decimal_Without_Whole_Part"),
+            ("2.500.563", Culture::Italian, 2500563.0, "2500563", "IT_Whole_Thousand_Separator"),
+            ("+1 000,5", Culture::French, 1000.5, "+1000.5", "FR_Decimal_Thousand_Separator"),
+            ("1,00,000.5", Culture::Indian, 100000.5, "100000.5", "ID_Decimal_Thousand_Separator"),
         ];
-        test_number(Some(Culture::Italian), list);
-    }
-
-    fn test_number(culture: Option<Culture>, list: Vec<(&str, i32, f32, NumberType)>) {
-        for (string_num, int_value, float_value, number_type) in list {
-            let convert = ConvertString::new(string_num, culture.to_owned());
 
-            //All input are valid number
-            assert_eq!(convert.is_numeric(), true, "Numeric number expected");
-            assert_eq!(
-                convert.is_integer(),
-                number_type == NumberType::WHOLE,
-                "Number should be a {}",
-                if number_type == NumberType::WHOLE {
-                    "integer"
-                } else {
-                    "decimal"
-                }
-            );
+        for (string_num, culture, value, cleaned, pattern_name) in list {
+            let context = parse_with_context::<f64>(string_num, Some(culture)).unwrap();
             assert_eq!(
-                convert.is_float(),
-                number_type == NumberType::DECIMAL,
-                "Number should be a {}",
-                if number_type == NumberType::WHOLE {
-                    "integer"
-                } else {
-                    "float"
+                context,
+                ParseContext {
+                    value,
+                    original: string_num.to_owned(),
+                    cleaned: cleaned.to_owned(),
+                    culture_used: Some(culture),
+                    pattern_name: Some(pattern_name.to_owned()),
                 }
             );
+        }
 
-            let to_integer = convert.to_number::<i32>();
-            if number_type == NumberType::WHOLE {
-                assert!(to_integer.is_ok(), "to_number() return Err instead of Ok");
-                assert_eq!(
-                    convert.to_number::<i32>().unwrap(),
-                    int_value,
-                    "to_integer() conversion failed for {}",
-                    string_num
-                );
-            } else {
-                assert!(to_integer.is_err(), "to_number() return Ok instead of Err");
-                assert_eq!(
-                    convert.to_number::<i32>(),
-                    Err(ConversionError::UnableToConvertStringToNumber)
-                );
-            }
+        // Without culture, the patterns of all the cultures are tried
+        let context = parse_with_context::<i32>("1 000", None).unwrap();
+        assert_eq!(context.value, 1000);
+        assert_eq!(context.original, "1 000");
+        assert_eq!(context.cleaned, "1000");
+        assert_eq!(context.culture_used, Some(Culture::French));
+        assert_eq!(context.pattern_name, Some("FR_Whole_Thousand_Separator".to_owned()));
 
-            let to_float = convert.to_number::<f32>();
-            assert!(to_float.is_ok(), "to_float() return Err instead of Ok");
-            assert_eq!(
-                convert.to_number::<f32>().unwrap(),
-                float_value,
-                "to_float() conversion failed for {}",
-                string_num
-            );
-        }
-    }
+        let context = parse_with_context::<i32>("-42", None).unwrap();
+        assert_eq!(context.culture_used, Some(Culture::English));
+        assert_eq!(context.pattern_name, Some("COMMON_Whole_Simple".to_owned()));
 
-    #[test]
-    fn test_number_unauthorized() {
-        let list = vec!["1..0", "1.,0", ",1.0", "+-0.2", "20 00", "-0,2245,45"];
-        let cultures = &vec![
-            None,
-            Some(Culture::English),
-            Some(Culture::French),
-            Some(Culture::Italian),
-        ];
+        // Without matching pattern, the string is only cleaned from its spaces
+        let context = parse_with_context::<i32>(" 42", None).unwrap();
+        assert_eq!(context.cleaned, "42");
+        assert_eq!(context.culture_used, None);
+        assert_eq!(context.pattern_name, None);
 
-        for string_num in list {
-            for culture in cultures.into_iter() {
-                let convert = ConvertString::new(string_num, culture.to_owned());
-                assert_eq!(convert.is_numeric(), false, "Numeric shouldn't be parsed");
-            }
-        }
+        assert_eq!(
+            parse_with_context::<i32>("abc", Some(Culture::English)),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            parse_with_context::<i32>("10,5", Some(Culture::French)),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
     }
 
     #[test]
@@ -0,0 +1,49 @@
+//! Re-export the most commonly used items
+//!
+//! ``` rust
+//! use num_string::prelude::*;
+//!     assert_eq!("1 000,5".to_number_culture::<f64>(Culture::French).unwrap(), 1000.5);
+//!     assert_eq!(1000.5.to_format("N1", Culture::English).unwrap(), "1,000.5");
+//! ```
+
+pub use crate::errors::ConversionError;
+pub use crate::number_to_string::{FormatOption, RoundingMode, ToFormat};
+pub use crate::pattern::{
+    ConvertString, NumberCultureSettings, NumberType, Separator, Sign, ThousandGrouping, TypeParsing,
+};
+pub use crate::string_to_number::NumberConversion;
+pub use crate::Culture;
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_prelude() {
+        assert_eq!("1,000".to_number_culture::<i32>(Culture::English).unwrap(), 1000);
+        assert_eq!(
+            "1'000.5"
+                .to_number_separators::<f64>(
+                    NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT)
+                        .with_grouping(ThousandGrouping::ThreeBlock)
+                )
+                .unwrap(),
+            1000.5
+        );
+        assert_eq!("abc".to_number::<i32>(), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(1000.to_format("N2", Culture::French).unwrap(), "1 000,00");
+
+        let string_num = ConvertString::new("1,000.5", Some(Culture::English));
+        let pattern = string_num.get_current_pattern().unwrap();
+        assert_eq!(pattern.get_number_type(), &NumberType::DECIMAL);
+        assert_eq!(pattern.get_regex().get_type_parsing(), &TypeParsing::DecimalThousandSeparator);
+
+        assert_eq!(ConvertString::new("-1", None).split_parts().unwrap().sign, Sign::Negative);
+
+        let _ = FormatOption::new(2, 2).with_grouping(ThousandGrouping::TwoBlock);
+
+        // Same items as the crate root re-exports
+        let _: crate::ConvertString = ConvertString::new("1", None);
+        let _: crate::NumberCultureSettings = NumberCultureSettings::from(Culture::Indian);
+    }
+}
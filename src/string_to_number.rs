@@ -1,10 +1,11 @@
 use crate::Culture;
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, str::FromStr, sync::OnceLock};
 
 use log::{trace, info, debug};
+use num::Bounded;
 use regex::Regex;
 
-use crate::{errors::ConversionError, pattern::NumberCultureSettings};
+use crate::{errors::ConversionError, pattern::{ConvertString, NumberCultureSettings, NumberParts, NumberType, RegexPattern, Separator, Sign, ThousandGrouping, TypeParsing}};
 
 /// Trait implemented to convert a string number to Rust number
 /// ``` rust
@@ -31,10 +32,460 @@ pub trait NumberConversion {
         &self,
         culture: Culture,
     ) -> Result<N, ConversionError>;
+
+    /// Try to convert a string with given culture, and check that the thousand grouping is consistent
+    fn to_number_validated<N: num::Num + Display + FromStr>(
+        &self,
+        culture: Culture,
+    ) -> Result<N, ConversionError>;
+
+    /// Try to convert a string with given culture, and check it against the given parse options
+    fn to_number_culture_options<N: num::Num + Display + FromStr>(
+        &self,
+        culture: Culture,
+        options: ParseOption,
+    ) -> Result<N, ConversionError>;
+
+    /// Try to convert a string with all cultures, and return the value with the detected culture.
+    /// If several cultures give the same value, the first culture (in Culture order) is returned.
+    /// If they give different values, return AmbiguousInput
+    fn to_number_detect_culture<N: num::Num + Display + FromStr>(
+        &self,
+    ) -> Result<(N, Culture), ConversionError>;
+
+    /// Try to convert a string in the given radix (2 to 36), not culture dependent.
+    /// The prefix of the radix (0b, 0o or 0x) is optional
+    fn to_number_radix<N: num::Num + Display + FromStr>(
+        &self,
+        radix: u32,
+    ) -> Result<N, ConversionError>;
+
+    /// Try to convert a string number followed by a unit ("100.5 kg", "1,000 EUR", "50%") with given culture.
+    /// Return the number, and the unit if any
+    fn to_number_with_unit<N: num::Num + Display + FromStr>(
+        &self,
+        culture: Culture,
+    ) -> Result<(N, Option<String>), ConversionError>;
+
+    /// Try to convert a string number followed by an optional scale suffix and an optional unit ("1.5K USD", "-50 €", "2M")
+    /// with given culture. The scale is applied on the digits, so "1.5K" is 1500 for the integer types too
+    /// Ref 'test_to_number_with_full_context'
+    fn to_number_with_full_context<N: num::Num + Display + FromStr>(
+        &self,
+        culture: Culture,
+    ) -> Result<NumberWithContext<N>, ConversionError>;
+
+    /// Try to convert a C99 hexadecimal float ("0x1.8p3" = 12.0), not culture dependent.
+    /// The binary exponent (after 'p') is required
+    fn to_number_hexfloat<N: num::Num + Display + FromStr>(&self) -> Result<N, ConversionError>;
+
+    /// Try to convert a string with given culture, only if it's written as an integer.
+    /// Return ExpectedInteger if the string number has a decimal part ("1.5", but also "1.0"), instead of truncating it
+    /// Ref 'test_to_integer_strict'
+    fn to_integer_strict<N: num::Num + Display + FromStr>(&self, culture: Culture) -> Result<N, ConversionError>;
+
+    /// Try to convert a string with given culture, only if it's written exactly as the culture writes it :
+    ///  - a pattern of the culture must match the whole string (no extra characters), otherwise NoExactPatternMatch
+    ///  - a string of digits and separators with an inconsistent grouping returns InvalidGrouping
+    ///  - the culture separators are required above 999 : a number matching only the WholeSimple or DecimalSimple pattern
+    ///    ("1000" or "1234.5" in English) returns NoExactPatternMatch
+    ///
+    /// Ref 'test_to_number_strict'
+    fn to_number_strict<N: num::Num + Display + FromStr>(&self, culture: Culture) -> Result<N, ConversionError>;
+
+    /// Split the string number of the given culture into its sign, whole part and decimal part, without separators.
+    /// The decimal part is None for whole numbers, and the whole part is "0" when omitted (",5" in French)
+    /// Ex : "-1 234,56" in French = (Negative, "1234", Some("56"))
+    /// Ref 'test_parts_culture'
+    fn parts_culture(&self, culture: Culture) -> Result<NumberParts, ConversionError>;
+
+    /// Try to convert a string with given culture, and return if precision was lost by the type N
+    /// (ex: "1.123456789012345" in f32 = (1.1234568, true), in f64 = (1.123456789012345, false)).
+    /// The value is compared to the f64 conversion, so the precision lost by f64 itself is not detected
+    /// Ref 'test_to_number_with_precision_check'
+    fn to_number_with_precision_check<N: num::Num + Display + FromStr>(
+        &self,
+        culture: Culture,
+    ) -> Result<(N, bool), ConversionError>;
+
+    /// Try to convert a common string (not culture dependent), and clamp the value to the bounds of the type N
+    /// if it doesn't fit ("300" in i8 = 127, "-300" in i8 = -128). Return the number, and true if it has been clamped.
+    /// It still fails for the non numeric strings, and for the decimal numbers in the bounds of an integer type ("1.5" in i8)
+    /// Ref 'test_to_number_clamped'
+    fn to_number_clamped<N: num::Num + Display + FromStr + Bounded>(&self) -> Result<(N, bool), ConversionError>;
+
+    /// Start a parse chain on the string number, to set the cultures and the validation before converting
+    /// Ex : "1,000.50".to_number_parse_chain().culture(Culture::English).strict().convert::<f64>()
+    /// Ref 'test_parse_chain'
+    fn to_number_parse_chain(&self) -> ParseChain<'_>;
+
+    /// Try to convert a common string (not culture dependent), and return the default value if the conversion fails
+    /// Ref 'test_to_number_or'
+    fn to_number_or<N: num::Num + Display + FromStr>(&self, default: N) -> N {
+        self.to_number().unwrap_or(default)
+    }
+
+    /// Try to convert a common string (not culture dependent), and compute the value from the error if the conversion fails
+    /// (ex: 0 for an empty cell, but NaN for an invalid one)
+    /// Ref 'test_to_number_or'
+    fn to_number_or_else<N: num::Num + Display + FromStr, F: FnOnce(ConversionError) -> N>(&self, f: F) -> N {
+        self.to_number().unwrap_or_else(f)
+    }
+
+    /// Try to convert a common string (not culture dependent), None if the conversion fails
+    /// Ref 'test_to_number_ok'
+    fn to_number_ok<N: num::Num + Display + FromStr>(&self) -> Option<N> {
+        self.to_number().ok()
+    }
+
+    /// Try to convert a string with given culture, and return the default value if the conversion fails
+    /// Ref 'test_to_number_or'
+    fn to_number_culture_or<N: num::Num + Display + FromStr>(&self, default: N, culture: Culture) -> N {
+        self.to_number_culture(culture).unwrap_or(default)
+    }
+
+    /// Try to convert a string with given culture, and compute the value from the error if the conversion fails
+    /// Ref 'test_to_number_or'
+    fn to_number_culture_or_else<N: num::Num + Display + FromStr, F: FnOnce(ConversionError) -> N>(&self, f: F, culture: Culture) -> N {
+        self.to_number_culture(culture).unwrap_or_else(f)
+    }
+
+    /// Try to convert a string with given culture, None if the conversion fails
+    /// Ref 'test_to_number_ok'
+    fn to_number_culture_ok<N: num::Num + Display + FromStr>(&self, culture: Culture) -> Option<N> {
+        self.to_number_culture(culture).ok()
+    }
+
+    /// Try to convert a string with given thousand and decimal separator, and return the default value if the conversion fails
+    /// Ref 'test_to_number_or'
+    fn to_number_separators_or<N: num::Num + Display + FromStr>(&self, default: N, separators: NumberCultureSettings) -> N {
+        self.to_number_separators(separators).unwrap_or(default)
+    }
+
+    /// Try to convert a string with given thousand and decimal separator, and compute the value from the error if the conversion fails
+    /// Ref 'test_to_number_or'
+    fn to_number_separators_or_else<N: num::Num + Display + FromStr, F: FnOnce(ConversionError) -> N>(
+        &self,
+        f: F,
+        separators: NumberCultureSettings,
+    ) -> N {
+        self.to_number_separators(separators).unwrap_or_else(f)
+    }
+
+    /// Try to convert a string with given thousand and decimal separator, None if the conversion fails
+    /// Ref 'test_to_number_ok'
+    fn to_number_separators_ok<N: num::Num + Display + FromStr>(&self, separators: NumberCultureSettings) -> Option<N> {
+        self.to_number_separators(separators).ok()
+    }
+
+    /// Same as 'to_number_ok'
+    /// Ref 'test_to_number_option'
+    fn to_number_option<N: num::Num + Display + FromStr>(&self) -> Option<N> {
+        self.to_number_ok()
+    }
+
+    /// Same as 'to_number_culture_ok'
+    /// Ref 'test_to_number_option'
+    fn to_number_culture_option<N: num::Num + Display + FromStr>(&self, culture: Culture) -> Option<N> {
+        self.to_number_culture_ok(culture)
+    }
+
+    /// Same as 'to_number_separators_ok'
+    /// Ref 'test_to_number_option'
+    fn to_number_separators_option<N: num::Num + Display + FromStr>(&self, separators: NumberCultureSettings) -> Option<N> {
+        self.to_number_separators_ok(separators)
+    }
+}
+
+/// Invisible characters removed by the sanitization (see ParseOption::with_sanitize and ConvertString::with_sanitize).
+///
+/// These characters are often found in strings copied from PDF or RTL web pages :
+///  - zero width characters : U+200B (space), U+200C (non-joiner), U+200D (joiner), U+2060 (word joiner), U+FEFF (BOM)
+///  - bidi marks : U+200E (LRM), U+200F (RLM), U+061C (ALM)
+///  - bidi embeddings / overrides / isolates : U+202A to U+202E, U+2066 to U+2069
+pub const INVISIBLE_CHARACTERS: [char; 17] = [
+    '\u{200B}', '\u{200C}', '\u{200D}', '\u{2060}', '\u{FEFF}',
+    '\u{200E}', '\u{200F}', '\u{061C}',
+    '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}',
+    '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+/// The typographic minus sign (U+2212), accepted as a negative sign when parsing (see FormatOption::with_minus_char)
+pub const MINUS_SIGN: char = '\u{2212}';
+
+/// Remove the invisible characters (zero width and bidi control characters) from the string
+/// Ref 'test_sanitize'
+pub fn sanitize(value: &str) -> String {
+    value.chars().filter(|c| !INVISIBLE_CHARACTERS.contains(c)).collect()
+}
+
+/// Return the AmbiguousInput error if the string number has different values depending on the culture
+/// (ex: "1,000" is 1000 in English and 1 in French), None otherwise
+/// Ref 'test_detect_conflicts'
+pub fn detect_conflicts(s: &str) -> Option<ConversionError> {
+    match s.to_number_detect_culture::<f64>() {
+        Err(error @ ConversionError::AmbiguousInput { .. }) => Some(error),
+        _ => None,
+    }
+}
+
+/// Convert the string number with the given separators and grouping, without ever panicking (ex: for fuzzing).
+/// Return DuplicateSeparators if the separators are the same, and SeparatorNotFound if a separator is a digit or a sign.
+/// The thousand groups are checked against the grouping (see StringNumber::validate_grouping) and return InvalidGrouping
+/// Ref 'test_try_parse'
+pub fn try_parse(input: &str, thousand: char, decimal: char, grouping: ThousandGrouping) -> Result<f64, ConversionError> {
+    if [thousand, decimal].iter().any(|c| c.is_ascii_digit() || ['-', '+', MINUS_SIGN].contains(c)) {
+        return Err(ConversionError::SeparatorNotFound);
+    }
+
+    let settings = NumberCultureSettings::try_new(Separator::from_char(thousand), Separator::from_char(decimal))?
+        .with_grouping(grouping);
+    StringNumber::new_with_settings(String::from(input), settings).validate_grouping()?;
+    input.to_number_separators::<f64>(settings)
+}
+
+/// Fluent conversion of a string number : the cultures and the validation are stored, and the conversion is done by 'convert'
+///
+///  - culture : the culture of the string number (the common patterns are used when there is none)
+///  - try_culture : a fallback culture, tried in order when the previous ones fail
+///  - strict : the string number must be written exactly as the culture writes it (see NumberConversion::to_number_strict).
+///    Without any culture, all the cultures are tried in Culture order.
+///
+/// When all the cultures fail, the error of the first one is returned.
+/// Ex : ParseChain::new("1,000.50").culture(Culture::English).strict().convert::<f64>() = Ok(1000.5)
+/// Ref 'test_parse_chain'
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseChain<'a> {
+    input: &'a str,
+    culture: Option<Culture>,
+    fallbacks: Vec<Culture>,
+    strict: bool,
+}
+
+impl<'a> ParseChain<'a> {
+    /// Create a new parse chain (no culture, no validation)
+    pub fn new(input: &'a str) -> ParseChain<'a> {
+        ParseChain {
+            input,
+            culture: None,
+            fallbacks: Vec::new(),
+            strict: false,
+        }
+    }
+
+    /// Set the culture of the string number
+    pub fn culture(mut self, culture: Culture) -> Self {
+        self.culture = Some(culture);
+        self
+    }
+
+    /// Add a fallback culture, tried when the previous ones fail
+    pub fn try_culture(mut self, culture: Culture) -> Self {
+        self.fallbacks.push(culture);
+        self
+    }
+
+    /// Enable the strict validation
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Convert the string number with the stored options
+    pub fn convert<N: num::Num + Display + FromStr>(self) -> Result<N, ConversionError> {
+        let mut cultures: Vec<Culture> = self.culture.into_iter().chain(self.fallbacks).collect();
+        if cultures.is_empty() {
+            if !self.strict {
+                return self.input.to_number();
+            }
+            cultures = Culture::all().collect();
+        }
+
+        let mut first_error = None;
+        for culture in cultures {
+            let result = if self.strict {
+                self.input.to_number_strict(culture)
+            } else {
+                self.input.to_number_culture(culture)
+            };
+            match result {
+                Ok(number) => return Ok(number),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        Err(first_error.unwrap_or(ConversionError::UnableToConvertStringToNumber))
+    }
+}
+
+/// Structure with the validations applied when converting a string number to number
+///
+/// The options combine this way :
+///  - validate_grouping : the thousand separators which are present must be well placed ("1,00" is rejected)
+///  - require_grouping : numbers with a long enough whole part must be grouped ("1234567" is rejected in French).
+///    It implies validate_grouping.
+///  - minimum_grouping_digits : with require_grouping, the number of digits needed before the first thousand separator
+///    to make grouping mandatory (1 by default, so "1234" must be grouped, with 2 only "12345" must be).
+///    Grouped numbers below this threshold are still accepted.
+///
+/// When sanitize is set, the invisible characters (see INVISIBLE_CHARACTERS) are removed before anything else.
+///
+/// For float types, a number too big is an Overflow error (instead of infinity) unless allow_infinity is set,
+/// and a number too small is an Underflow error (instead of 0) unless allow_underflow is set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParseOption {
+    validate_grouping: bool,
+    require_grouping: bool,
+    minimum_grouping_digits: u8,
+    sanitize: bool,
+    allow_infinity: bool,
+    allow_underflow: bool,
+}
+
+impl ParseOption {
+    /// Create a new parse option (no validation)
+    pub fn new() -> ParseOption {
+        ParseOption::default()
+    }
+
+    /// Reject string numbers with misplaced thousand separators
+    pub fn with_validate_grouping(mut self, validate_grouping: bool) -> Self {
+        self.validate_grouping = validate_grouping;
+        self
+    }
+
+    /// Reject string numbers which should have thousand separators but don't
+    pub fn with_require_grouping(mut self, require_grouping: bool) -> Self {
+        self.require_grouping = require_grouping;
+        self
+    }
+
+    /// Change the number of digits needed before the first separator to require grouping
+    pub fn with_minimum_grouping_digits(mut self, minimum_grouping_digits: u8) -> Self {
+        self.minimum_grouping_digits = minimum_grouping_digits;
+        self
+    }
+
+    /// Remove the invisible characters before parsing
+    pub fn with_sanitize(mut self, sanitize: bool) -> Self {
+        self.sanitize = sanitize;
+        self
+    }
+
+    /// Return infinity instead of an Overflow error when the number is too big for the float type
+    pub fn with_allow_infinity(mut self, allow_infinity: bool) -> Self {
+        self.allow_infinity = allow_infinity;
+        self
+    }
+
+    /// Return 0 instead of an Underflow error when the number is too small for the float type
+    pub fn with_allow_underflow(mut self, allow_underflow: bool) -> Self {
+        self.allow_underflow = allow_underflow;
+        self
+    }
+}
+
+impl Default for ParseOption {
+    fn default() -> Self {
+        Self {
+            validate_grouping: false,
+            require_grouping: false,
+            minimum_grouping_digits: 1,
+            sanitize: false,
+            allow_infinity: false,
+            allow_underflow: false,
+        }
+    }
+}
+
+/// An inclusive range of numbers, used to check a converted number
+/// Ref 'test_number_range'
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberRange<N: PartialOrd + Display> {
+    min: N,
+    max: N,
+}
+
+impl<N: PartialOrd + Display> NumberRange<N> {
+    /// Create a new range, return InvalidRange if min is greater than max
+    pub fn new(min: N, max: N) -> Result<NumberRange<N>, ConversionError> {
+        // NaN bounds can't be compared, and are rejected too
+        if !matches!(min.partial_cmp(&max), Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal)) {
+            return Err(ConversionError::InvalidRange {
+                min_str: min.to_string(),
+                max_str: max.to_string(),
+            });
+        }
+
+        Ok(NumberRange { min, max })
+    }
+
+    pub fn min(&self) -> &N {
+        &self.min
+    }
+
+    pub fn max(&self) -> &N {
+        &self.max
+    }
+
+    /// Return true if the number is between min and max (inclusive)
+    pub fn contains(&self, number: &N) -> bool {
+        &self.min <= number && number <= &self.max
+    }
+}
+
+/// The scale suffixes written after a number, the same as the compact display of 'to_format_smart' ("1.5K" = 1500)
+/// Ref 'test_to_number_with_full_context'
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleSuffix {
+    /// K (or k), thousand
+    Kilo,
+    /// M, million
+    Mega,
+    /// B (or G), billion
+    Giga,
+    /// T, trillion
+    Tera,
+}
+
+impl ScaleSuffix {
+    /// Get the scale from its letter, None for the other characters
+    pub fn from_char(c: char) -> Option<ScaleSuffix> {
+        match c {
+            'K' | 'k' => Some(ScaleSuffix::Kilo),
+            'M' => Some(ScaleSuffix::Mega),
+            'B' | 'G' => Some(ScaleSuffix::Giga),
+            'T' => Some(ScaleSuffix::Tera),
+            _ => None,
+        }
+    }
+
+    /// The power of ten of the scale (3 for Kilo)
+    pub fn exponent(&self) -> usize {
+        match self {
+            ScaleSuffix::Kilo => 3,
+            ScaleSuffix::Mega => 6,
+            ScaleSuffix::Giga => 9,
+            ScaleSuffix::Tera => 12,
+        }
+    }
+}
+
+/// A number read with its context by 'to_number_with_full_context'
+/// Ex : "1.5K USD" = (1500, Some("USD"), Some(Kilo), Positive)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberWithContext<N> {
+    /// The converted number, with the scale applied
+    pub value: N,
+    pub unit: Option<String>,
+    pub scale: Option<ScaleSuffix>,
+    pub sign: Sign,
 }
 
 /// Structure which represent a string number (can be either well formated or bad formated)
-struct StringNumber {
+pub(crate) struct StringNumber {
     value: String,
     number_culture_settings: Option<NumberCultureSettings>,
 }
@@ -74,6 +525,161 @@ impl StringNumber {
         self.number_culture_settings.as_ref()
     }
 
+    /// Split the whole part (without sign and decimal part) on the thousand separator
+    fn whole_part_groups<'a>(
+        value: &'a str,
+        settings: &NumberCultureSettings,
+    ) -> Result<Vec<&'a str>, ConversionError> {
+        let decimal_regex = Regex::new(&settings.into_decimal_separator_regex())
+            .map_err(|_| ConversionError::RegexBuilder)?;
+        let whole_part = decimal_regex
+            .split(value)
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches(['+', '-', MINUS_SIGN]);
+
+        let thousand_regex = Regex::new(&settings.into_thousand_separator_regex())
+            .map_err(|_| ConversionError::RegexBuilder)?;
+        Ok(thousand_regex.split(whole_part).collect())
+    }
+
+    /// Check that the thousand grouping of the whole part is consistent with the settings.
+    ///
+    /// Every group except the leftmost must have exactly the expected size :
+    /// 3 for ThreeBlock, 2 for TwoBlock (except the rightmost group which is 3).
+    /// The leftmost group can be shorter, but not longer.
+    /// Ref 'test_validate_grouping'
+    pub fn validate_grouping(&self) -> Result<(), ConversionError> {
+        let settings = match self.get_settings() {
+            Some(settings) => settings,
+            None => return Ok(()),
+        };
+
+        // Only the whole part is grouped
+        let groups = StringNumber::whole_part_groups(&self.value, settings)?;
+
+        // No thousand separator, nothing to check
+        if groups.len() == 1 {
+            return Ok(());
+        }
+
+        for (index, group) in groups.iter().rev().enumerate() {
+            let expected = match settings.thousand_grouping() {
+                ThousandGrouping::TwoBlock if index > 0 => 2,
+                _ => 3,
+            };
+            let found = group.chars().count() as u8;
+            let is_leftmost = index == groups.len() - 1;
+
+            if (is_leftmost && (found == 0 || found > expected)) || (!is_leftmost && found != expected) {
+                debug!("Invalid group '{}' in {} (expected {} digits)", group, self.value, expected);
+                return Err(ConversionError::InvalidGrouping { expected, found });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the string number against the parse options (see ParseOption for the combined rules)
+    /// Ref 'test_parse_option'
+    pub fn check_options(&self, options: ParseOption) -> Result<(), ConversionError> {
+        let settings = match self.get_settings() {
+            Some(settings) => settings,
+            None => return Ok(()),
+        };
+
+        if options.validate_grouping || options.require_grouping {
+            self.validate_grouping()?;
+        }
+
+        if options.require_grouping {
+            let groups = StringNumber::whole_part_groups(&self.value, settings)?;
+            let nb_digits = groups[0].chars().count();
+
+            // The first separator is always after 3 digits, whatever the grouping
+            if groups.len() == 1 && nb_digits >= 3 + options.minimum_grouping_digits as usize {
+                debug!("Thousand separator required for {}", self.value);
+                return Err(ConversionError::MissingGrouping);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clean and parse the string number, then check the float overflow / underflow
+    /// Ref 'test_float_overflow', 'test_multiple_decimal_separators'
+    fn parse<N: num::Num + Display + FromStr>(&self, options: ParseOption) -> Result<N, ConversionError> {
+        let cleaned = self.clean();
+
+        // After the clean, every decimal separator has been replaced by the Rust one
+        if cleaned.matches(StringNumber::string_decimal_replacement().as_str()).count() > 1 {
+            debug!("{} has more than one decimal separator", self.value);
+            return Err(ConversionError::UnableToConvertStringToNumber);
+        }
+
+        // The types without a decimal notation (like num::rational::Ratio) are parsed from the fraction ("0.25" = "25/100")
+        let number = match cleaned.parse::<N>() {
+            Ok(number) => number,
+            Err(_e) => StringNumber::decimal_to_fraction(&cleaned)
+                .and_then(|fraction| fraction.parse::<N>().ok())
+                .ok_or(ConversionError::UnableToConvertStringToNumber)?,
+        };
+
+        // Only the mantissa digits tell if the number is 0, not the exponent ones ("0e5" = 0)
+        let mantissa = cleaned.split(['e', 'E']).next().unwrap_or_default();
+        StringNumber::check_float_range(number, mantissa, 10, options)
+    }
+
+    /// Check that the float parse kept the number : Overflow if it has been converted to infinity,
+    /// Underflow if it has been rounded to 0 while its mantissa has a nonzero digit (in the given radix).
+    /// The other types are never infinite nor rounded to 0
+    /// Ref 'test_float_overflow', 'test_float_underflow'
+    pub(crate) fn check_float_range<N: num::Num + FromStr>(
+        number: N,
+        mantissa: &str,
+        radix: u32,
+        options: ParseOption,
+    ) -> Result<N, ConversionError> {
+        let mut digits = mantissa.chars().filter(|c| c.is_digit(radix)).peekable();
+        let has_digits = digits.peek().is_some();
+        let is_infinite = ["inf", "-inf"].iter().any(|infinity| infinity.parse::<N>().is_ok_and(|infinity| infinity == number));
+
+        if !options.allow_infinity && is_infinite && has_digits {
+            debug!("{} is too big and has been converted to infinity", mantissa);
+            return Err(ConversionError::Overflow);
+        }
+        if !options.allow_underflow && number.is_zero() && digits.any(|c| c != '0') {
+            debug!("{} is too small and has been converted to 0", mantissa);
+            return Err(ConversionError::Underflow);
+        }
+
+        Ok(number)
+    }
+
+    /// Write the cleaned decimal string number as a fraction of a power of ten ("-0.25" = "-025/100"), None without decimal part
+    fn decimal_to_fraction(cleaned: &str) -> Option<String> {
+        let (whole, decimal) = cleaned.split_once('.')?;
+        Some(format!("{}{}/1{}", whole, decimal, "0".repeat(decimal.len())))
+    }
+
+    /// Replace the full-width digits, comma, period and space (from CJK keyboards) by their ASCII equivalent
+    /// Ex : "１，２３４．５" = "1,234.5"
+    /// Ref 'test_number_full_width'
+    fn normalize_full_width(value: &str) -> String {
+        value
+            .chars()
+            .map(|c| match c {
+                '\u{FF10}'..='\u{FF19}' => char::from(b'0' + (c as u32 - 0xFF10) as u8),
+                '\u{FF0C}' => ',',
+                '\u{FF0E}' => '.',
+                '\u{3000}' => ' ',
+                '\u{FF0B}' => '+',
+                '\u{FF0D}' => '-',
+                c => c,
+            })
+            .collect()
+    }
+
     /// Replace the string which match the regex by the replacement string
     fn replace_element(string_number: &str, string_regex: &str, replacement: &str) -> String {
         // let regex_space = Regex::new(format!(r"[\\{}]", string_regex).as_str()).unwrap();
@@ -90,6 +696,21 @@ impl StringNumber {
         cleaned_input.to_string()
     }
 
+    /// Remove the underscores used as digit separator ("1_000_000"), only between two digits like in Rust literals
+    /// Ref 'test_underscore_separator'
+    fn remove_digit_underscores(value: &str) -> String {
+        let chars = value.chars().collect::<Vec<char>>();
+        chars
+            .iter()
+            .enumerate()
+            .filter(|(index, c)| {
+                let is_digit = |index: Option<usize>| index.and_then(|index| chars.get(index)).is_some_and(|c| c.is_ascii_digit());
+                **c != '_' || !(is_digit(index.checked_sub(1)) && is_digit(Some(index + 1)))
+            })
+            .map(|(_, c)| c)
+            .collect()
+    }
+
     /// Create regex from struct to clean the string.
     ///
     /// Return the string cleaned.
@@ -98,7 +719,8 @@ impl StringNumber {
             "Clean with string input = {} and separators = {:?}",
             &self.value, &self.number_culture_settings
         );
-        let mut string_value = self.value.clone();
+        // The typographic minus sign and the full-width forms are read as the ASCII ones
+        let mut string_value = StringNumber::normalize_full_width(&self.value).replace(MINUS_SIGN, "-");
 
         // Shortcut closure to call replace_element function
         let replace = |string_input: &str, separator: &str, replacement: &str| {
@@ -111,6 +733,12 @@ impl StringNumber {
 
         //Clean decimal and thousand separator if needed
         if self.has_settings() {
+            // With SPACE as decimal separator, only the inner whitespace is the decimal separator :
+            // the leading and trailing whitespaces would be replaced by the decimal replacement
+            if self.get_settings().unwrap().decimal_separator() == Separator::SPACE {
+                string_value = string_value.trim().to_owned();
+            }
+
             debug!(
                 "Decimal ({}) and thousand ({}) separator has been specified",
                 &self.get_settings().unwrap().into_decimal_separator_string(),
@@ -139,7 +767,7 @@ impl StringNumber {
                 string_value
             );
         } else {
-            string_value = replace(&string_value, r"\s", "");
+            string_value = StringNumber::remove_digit_underscores(&replace(&string_value, r"\s", ""));
         }
 
         debug!(
@@ -158,6 +786,12 @@ impl NumberConversion for &str {
         N: std::fmt::Display,
         N: std::str::FromStr,
     {
+        // Hexadecimal floats bypass the culture machinery
+        let unsigned = self.trim().trim_start_matches(['-', '+']);
+        if unsigned.starts_with("0x") || unsigned.starts_with("0X") {
+            return self.to_number_hexfloat();
+        }
+
         StringNumber::new(String::from(*self)).to_number()
     }
 
@@ -182,94 +816,579 @@ impl NumberConversion for &str {
         StringNumber::new_with_settings(String::from(*self), culture.into())
             .to_number()
     }
-}
 
-impl NumberConversion for StringNumber {
-    fn to_number<N: num::Num + Display + FromStr>(&self) -> Result<N, ConversionError> {
-        Ok(self
-            .clean()
-            .parse::<N>()
-            .map_err(|_e| ConversionError::UnableToConvertStringToNumber)?)
+    fn to_number_validated<N>(&self, culture: Culture) -> Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        StringNumber::new_with_settings(String::from(*self), culture.into())
+            .to_number_validated(culture)
     }
 
-    fn to_number_separators<N>(
+    fn to_number_culture_options<N>(
         &self,
-        _pattern: NumberCultureSettings,
-    ) -> std::result::Result<N, ConversionError>
+        culture: Culture,
+        options: ParseOption,
+    ) -> Result<N, ConversionError>
     where
         N: num::Num,
         N: std::fmt::Display,
         N: std::str::FromStr,
     {
-        self.to_number()
+        let value = if options.sanitize { sanitize(self) } else { String::from(*self) };
+        StringNumber::new_with_settings(value, culture.into())
+            .to_number_culture_options(culture, options)
     }
 
-    fn to_number_culture<N>(&self, _: Culture) -> std::result::Result<N, ConversionError>
+    fn to_number_detect_culture<N>(&self) -> Result<(N, Culture), ConversionError>
     where
         N: num::Num,
         N: std::fmt::Display,
         N: std::str::FromStr,
     {
-        self.to_number()
-    }
-}
+        let patterns = ConvertString::load_patterns();
 
-#[cfg(test)]
-mod tests {
-    use regex::escape;
+        // Keep only the cultures with a matching pattern and a successful conversion
+        let matches = enum_iterator::all::<Culture>()
+            .filter(|culture| ConvertString::find_pattern(self, culture, patterns).is_some())
+            .filter_map(|culture| {
+                self.to_number_culture::<N>(culture)
+                    .ok()
+                    .map(|value| (value, culture))
+            })
+            .collect::<Vec<(N, Culture)>>();
+        debug!("Culture detection for {} : {} culture(s) found", self, matches.len());
 
-    use crate::{
-        errors::ConversionError,
-        string_to_number::{NumberConversion, StringNumber},
-        pattern::{NumberCultureSettings, ThousandGrouping}, Separator,
-    };
+        if matches.iter().any(|(value, _)| value != &matches[0].0) {
+            return Err(ConversionError::AmbiguousInput {
+                alternatives: matches
+                    .iter()
+                    .map(|(value, culture)| (*culture, value.to_string()))
+                    .collect(),
+            });
+        }
 
-    fn dot_comma() -> NumberCultureSettings {
-        NumberCultureSettings::from((".", ","))
-    }
-    fn comma_dot() -> NumberCultureSettings {
-        NumberCultureSettings::from((",", "."))
-    }
-    fn comma_dot_grouping_two() -> NumberCultureSettings {
-        NumberCultureSettings::from((",", ".")).with_grouping(ThousandGrouping::TwoBlock)
-    }
-    fn space_comma() -> NumberCultureSettings {
-        NumberCultureSettings::from((" ", ","))
+        matches
+            .into_iter()
+            .next()
+            .ok_or(ConversionError::UnableToConvertStringToNumber)
     }
 
-    #[test]
-    fn test_number_separator() {
-        // Use enum when it's ok
-        assert_eq!("-5'000.66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT)).unwrap(), -5000.66);
-        // Use enum when it's not necessary, should work
-        assert_eq!("1000.66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::APOSTROPHE, Separator::COMMA)).unwrap(), 1000.66);
+    fn to_number_radix<N>(&self, radix: u32) -> Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        // from_str_radix panics outside of this range
+        if !(2..=36).contains(&radix) {
+            return Err(ConversionError::UnableToConvertStringToNumber);
+        }
 
-        // Use text
-        assert_eq!("-5{000.66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('{'), Separator::DOT)).unwrap(), -5000.66);
-        
-        // https://fr.piliapp.com/emoji/list/
-        // Should work
-        assert_eq!("-5🍓000🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000.66);
-        assert_eq!("-5🦀🦀🦀🦀🦀000🍓66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🦀'), Separator::CUSTOM('🍓'))).unwrap(), -5000.66);
-        
-        
-        assert_eq!("-5🍓000🍓000🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000000.66);
-        
-        // It works but it's close to be an invalid separator
-        assert_eq!("-5🍓🍓🍓🍓🍓🍓000🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000.66);
-        assert_eq!("-5🍓🍓000🍓🍓000🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000000.66);
-    }
+        let (sign, value) = match self.trim().strip_prefix('-') {
+            Some(value) => ("-", value),
+            None => ("", self.trim().trim_start_matches('+')),
+        };
 
-    #[test]
-    #[should_panic]
-    fn test_number_separator_same_separator() {
-        assert_eq!("-5|000|66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('|'), Separator::CUSTOM('|'))).unwrap(), -5000.66);
+        // Only strip the prefix of the given radix ("0b1" is a valid hexadecimal number)
+        let prefix = match radix {
+            2 => Some(["0b", "0B"]),
+            8 => Some(["0o", "0O"]),
+            16 => Some(["0x", "0X"]),
+            _ => None,
+        };
+        let value = prefix
+            .and_then(|prefix| prefix.iter().find_map(|p| value.strip_prefix(p)))
+            .unwrap_or(value);
+
+        N::from_str_radix(format!("{}{}", sign, value).as_str(), radix)
+            .map_err(|_e| ConversionError::UnableToConvertStringToNumber)
     }
 
-    #[test]
-    #[should_panic]
-    fn test_number_separator_multiple_decimal() {
-        assert_eq!("-5🍓000🦀🦀🦀🦀🦀🦀🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000.66);
+    fn to_number_with_unit<N>(&self, culture: Culture) -> Result<(N, Option<String>), ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        let regex = RegexPattern::new(&TypeParsing::NumberWithUnit, Some(culture.into()))?.get_regex();
+        let captures = regex
+            .captures(self.trim())
+            .ok_or(ConversionError::UnableToConvertStringToNumber)?;
+        trace!("Number with unit : {} / {:?}", self, captures);
+
+        // The number group is not optional, it's always captured when the regex match
+        let number = captures
+            .name("number")
+            .ok_or(ConversionError::UnableToConvertStringToNumber)?
+            .as_str()
+            .to_number_culture::<N>(culture)?;
+
+        Ok((number, captures.name("unit").map(|unit| unit.as_str().to_owned())))
+    }
+
+    fn to_number_with_full_context<N>(&self, culture: Culture) -> Result<NumberWithContext<N>, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        // The scale letter is alone, or separated from the unit by a whitespace ("1.5K USD", but "50 kg" has no scale).
+        // The unit is made of the same characters as the NumberWithUnit one. The regex is compiled only once (the pattern is valid)
+        static SCALED_REGEX: OnceLock<Regex> = OnceLock::new();
+        let scaled = SCALED_REGEX.get_or_init(|| {
+            Regex::new(r"^(?P<number>[+\-\x{2212}]?[^\p{L}\p{S}%‰]+?)\s*(?P<scale>[kKMBGT])(?:\s+(?P<unit>[\p{L}\p{S}%‰/²³]+))?$").unwrap()
+        });
+        let trimmed = self.trim();
+
+        let (number, scale, unit) = match scaled.captures(trimmed) {
+            Some(captures) => (
+                captures["number"].to_owned(),
+                captures["scale"].chars().next().and_then(ScaleSuffix::from_char),
+                captures.name("unit").map(|unit| unit.as_str().to_owned()),
+            ),
+            None => {
+                let regex = RegexPattern::new(&TypeParsing::NumberWithUnit, Some(culture.into()))?.get_regex();
+                let captures = regex.captures(trimmed).ok_or(ConversionError::UnableToConvertStringToNumber)?;
+                (
+                    captures["number"].to_owned(),
+                    None,
+                    captures.name("unit").map(|unit| unit.as_str().to_owned()),
+                )
+            }
+        };
+
+        let parts = number.as_str().parts_culture(culture)?;
+        let value = match scale {
+            // Move the decimal separator in the digits, there is no float error
+            Some(scale) => {
+                let decimal = format!("{:0<width$}", parts.decimal.unwrap_or_default(), width = scale.exponent());
+                let sign = if parts.sign == Sign::Negative { "-" } else { "" };
+                let (shifted, decimal) = decimal.split_at(scale.exponent());
+                let canonical = match decimal.is_empty() {
+                    true => format!("{}{}{}", sign, parts.whole, shifted),
+                    false => format!("{}{}{}.{}", sign, parts.whole, shifted, decimal),
+                };
+                canonical.as_str().to_number::<N>()?
+            }
+            None => number.as_str().to_number_culture::<N>(culture)?,
+        };
+
+        Ok(NumberWithContext { value, unit, scale, sign: parts.sign })
+    }
+
+    fn to_number_hexfloat<N>(&self) -> Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        let value = parse_hexfloat(self)?;
+        let number = value
+            .to_string()
+            .parse::<N>()
+            .map_err(|_e| ConversionError::UnableToConvertStringToNumber)?;
+
+        // The range of N is checked like the decimal strings (f64 fits, but not f32), on the hexadecimal digits before the exponent
+        let mantissa = self.trim().split(['p', 'P']).next().unwrap_or_default();
+        StringNumber::check_float_range(number, mantissa, 16, ParseOption::default())
+    }
+
+    fn to_integer_strict<N>(&self, culture: Culture) -> Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        let convert = ConvertString::new(self, Some(culture));
+        match convert.get_current_pattern() {
+            Some(pattern) if pattern.get_number_type() == &NumberType::WHOLE => convert.to_number(),
+            Some(_) => Err(ConversionError::ExpectedInteger),
+            None => Err(ConversionError::UnableToConvertStringToNumber),
+        }
+    }
+
+    fn to_number_strict<N>(&self, culture: Culture) -> Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        let convert = ConvertString::new(self, Some(culture));
+        let pattern = match convert.get_current_pattern() {
+            Some(pattern) => pattern,
+            None => {
+                // Only digits, signs and separators : the grouping is the reason of the mismatch
+                let settings: NumberCultureSettings = culture.into();
+                let is_separator = |c: char| {
+                    [settings.thousand_separator(), settings.decimal_separator()]
+                        .iter()
+                        .any(|separator| separator.to_char() == c || (separator.is_whitespace() && c.is_whitespace()))
+                };
+                if self.chars().all(|c| c.is_numeric() || ['+', '-', MINUS_SIGN].contains(&c) || is_separator(c)) {
+                    StringNumber::new_with_settings(String::from(*self), settings).validate_grouping()?;
+                }
+
+                return Err(ConversionError::NoExactPatternMatch);
+            }
+        };
+
+        // Only the digits of the whole part are counted, the decimal part is never grouped
+        let type_parsing = pattern.get_regex().get_type_parsing();
+        let whole_digits = convert.split_parts()?.whole.chars().filter(|c| c.is_ascii_digit()).count();
+        if [TypeParsing::WholeSimple, TypeParsing::DecimalSimple].contains(type_parsing) && whole_digits > 3 {
+            debug!("{} should be written with the thousand separator in {:?}", self, culture);
+            return Err(ConversionError::NoExactPatternMatch);
+        }
+
+        convert.to_number()
+    }
+
+    fn parts_culture(&self, culture: Culture) -> Result<NumberParts, ConversionError> {
+        ConvertString::new(self, Some(culture)).split_parts()
+    }
+
+    fn to_number_with_precision_check<N>(&self, culture: Culture) -> Result<(N, bool), ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        let value = self.to_number_culture::<N>(culture)?;
+        let reference = self.to_number_culture::<f64>(culture)?;
+
+        // The display of N is the shortest string read back as the same N, so it's the value kept by N
+        let kept = value
+            .to_string()
+            .parse::<f64>()
+            .map_err(|_e| ConversionError::UnableToConvertStringToNumber)?;
+        let precision_lost = (kept - reference).abs() > f64::EPSILON * reference.abs().max(1.0);
+        trace!("Precision check of {} : {} / {}", self, kept, reference);
+
+        Ok((value, precision_lost))
+    }
+
+    fn to_number_clamped<N>(&self) -> Result<(N, bool), ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+        N: Bounded,
+    {
+        let error = match self.to_number::<N>() {
+            Ok(number) => return Ok((number, false)),
+            Err(error) => error,
+        };
+
+        // Only a valid number can be clamped
+        let value = self.to_number::<f64>().map_err(|_e| error.clone())?;
+
+        // The whole part is compared to the bounds, a decimal part which doesn't fit an integer type is still an error
+        let whole = ConvertString::new(self, None)
+            .split_parts()
+            .map(|parts| match parts.sign {
+                Sign::Negative => format!("-{}", parts.whole),
+                Sign::Positive => parts.whole,
+            })
+            .map_err(|_e| error.clone())?;
+        if ConvertString::new(&whole, None).fits_in::<N>() {
+            return Err(error);
+        }
+
+        debug!("{} doesn't fit in the type and has been clamped", self);
+        Ok((if value < 0.0 { N::min_value() } else { N::max_value() }, true))
+    }
+
+    fn to_number_parse_chain(&self) -> ParseChain<'_> {
+        ParseChain::new(self)
+    }
+}
+
+/// Parse a C99 hexadecimal float : sign, "0x" prefix, hexadecimal mantissa with optional fraction, and binary exponent
+/// Ex : "0x1.8p3" = 1.5 * 2^3 = 12.0
+/// Ref 'test_number_hexfloat'
+fn parse_hexfloat(value: &str) -> Result<f64, ConversionError> {
+    let regex = Regex::new(r"^([\-\+]?)0[xX]([0-9a-fA-F]*)(?:\.([0-9a-fA-F]*))?[pP]([\-\+]?[0-9]+)$")
+        .map_err(|_e| ConversionError::RegexBuilder)?;
+    let captures = regex
+        .captures(value.trim())
+        .ok_or(ConversionError::UnableToConvertStringToNumber)?;
+
+    let whole = captures.get(2).map_or("", |m| m.as_str());
+    let fraction = captures.get(3).map_or("", |m| m.as_str());
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(ConversionError::UnableToConvertStringToNumber);
+    }
+
+    let exponent = captures[4]
+        .parse::<i32>()
+        .map_err(|_e| ConversionError::UnableToConvertStringToNumber)?;
+
+    // The regex only captures hexadecimal digits, to_digit can't fail
+    let mantissa = whole
+        .chars()
+        .chain(fraction.chars())
+        .fold(0f64, |mantissa, c| mantissa * 16.0 + c.to_digit(16).unwrap_or_default() as f64);
+    let number = mantissa * 2f64.powi(exponent.saturating_sub(4 * fraction.len() as i32));
+
+    if number.is_infinite() {
+        debug!("{} is too big and has been converted to infinity", value);
+        return Err(ConversionError::Overflow);
+    }
+
+    Ok(if &captures[1] == "-" { -number } else { number })
+}
+
+impl NumberConversion for StringNumber {
+    fn to_number<N: num::Num + Display + FromStr>(&self) -> Result<N, ConversionError> {
+        self.parse(ParseOption::default())
+    }
+
+    fn to_number_separators<N>(
+        &self,
+        _pattern: NumberCultureSettings,
+    ) -> std::result::Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.to_number()
+    }
+
+    fn to_number_culture<N>(&self, _: Culture) -> std::result::Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.to_number()
+    }
+
+    fn to_number_validated<N>(&self, culture: Culture) -> std::result::Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.to_number_culture_options(culture, ParseOption::new().with_validate_grouping(true))
+    }
+
+    fn to_number_culture_options<N>(
+        &self,
+        _: Culture,
+        options: ParseOption,
+    ) -> std::result::Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.check_options(options)?;
+        self.parse(options)
+    }
+
+    fn to_number_detect_culture<N>(&self) -> std::result::Result<(N, Culture), ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.value.as_str().to_number_detect_culture()
+    }
+
+    fn to_number_radix<N>(&self, radix: u32) -> std::result::Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.value.as_str().to_number_radix(radix)
+    }
+
+    fn to_number_with_unit<N>(&self, culture: Culture) -> std::result::Result<(N, Option<String>), ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.value.as_str().to_number_with_unit(culture)
+    }
+
+    fn to_number_with_full_context<N>(&self, culture: Culture) -> std::result::Result<NumberWithContext<N>, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.value.as_str().to_number_with_full_context(culture)
+    }
+
+    fn to_number_hexfloat<N>(&self) -> std::result::Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.value.as_str().to_number_hexfloat()
+    }
+
+    fn to_integer_strict<N>(&self, culture: Culture) -> Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.value.as_str().to_integer_strict(culture)
+    }
+
+    fn to_number_strict<N>(&self, culture: Culture) -> Result<N, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.value.as_str().to_number_strict(culture)
+    }
+
+    fn parts_culture(&self, culture: Culture) -> Result<NumberParts, ConversionError> {
+        self.value.as_str().parts_culture(culture)
+    }
+
+    fn to_number_with_precision_check<N>(&self, culture: Culture) -> Result<(N, bool), ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        self.value.as_str().to_number_with_precision_check(culture)
+    }
+
+    fn to_number_clamped<N>(&self) -> Result<(N, bool), ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+        N: Bounded,
+    {
+        self.value.as_str().to_number_clamped()
+    }
+
+    fn to_number_parse_chain(&self) -> ParseChain<'_> {
+        ParseChain::new(self.value.as_str())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use num::rational::Ratio;
+    use regex::escape;
+
+    use crate::{
+        Culture,
+        errors::ConversionError,
+        string_to_number::{detect_conflicts, sanitize, try_parse, NumberConversion, NumberRange, NumberWithContext, ParseChain, ParseOption, ScaleSuffix, StringNumber},
+        pattern::{NumberCultureSettings, NumberParts, Sign, ThousandGrouping}, Separator,
+    };
+
+    fn dot_comma() -> NumberCultureSettings {
+        NumberCultureSettings::from((".", ","))
+    }
+    fn comma_dot() -> NumberCultureSettings {
+        NumberCultureSettings::from((",", "."))
+    }
+    fn comma_dot_grouping_two() -> NumberCultureSettings {
+        NumberCultureSettings::from((",", ".")).with_grouping(ThousandGrouping::TwoBlock)
+    }
+    fn space_comma() -> NumberCultureSettings {
+        NumberCultureSettings::from((" ", ","))
+    }
+
+    #[test]
+    fn test_number_separator() {
+        // Use enum when it's ok
+        assert_eq!("-5'000.66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT)).unwrap(), -5000.66);
+        // Use enum when it's not necessary, should work
+        assert_eq!("1000.66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::APOSTROPHE, Separator::COMMA)).unwrap(), 1000.66);
+
+        // Use text
+        assert_eq!("-5{000.66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('{'), Separator::DOT)).unwrap(), -5000.66);
+        
+        // https://fr.piliapp.com/emoji/list/
+        // Should work
+        assert_eq!("-5🍓000🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000.66);
+        assert_eq!("-5🦀🦀🦀🦀🦀000🍓66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🦀'), Separator::CUSTOM('🍓'))).unwrap(), -5000.66);
+        
+        
+        assert_eq!("-5🍓000🍓000🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000000.66);
+        
+        // It works but it's close to be an invalid separator
+        assert_eq!("-5🍓🍓🍓🍓🍓🍓000🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000.66);
+        assert_eq!("-5🍓🍓000🍓🍓000🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000000.66);
+    }
+
+    #[test]
+    fn test_number_separator_space_decimal() {
+        let dot_space = NumberCultureSettings::new(Separator::DOT, Separator::SPACE);
+        let values = vec![
+            ("1.000 8888", 1000.8888),
+            ("-1.000.000 5", -1000000.5),
+            ("1000 5", 1000.5),
+            ("1.000", 1000.0),
+            (" 1.000 8888 ", 1000.8888),
+            ("\t1.000 25\n", 1000.25),
+            ("1.000\u{00A0}8888", 1000.8888),
+        ];
+
+        for (string_num, number) in values {
+            assert_eq!(string_num.to_number_separators::<f64>(dot_space).unwrap(), number, "{:?}", string_num);
+        }
+
+        let errors = vec!["1.000  8888", "1 000 5", "1.000 88 88"];
+        for string_num in errors {
+            assert_eq!(
+                string_num.to_number_separators::<f64>(dot_space),
+                Err(ConversionError::UnableToConvertStringToNumber),
+                "{:?}",
+                string_num
+            );
+        }
+
+        // The cleaning doesn't delete the decimal space
+        assert_eq!(StringNumber::new_with_settings("1.000 8888".to_owned(), dot_space).clean(), "1000.8888");
+        // Without settings, the spaces are still removed
+        assert_eq!(StringNumber::new("1 000".to_owned()).clean(), "1000");
+        assert_eq!("1 000".to_number::<i32>().unwrap(), 1000);
+    }
+
+    #[test]
+    fn test_number_separator_typographic_apostrophe() {
+        let swiss = NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT);
+
+        // U+0027 (ASCII), U+2019 (right single quotation mark) and U+02BC (modifier letter apostrophe)
+        assert_eq!("1'234'567.89".to_number_separators::<f64>(swiss).unwrap(), 1234567.89);
+        assert_eq!("1\u{2019}234\u{2019}567.89".to_number_separators::<f64>(swiss).unwrap(), 1234567.89);
+        assert_eq!("-1\u{02BC}234".to_number_separators::<i32>(swiss).unwrap(), -1234);
+        // Only for the apostrophe separator
+        assert!("1\u{2019}234\u{2019}567".to_number_culture::<i32>(Culture::English).is_err());
+
+        // The display keeps the ASCII apostrophe
+        assert_eq!(crate::ToFormat::to_format_separators(1234567.89, "N2", swiss).unwrap(), "1'234'567.89");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_number_separator_same_separator() {
+        assert_eq!("-5|000|66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('|'), Separator::CUSTOM('|'))).unwrap(), -5000.66);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_number_separator_multiple_decimal() {
+        assert_eq!("-5🍓000🦀🦀🦀🦀🦀🦀🦀66".to_number_separators::<f32>(NumberCultureSettings::new(Separator::CUSTOM('🍓'), Separator::CUSTOM('🦀'))).unwrap(), -5000.66);
     }
     /// Simple integer conversion
     #[test]
@@ -282,154 +1401,984 @@ mod tests {
             ("1 000", 1000, 1000.0),
         ];
 
-        for (string_value, int_value, float_value) in list {
-            assert_eq!(string_value.to_number::<i32>().unwrap(), int_value);
-            assert_eq!(string_value.to_number::<f64>().unwrap(), float_value);
-        }
+        for (string_value, int_value, float_value) in list {
+            assert_eq!(string_value.to_number::<i32>().unwrap(), int_value);
+            assert_eq!(string_value.to_number::<f64>().unwrap(), float_value);
+        }
+    }
+
+    /// Simple decimal conversion
+    #[test]
+    fn number_conversion_decimal() {
+        let list = vec![
+            ("10,0", 10.0),
+            ("0,25", 0.25),
+            ("-10,5", -10.5),
+            ("1000,89", 1000.89),
+            ("1 000,4564654654654", 1000.4564654654654),
+            ("1000,4564654654654", 1000.4564654654654),
+        ];
+
+        for (string_value, float_value) in list {
+            assert_eq!(
+                string_value
+                    .to_number_separators::<f64>(NumberCultureSettings::from((" ", ",")))
+                    .unwrap(),
+                float_value
+            );
+        }
+    }
+
+    /// Conversion with several thousand and decimal separator
+    #[test]
+    fn number_conversion_others() {
+        assert_eq!(
+            "10.000.000"
+                .to_number_separators::<i32>(dot_comma())
+                .unwrap(),
+            10_000_000
+        );
+
+        assert_eq!(
+            "10,000,000"
+                .to_number_separators::<i32>(comma_dot())
+                .unwrap(),
+            10_000_000
+        );
+
+        assert_eq!(
+            "10,00,00,00,000"
+                .to_number_separators::<i64>(comma_dot_grouping_two())
+                .unwrap(),
+            10_000_000_000
+        );
+
+        assert_eq!(
+            "1.000,45"
+                .to_number_separators::<f64>(dot_comma())
+                .unwrap(),
+            1_000.45
+        );
+
+        assert_eq!(
+            "1.000"
+                .to_number_separators::<i32>(dot_comma())
+                .unwrap(),
+            1_000
+        );
+    }
+
+    /// Conversion with i8 primitive
+    #[test]
+    fn number_conversion_primitive_dependent_i8() {
+        /* Reminder :
+        * i8 : [-128: 128]
+        */
+
+        let i8_ok = "120";
+        assert_eq!(
+            i8_ok
+                .to_number::<i8>()
+                .unwrap(),
+            120
+        );
+        assert_eq!(
+            i8_ok
+                .to_number::<i64>()
+                .unwrap(),
+            120
+        );
+        assert_eq!(
+            i8_ok
+                .to_number::<u8>()
+                .unwrap(),
+            120
+        );
+    }
+
+    /// Conversion with i16 primitive
+    #[test]
+    fn number_conversion_primitive_dependent_i16() {
+        /* Reminder :
+        * i16 : [-32768: 32768]
+        */
+        let i16_ok = "-10000";
+        assert_eq!(
+            i16_ok
+                .to_number::<i16>()
+                .unwrap(),
+            -10_000
+        );
+
+        assert_eq!(
+            i16_ok.to_number::<i8>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_error_conversion() {
+        assert_eq!(
+            "10,000,000"
+                .to_number_separators::<i32>(space_comma()),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+
+        assert_eq!(
+            "10,00,00,00"
+                .to_number_separators::<i32>(space_comma()),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "10,00,00,00"
+                .to_number::<i32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+    #[test]
+    fn number_conversion_not_allowed() {
+        let list = vec!["x", "10*5", "2..500"];
+
+        for string_value in list {
+            let wn = StringNumber::new(String::from(string_value));
+
+            assert_eq!(
+                wn.to_number::<i32>(),
+                Err(ConversionError::UnableToConvertStringToNumber)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_grouping() {
+        // ThreeBlock
+        assert_eq!("1,000".to_number_validated::<i32>(Culture::English), Ok(1000));
+        assert_eq!("-2,500,563".to_number_validated::<i32>(Culture::English), Ok(-2500563));
+        assert_eq!("1,000.5".to_number_validated::<f64>(Culture::English), Ok(1000.5));
+        assert_eq!("1000".to_number_validated::<i32>(Culture::English), Ok(1000));
+        assert_eq!("10 000,25".to_number_validated::<f64>(Culture::French), Ok(10000.25));
+        assert_eq!(
+            "1,0000".to_number_validated::<i32>(Culture::English),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 4 })
+        );
+        assert_eq!(
+            "1,00".to_number_validated::<i32>(Culture::English),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            "1000,000".to_number_validated::<i32>(Culture::English),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 4 })
+        );
+
+        // TwoBlock
+        assert_eq!("1,00,00,000".to_number_validated::<i32>(Culture::Indian), Ok(10000000));
+        assert_eq!("10,00,000.50".to_number_validated::<f64>(Culture::Indian), Ok(1000000.5));
+        assert_eq!("1,000".to_number_validated::<i32>(Culture::Indian), Ok(1000));
+        assert_eq!(
+            "1,000,000".to_number_validated::<i32>(Culture::Indian),
+            Err(ConversionError::InvalidGrouping { expected: 2, found: 3 })
+        );
+        assert_eq!(
+            "1,00,00".to_number_validated::<i32>(Culture::Indian),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 2 })
+        );
+
+        // Bad grouping is still accepted by the lenient conversion
+        assert_eq!(StringNumber::new_with_settings(String::from("1,0000"), comma_dot()).to_number::<i32>(), Ok(10000));
+    }
+
+    #[test]
+    fn test_parse_option() {
+        let require = ParseOption::new().with_require_grouping(true);
+
+        // No option is the lenient conversion
+        assert_eq!("1234567".to_number_culture_options::<i32>(Culture::French, ParseOption::new()), Ok(1234567));
+        assert_eq!("1,0000".to_number_culture_options::<i32>(Culture::English, ParseOption::new()), Ok(10000));
+
+        assert_eq!("1 234 567".to_number_culture_options::<i32>(Culture::French, require), Ok(1234567));
+        assert_eq!("123".to_number_culture_options::<i32>(Culture::French, require), Ok(123));
+        assert_eq!("-123,45".to_number_culture_options::<f64>(Culture::French, require), Ok(-123.45));
+        assert_eq!("12,34,567".to_number_culture_options::<i32>(Culture::Indian, require), Ok(1234567));
+        assert_eq!(
+            "1234567".to_number_culture_options::<i32>(Culture::French, require),
+            Err(ConversionError::MissingGrouping)
+        );
+        assert_eq!(
+            "1234,5".to_number_culture_options::<f64>(Culture::French, require),
+            Err(ConversionError::MissingGrouping)
+        );
+        assert_eq!(
+            "1234567".to_number_culture_options::<i32>(Culture::Indian, require),
+            Err(ConversionError::MissingGrouping)
+        );
+
+        // require_grouping implies validate_grouping
+        assert_eq!(
+            "1 2345 67".to_number_culture_options::<i32>(Culture::French, require),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            "1,0000".to_number_culture_options::<i32>(Culture::English, ParseOption::new().with_validate_grouping(true)),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 4 })
+        );
+
+        // minimum_grouping_digits moves the threshold
+        let require_two = require.with_minimum_grouping_digits(2);
+        assert_eq!("1234".to_number_culture_options::<i32>(Culture::English, require), Err(ConversionError::MissingGrouping));
+        assert_eq!("1234".to_number_culture_options::<i32>(Culture::English, require_two), Ok(1234));
+        assert_eq!("1,234".to_number_culture_options::<i32>(Culture::English, require_two), Ok(1234));
+        assert_eq!("12345".to_number_culture_options::<i32>(Culture::English, require_two), Err(ConversionError::MissingGrouping));
     }
 
-    /// Simple decimal conversion
     #[test]
-    fn number_conversion_decimal() {
+    fn test_sanitize() {
+        assert_eq!(sanitize("\u{FEFF}1\u{200B}000\u{200E}"), "1000");
+        assert_eq!(sanitize("\u{2067}-1 000,5\u{2069}"), "-1 000,5");
+        assert_eq!(sanitize("1 000"), "1 000");
+
+        let with_sanitize = ParseOption::new().with_sanitize(true);
         let list = vec![
-            ("10,0", 10.0),
-            ("0,25", 0.25),
-            ("-10,5", -10.5),
-            ("1000,89", 1000.89),
-            ("1 000,4564654654654", 1000.4564654654654),
-            ("1000,4564654654654", 1000.4564654654654),
+            ("\u{FEFF}1,000.5", Culture::English, 1000.5),
+            ("1\u{200B},\u{200B}000.5", Culture::English, 1000.5),
+            ("\u{200F}-2,500\u{200F}", Culture::English, -2500.0),
+            ("\u{202B}1 000,25\u{202C}", Culture::French, 1000.25),
+            ("1\u{2060} 000\u{200D},5", Culture::French, 1000.5),
+            ("\u{2066}-10\u{200C},5\u{2069}", Culture::French, -10.5),
         ];
 
-        for (string_value, float_value) in list {
+        for (string_value, culture, float_value) in list {
+            assert_eq!(string_value.to_number_culture_options::<f64>(culture, with_sanitize), Ok(float_value));
+            // The sanitization is opt-in
             assert_eq!(
-                string_value
-                    .to_number_separators::<f64>(NumberCultureSettings::from((" ", ",")))
-                    .unwrap(),
-                float_value
+                string_value.to_number_culture_options::<f64>(culture, ParseOption::new()),
+                Err(ConversionError::UnableToConvertStringToNumber)
             );
         }
     }
 
-    /// Conversion with several thousand and decimal separator
     #[test]
-    fn number_conversion_others() {
+    fn test_detect_culture() {
+        assert_eq!("1,000.5".to_number_detect_culture::<f64>(), Ok((1000.5, Culture::English)));
+        assert_eq!("1.000,5".to_number_detect_culture::<f64>(), Ok((1000.5, Culture::Italian)));
+        assert_eq!("-1 000,5".to_number_detect_culture::<f64>(), Ok((-1000.5, Culture::French)));
+        assert_eq!("1,00,000".to_number_detect_culture::<i32>(), Ok((100000, Culture::Indian)));
+
+        // Same value for all cultures, the first one is returned
+        assert_eq!("1000".to_number_detect_culture::<i32>(), Ok((1000, Culture::English)));
+
+        // 1000 in English, 1.0 in French
         assert_eq!(
-            "10.000.000"
-                .to_number_separators::<i32>(dot_comma())
-                .unwrap(),
-            10_000_000
+            "1,000".to_number_detect_culture::<f64>(),
+            Err(ConversionError::AmbiguousInput {
+                alternatives: vec![
+                    (Culture::English, "1000".to_owned()),
+                    (Culture::French, "1".to_owned()),
+                    (Culture::Italian, "1".to_owned()),
+                    (Culture::Indian, "1000".to_owned()),
+                    (Culture::Turkish, "1".to_owned()),
+                ]
+            })
         );
+        // Only the cultures which can convert to the type are kept
+        assert_eq!("1,000".to_number_detect_culture::<i32>(), Ok((1000, Culture::English)));
 
         assert_eq!(
-            "10,000,000"
-                .to_number_separators::<i32>(comma_dot())
-                .unwrap(),
-            10_000_000
+            "NotANumber".to_number_detect_culture::<f64>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
         );
+    }
+
+    #[test]
+    fn test_float_overflow() {
+        // f32::MAX is ~3.4e38, f64::MAX is ~1.8e308
+        let e39 = format!("1{}", "0".repeat(39));
+        let e309 = format!("1{}", "0".repeat(309));
+        assert_eq!(e39.as_str().to_number::<f32>(), Err(ConversionError::Overflow));
+        assert_eq!(e39.as_str().to_number::<f64>(), Ok(1e39));
+        assert_eq!(e309.as_str().to_number::<f64>(), Err(ConversionError::Overflow));
+        assert_eq!(format!("-{}", e309).as_str().to_number::<f64>(), Err(ConversionError::Overflow));
+        assert_eq!("1e400".to_number::<f64>(), Err(ConversionError::Overflow));
+        assert_eq!("1e39".to_number::<f32>(), Err(ConversionError::Overflow));
+        assert_eq!("-1e39".to_number::<f32>(), Err(ConversionError::Overflow));
+        assert_eq!("3.4e38".to_number::<f32>(), Ok(3.4e38));
 
+        // Opt-in infinity
+        let allow_infinity = ParseOption::new().with_allow_infinity(true);
+        assert_eq!(e39.as_str().to_number_culture_options::<f32>(Culture::English, allow_infinity), Ok(f32::INFINITY));
         assert_eq!(
-            "10,00,00,00,000"
-                .to_number_separators::<i64>(comma_dot_grouping_two())
-                .unwrap(),
-            10_000_000_000
+            format!("-{}", e309).as_str().to_number_culture_options::<f64>(Culture::French, allow_infinity),
+            Ok(f64::NEG_INFINITY)
         );
+        assert_eq!(e309.as_str().to_number_culture_options::<f64>(Culture::French, ParseOption::new()), Err(ConversionError::Overflow));
+
+        // Integer overflow is still a conversion error
+        assert_eq!(e39.as_str().to_number::<i64>(), Err(ConversionError::UnableToConvertStringToNumber));
+    }
 
+    #[test]
+    fn test_to_integer_strict() {
+        assert_eq!("1,000".to_integer_strict::<i32>(Culture::English), Ok(1000));
+        assert_eq!("-1 000 000".to_integer_strict::<i64>(Culture::French), Ok(-1_000_000));
+        assert_eq!("1,00,000".to_integer_strict::<u32>(Culture::Indian), Ok(100_000));
+        assert_eq!("42".to_integer_strict::<u8>(Culture::Italian), Ok(42));
+        assert_eq!("1,000.5".to_integer_strict::<i32>(Culture::English), Err(ConversionError::ExpectedInteger));
+        assert_eq!("1.0".to_integer_strict::<f64>(Culture::English), Err(ConversionError::ExpectedInteger));
+        assert_eq!("10,2".to_integer_strict::<i32>(Culture::French), Err(ConversionError::ExpectedInteger));
+        assert_eq!(",5".to_integer_strict::<i32>(Culture::French), Err(ConversionError::ExpectedInteger));
+        assert_eq!("abc".to_integer_strict::<i32>(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("300".to_integer_strict::<u8>(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
         assert_eq!(
-            "1.000,45"
-                .to_number_separators::<f64>(dot_comma())
-                .unwrap(),
-            1_000.45
+            StringNumber::new(String::from("1.000")).to_integer_strict::<i32>(Culture::Italian),
+            Ok(1000)
         );
+    }
+
+    #[test]
+    fn test_parts_culture() {
+        let parts = |sign, whole: &str, decimal: Option<&str>| NumberParts {
+            sign,
+            whole: whole.to_owned(),
+            decimal: decimal.map(|d| d.to_owned()),
+        };
 
+        assert_eq!("1 234,56".parts_culture(Culture::French), Ok(parts(Sign::Positive, "1234", Some("56"))));
+        assert_eq!("-1 234,56".parts_culture(Culture::French), Ok(parts(Sign::Negative, "1234", Some("56"))));
+        assert_eq!("1,234.56".parts_culture(Culture::English), Ok(parts(Sign::Positive, "1234", Some("56"))));
+        assert_eq!("+1.000".parts_culture(Culture::Italian), Ok(parts(Sign::Positive, "1000", None)));
+        assert_eq!("-1.000,05".parts_culture(Culture::Turkish), Ok(parts(Sign::Negative, "1000", Some("05"))));
+        assert_eq!("-12,34,567.8".parts_culture(Culture::Indian), Ok(parts(Sign::Negative, "1234567", Some("8"))));
+        assert_eq!("-,5".parts_culture(Culture::French), Ok(parts(Sign::Negative, "0", Some("5"))));
+        assert_eq!("007".parts_culture(Culture::English), Ok(parts(Sign::Positive, "007", None)));
+        assert_eq!("1,234.56".parts_culture(Culture::French), Err(ConversionError::UnableToConvertStringToNumber));
         assert_eq!(
-            "1.000"
-                .to_number_separators::<i32>(dot_comma())
-                .unwrap(),
-            1_000
+            StringNumber::new(String::from("10,5")).parts_culture(Culture::French),
+            Ok(parts(Sign::Positive, "10", Some("5")))
         );
     }
 
-    /// Conversion with i8 primitive
     #[test]
-    fn number_conversion_primitive_dependent_i8() {
-        /* Reminder :
-        * i8 : [-128: 128]
-        */
+    fn test_to_number_or() {
+        assert_eq!("1 000,5".to_number_culture_or(0.0, Culture::French), 1000.5);
+        assert_eq!("abc".to_number_culture_or(0.0, Culture::French), 0.0);
+        assert_eq!("1,000".to_number_culture_or::<i32>(-1, Culture::English), 1000);
+        assert_eq!("1,000.5".to_number_culture_or::<i32>(-1, Culture::English), -1);
+        assert_eq!("300".to_number_culture_or::<u8>(u8::MAX, Culture::English), u8::MAX);
+        assert_eq!("1000.5".to_number_or(0.0), 1000.5);
+        assert_eq!("1 000,5".to_number_or(-1.0), -1.0);
+        assert_eq!("1'000.5".to_number_separators_or(0.0, NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT)), 1000.5);
+        assert_eq!("1'000.5".to_number_separators_or(0.0, comma_dot()), 0.0);
 
-        let i8_ok = "120";
+        // The error is given to compute the value
+        let or_else = |s: &str| s.to_number_culture_or_else(|_| if s.is_empty() { 0.0 } else { f64::NAN }, Culture::English);
+        assert_eq!(or_else("1,000.25"), 1000.25);
+        assert_eq!(or_else(""), 0.0);
+        assert!(or_else("garbage").is_nan());
         assert_eq!(
-            i8_ok
-                .to_number::<i8>()
-                .unwrap(),
-            120
+            "1.000,5".to_number_culture_or_else(|e| if e == ConversionError::UnableToConvertStringToNumber { -1 } else { -2 }, Culture::English),
+            -1
         );
+        assert_eq!("12".to_number_or_else::<i32, _>(|_| -1), 12);
+        assert_eq!("1,5".to_number_or_else::<i32, _>(|_| -1), -1);
+        assert_eq!("1.000,5".to_number_separators_or_else(|_| f64::NAN, NumberCultureSettings::new(Separator::DOT, Separator::COMMA)), 1000.5);
+        assert!("abc".to_number_separators_or_else(|_| f64::NAN, comma_dot()).is_nan());
+
+        assert_eq!("42".to_number_ok::<i32>(), Some(42));
+        assert_eq!("-1.5".to_number_ok::<f32>(), Some(-1.5));
+        assert_eq!("abc".to_number_ok::<i32>(), None);
+        assert_eq!("1.5".to_number_ok::<i32>().map(|n| n * 2), None);
+        assert_eq!("21".to_number_ok::<i32>().map(|n| n * 2), Some(42));
+        assert_eq!("1 000".to_number_culture_ok::<i32>(Culture::French), Some(1000));
+        assert_eq!("1,000".to_number_culture_ok::<i32>(Culture::French), None);
+        assert_eq!("1_000".to_number_separators_ok::<i32>(NumberCultureSettings::new(Separator::UNDERSCORE, Separator::DOT)), Some(1000));
+        assert_eq!("1 000 kg".to_number_separators_ok::<f32>(comma_dot()), None);
+
+        // Same results as the wrapped methods for StringNumber
+        for value in ["1000.5", "1 000,5", "abc"] {
+            let string_number = StringNumber::new(String::from(value));
+            assert_eq!(string_number.to_number_or(-1.0), string_number.to_number().unwrap_or(-1.0));
+            assert_eq!(string_number.to_number_culture_or_else(|_| -1.0, Culture::French), string_number.to_number_culture(Culture::French).unwrap_or(-1.0));
+            assert_eq!(string_number.to_number_separators_ok::<f64>(comma_dot()), string_number.to_number_separators(comma_dot()).ok());
+        }
+        assert_eq!(StringNumber::new(String::from("7")).to_number_ok::<u8>(), Some(7));
+    }
+
+    #[test]
+    fn test_underscore_separator() {
+        // Without settings, the underscores between digits are removed
+        assert_eq!("1_000_000".to_number::<i64>(), Ok(1000000));
+        assert_eq!("-1_000".to_number::<i32>(), Ok(-1000));
+        assert_eq!("1_000.5".to_number::<f64>(), Ok(1000.5));
+        assert_eq!("0.000_001".to_number::<f64>(), Ok(0.000001));
+        assert_eq!("1__000".to_number::<i32>(), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("_1000".to_number::<i32>(), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("1000_".to_number::<i32>(), Err(ConversionError::UnableToConvertStringToNumber));
+
+        // As thousand separator
+        let underscore = NumberCultureSettings::new(Separator::UNDERSCORE, Separator::DOT);
+        assert_eq!("1_000_000".to_number_separators::<i64>(underscore), Ok(1000000));
+        assert_eq!("-1_000.5".to_number_separators::<f64>(underscore), Ok(-1000.5));
+        assert_eq!(crate::ToFormat::to_format_separators(1000000.5, "N1", underscore).unwrap(), "1_000_000.5");
+
+        // The '_' char is always UNDERSCORE, and CUSTOM('_') is parsed the same way
+        assert_eq!(Separator::from_char('_'), Separator::UNDERSCORE);
+        assert_eq!(Separator::try_from("_"), Ok(Separator::UNDERSCORE));
+        assert_eq!(NumberCultureSettings::from(("_", ".")), underscore);
+        let custom = NumberCultureSettings::new(Separator::CUSTOM('_'), Separator::DOT);
+        assert_eq!("1_000.5".to_number_separators::<f64>(custom), "1_000.5".to_number_separators::<f64>(underscore));
+        assert_eq!(custom.into_thousand_separator_regex(), underscore.into_thousand_separator_regex());
+    }
+
+    #[test]
+    fn test_to_number_strict() {
+        // Written as the culture writes it
+        assert_eq!("1,000".to_number_strict::<i32>(Culture::English), Ok(1000));
+        assert_eq!("-1,234,567.25".to_number_strict::<f64>(Culture::English), Ok(-1234567.25));
+        assert_eq!("999".to_number_strict::<i32>(Culture::English), Ok(999));
+        assert_eq!("999.5".to_number_strict::<f64>(Culture::English), Ok(999.5));
+        assert_eq!("0.12345".to_number_strict::<f64>(Culture::English), Ok(0.12345));
+        assert_eq!("1 000,5".to_number_strict::<f64>(Culture::French), Ok(1000.5));
+        assert_eq!(",5".to_number_strict::<f64>(Culture::Italian), Ok(0.5));
+        assert_eq!("1,00,000".to_number_strict::<i32>(Culture::Indian), Ok(100000));
+
+        // Inconsistent grouping
+        assert_eq!("10,00".to_number_strict::<i32>(Culture::English), Err(ConversionError::InvalidGrouping { expected: 3, found: 2 }));
+        assert_eq!("1,0000.5".to_number_strict::<f64>(Culture::English), Err(ConversionError::InvalidGrouping { expected: 3, found: 4 }));
+        assert_eq!("100,000".to_number_strict::<i32>(Culture::Indian), Err(ConversionError::InvalidGrouping { expected: 2, found: 3 }));
+
+        // Extra characters, or the separators of another culture
+        assert_eq!("1,000abc".to_number_strict::<i32>(Culture::English), Err(ConversionError::NoExactPatternMatch));
+        assert_eq!("1,000.5".to_number_strict::<f64>(Culture::French), Err(ConversionError::NoExactPatternMatch));
+        assert_eq!("".to_number_strict::<i32>(Culture::English), Err(ConversionError::NoExactPatternMatch));
+
+        // The thousand separator is required, with or without decimal part
+        assert_eq!("1000".to_number_strict::<i32>(Culture::English), Err(ConversionError::NoExactPatternMatch));
+        assert_eq!("-1234567".to_number_strict::<i32>(Culture::French), Err(ConversionError::NoExactPatternMatch));
+        assert_eq!("1234.5".to_number_strict::<f64>(Culture::English), Err(ConversionError::NoExactPatternMatch));
+        assert_eq!("-1000,25".to_number_strict::<f64>(Culture::French), Err(ConversionError::NoExactPatternMatch));
+
+        // Still fails when the number doesn't fit the type
+        assert_eq!("1,000".to_number_strict::<i8>(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(StringNumber::new(String::from("1.000")).to_number_strict::<i32>(Culture::Italian), Ok(1000));
+    }
+
+    #[test]
+    fn test_to_number_option() {
+        assert_eq!("1000".to_number_option::<i32>(), Some(1000));
+        assert_eq!("abc".to_number_option::<i32>(), None);
+        assert_eq!("".to_number_option::<f64>(), None);
+
+        assert_eq!("1 000,5".to_number_culture_option::<f64>(Culture::French), Some(1000.5));
+        assert_eq!("1 000,5".to_number_culture_option::<i32>(Culture::French), None);
+        assert_eq!("1.000".to_number_culture_option::<i32>(Culture::Italian), Some(1000));
+
+        assert_eq!("1'000.5".to_number_separators_option::<f64>(NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT)), Some(1000.5));
+        assert_eq!("1'000.5".to_number_separators_option::<f64>(comma_dot()), None);
+
+        let values = ["1", "", "x", "3"];
+        assert_eq!(values.iter().filter_map(|s| s.to_number_option::<u8>()).sum::<u8>(), 4);
+        assert_eq!(StringNumber::new(String::from("12")).to_number_option::<i8>(), Some(12));
+    }
+
+    #[test]
+    fn test_to_number_ok() {
+        assert_eq!("1000".to_number_ok::<i32>(), Some(1000));
+        assert_eq!("abc".to_number_ok::<i32>(), None);
+        assert_eq!("".to_number_ok::<f64>(), None);
+
+        assert_eq!("1 000,5".to_number_culture_ok::<f64>(Culture::French), Some(1000.5));
+        assert_eq!("1 000,5".to_number_culture_ok::<i32>(Culture::French), None);
+        assert_eq!("1.000".to_number_culture_ok::<i32>(Culture::Italian), Some(1000));
+
+        assert_eq!("1'000.5".to_number_separators_ok::<f64>(NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT)), Some(1000.5));
+        assert_eq!("1'000.5".to_number_separators_ok::<f64>(comma_dot()), None);
+
+        let values = ["1", "", "x", "3"];
+        assert_eq!(values.iter().filter_map(|s| s.to_number_ok::<u8>()).sum::<u8>(), 4);
+        assert_eq!(StringNumber::new(String::from("12")).to_number_ok::<i8>(), Some(12));
+    }
+
+    #[test]
+    fn test_to_number_clamped() {
+        assert_eq!("300".to_number_clamped::<i8>(), Ok((127, true)));
+        assert_eq!("-300".to_number_clamped::<i8>(), Ok((-128, true)));
+        assert_eq!("100".to_number_clamped::<i8>(), Ok((100, false)));
+        assert_eq!("-128".to_number_clamped::<i8>(), Ok((-128, false)));
+        assert_eq!("-1".to_number_clamped::<u32>(), Ok((0, true)));
+        assert_eq!("300.5".to_number_clamped::<u8>(), Ok((255, true)));
+        assert_eq!("9223372036854775808".to_number_clamped::<i64>(), Ok((i64::MAX, true)));
+        assert_eq!("-99999999999999999999999".to_number_clamped::<i128>(), Ok((-99999999999999999999999, false)));
+
+        // Not an overflow
+        assert_eq!("1.5".to_number_clamped::<i8>(), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("abc".to_number_clamped::<i8>(), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("1.5".to_number_clamped::<f32>(), Ok((1.5, false)));
+
+        assert_eq!(StringNumber::new(String::from("1000")).to_number_clamped::<i16>(), Ok((1000, false)));
+        assert_eq!(StringNumber::new(String::from("-40000")).to_number_clamped::<i16>(), Ok((i16::MIN, true)));
+    }
+
+    #[test]
+    fn test_to_number_with_precision_check() {
+        assert_eq!("1.123456789012345".to_number_with_precision_check::<f32>(Culture::English), Ok((1.1234568, true)));
         assert_eq!(
-            i8_ok
-                .to_number::<i64>()
-                .unwrap(),
-            120
+            "1.123456789012345".to_number_with_precision_check::<f64>(Culture::English),
+            Ok((1.123456789012345, false))
         );
+        assert_eq!("1,5".to_number_with_precision_check::<f32>(Culture::French), Ok((1.5, false)));
+        assert_eq!("0,1".to_number_with_precision_check::<f32>(Culture::Italian), Ok((0.1, false)));
+        assert_eq!("1 234,567".to_number_with_precision_check::<f32>(Culture::French), Ok((1234.567, false)));
+        assert_eq!("1 234,5678".to_number_with_precision_check::<f32>(Culture::French), Ok((1234.5677, true)));
+        assert_eq!("16,777,217".to_number_with_precision_check::<f32>(Culture::English), Ok((16777216.0, true)));
+        assert_eq!("-16,777,216".to_number_with_precision_check::<f32>(Culture::English), Ok((-16777216.0, false)));
+        assert_eq!("1,000".to_number_with_precision_check::<i32>(Culture::English), Ok((1000, false)));
         assert_eq!(
-            i8_ok
-                .to_number::<u8>()
-                .unwrap(),
-            120
+            "abc".to_number_with_precision_check::<f32>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
         );
     }
 
-    /// Conversion with i16 primitive
     #[test]
-    fn number_conversion_primitive_dependent_i16() {
-        /* Reminder :
-        * i16 : [-32768: 32768]
-        */
-        let i16_ok = "-10000";
+    fn test_multiple_decimal_separators() {
+        assert_eq!("1.0.0".to_number::<f64>(), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("1.0.0".to_number_culture::<f64>(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("1..0".to_number_culture::<f64>(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("1,0,0".to_number_culture::<f64>(Culture::French), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("1,0,0".to_number_culture::<f64>(Culture::Italian), Err(ConversionError::UnableToConvertStringToNumber));
+
+        // The comma is the thousand separator in English, the grouping is checked by the validation
+        assert_eq!("1,0,0".to_number_culture::<f64>(Culture::English), Ok(100.0));
         assert_eq!(
-            i16_ok
-                .to_number::<i16>()
-                .unwrap(),
-            -10_000
+            "1,0,0".to_number_validated::<f64>(Culture::English),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 1 })
+        );
+
+        // A single decimal separator is still valid
+        assert_eq!("1.5".to_number_culture::<f64>(Culture::English), Ok(1.5));
+        assert_eq!("1,5".to_number_culture::<f64>(Culture::French), Ok(1.5));
+    }
+
+    #[test]
+    fn test_float_underflow() {
+        // The smallest f32 is ~1.4e-45, the smallest f64 is ~4.9e-324
+        let e50 = format!("0.{}1", "0".repeat(49));
+        let e330 = format!("0.{}1", "0".repeat(329));
+        assert_eq!(e50.as_str().to_number::<f32>(), Err(ConversionError::Underflow));
+        assert_eq!(e50.as_str().to_number::<f64>(), Ok(1e-50));
+        assert_eq!(e330.as_str().to_number::<f64>(), Err(ConversionError::Underflow));
+        assert_eq!(format!("-{}", e330).as_str().to_number::<f64>(), Err(ConversionError::Underflow));
+
+        let allow_underflow = ParseOption::new().with_allow_underflow(true);
+        assert_eq!(e50.as_str().to_number_culture_options::<f32>(Culture::English, allow_underflow), Ok(0.0));
+
+        // Real zeros are not underflow
+        assert_eq!("0".to_number::<f32>(), Ok(0.0));
+        assert_eq!("-0.000".to_number::<f64>(), Ok(0.0));
+        assert_eq!("0".to_number::<i32>(), Ok(0));
+
+        // The exponent digits don't make the number nonzero
+        assert_eq!("0e1".to_number::<f64>(), Ok(0.0));
+        assert_eq!("0E-5".to_number::<f32>(), Ok(0.0));
+        assert_eq!("0,0E10".to_number_culture::<f64>(Culture::French), Ok(0.0));
+        assert_eq!("1e-50".to_number::<f32>(), Err(ConversionError::Underflow));
+    }
+
+    #[test]
+    fn test_detect_conflicts() {
+        let conflicts = detect_conflicts("1,000");
+        assert!(matches!(conflicts, Some(ConversionError::AmbiguousInput { .. })));
+        if let Some(ConversionError::AmbiguousInput { alternatives }) = conflicts {
+            assert!(alternatives.contains(&(Culture::English, "1000".to_owned())));
+            assert!(alternatives.contains(&(Culture::Italian, "1".to_owned())));
+        }
+
+        assert_eq!(detect_conflicts("1.000,5"), None);
+        assert_eq!(detect_conflicts("1000"), None);
+        assert_eq!(detect_conflicts("NotANumber"), None);
+    }
+
+    /// The '+', '-' and no sign are read by all the conversions, for all the cultures
+    #[test]
+    fn test_number_sign() {
+        for culture in Culture::all() {
+            let grouped = crate::ToFormat::to_format(1234, "N0", culture).unwrap();
+            let decimal = crate::ToFormat::to_format(1234.5, "N1", culture).unwrap();
+            let settings = NumberCultureSettings::from(culture);
+
+            for (sign, factor) in [("+", 1), ("", 1), ("-", -1)] {
+                let grouped = format!("{}{}", sign, grouped);
+                let decimal = format!("{}{}", sign, decimal);
+                let simple = format!("{}1000", sign);
+                let message = format!("{:?} {}", culture, decimal);
+
+                assert_eq!(grouped.as_str().to_number_culture::<i32>(culture), Ok(1234 * factor), "{}", message);
+                assert_eq!(grouped.as_str().to_number_separators::<i32>(settings), Ok(1234 * factor), "{}", message);
+                assert_eq!(simple.as_str().to_number_culture::<i32>(culture), Ok(1000 * factor), "{}", message);
+                assert_eq!(simple.as_str().to_number::<i32>(), Ok(1000 * factor), "{}", message);
+                assert_eq!(decimal.as_str().to_number_culture::<f64>(culture), Ok(1234.5 * factor as f64), "{}", message);
+                assert_eq!(decimal.as_str().to_number_separators::<f64>(settings), Ok(1234.5 * factor as f64), "{}", message);
+                assert_eq!(decimal.as_str().to_number_validated::<f64>(culture), Ok(1234.5 * factor as f64), "{}", message);
+                assert_eq!(decimal.as_str().to_number_strict::<f64>(culture), Ok(1234.5 * factor as f64), "{}", message);
+
+                // The unsigned types only accept the positive numbers
+                match factor {
+                    1 => {
+                        assert_eq!(grouped.as_str().to_number_culture::<u32>(culture), Ok(1234), "{}", message);
+                        assert_eq!(simple.as_str().to_number::<u32>(), Ok(1000), "{}", message);
+                    }
+                    _ => {
+                        assert!(grouped.as_str().to_number_culture::<u32>(culture).is_err(), "{}", message);
+                        assert!(simple.as_str().to_number::<u32>().is_err(), "{}", message);
+                    }
+                }
+            }
+        }
+
+        assert_eq!("+1000".to_number::<i64>(), Ok(1000));
+        assert_eq!("+1000".to_number_detect_culture::<u32>(), Ok((1000, Culture::English)));
+    }
+
+    #[test]
+    fn test_number_ratio() {
+        assert_eq!("0,3333".to_number_culture::<Ratio<i64>>(Culture::French), Ok(Ratio::new(3333, 10000)));
+        assert_eq!("-1,234.5".to_number_culture::<Ratio<i32>>(Culture::English), Ok(Ratio::new(-2469, 2)));
+        assert_eq!("3".to_number::<Ratio<i32>>(), Ok(Ratio::from_integer(3)));
+
+        // Round trip with the display
+        let formatted = crate::ToFormat::to_format(Ratio::new(7, 2), "N2", Culture::French).unwrap();
+        assert_eq!(formatted.as_str().to_number_culture::<Ratio<i32>>(Culture::French), Ok(Ratio::new(7, 2)));
+
+        // Still an error for the integer types
+        assert_eq!("1.5".to_number::<i32>(), Err(ConversionError::UnableToConvertStringToNumber));
+    }
+
+    #[test]
+    fn test_parse_chain() {
+        // No culture : common patterns, or all the cultures when strict
+        assert_eq!(ParseChain::new("1000.5").convert::<f64>(), Ok(1000.5));
+        assert_eq!(ParseChain::new("1 000,5").strict().convert::<f64>(), Ok(1000.5));
+        assert_eq!(ParseChain::new("abc").strict().convert::<f64>(), Err(ConversionError::NoExactPatternMatch));
+
+        // Culture
+        assert_eq!(ParseChain::new("1,000.50").culture(Culture::English).convert::<f64>(), Ok(1000.5));
+        assert_eq!(ParseChain::new("1 000,50").culture(Culture::French).convert::<f64>(), Ok(1000.5));
+        assert_eq!(ParseChain::new("1,000.50").culture(Culture::English).strict().convert::<f64>(), Ok(1000.5));
+        assert_eq!(ParseChain::new("1000").culture(Culture::English).convert::<i32>(), Ok(1000));
+        assert_eq!(
+            ParseChain::new("1000").culture(Culture::English).strict().convert::<i32>(),
+            Err(ConversionError::NoExactPatternMatch)
         );
+        // The last culture set is used
+        assert_eq!(ParseChain::new("1,5").culture(Culture::English).culture(Culture::French).convert::<f64>(), Ok(1.5));
 
+        // Fallback cultures, tried in order
         assert_eq!(
-            i16_ok.to_number::<i8>(),
-            Err(ConversionError::UnableToConvertStringToNumber)
+            ParseChain::new("1 000,50").culture(Culture::English).try_culture(Culture::French).convert::<f64>(),
+            Ok(1000.5)
+        );
+        assert_eq!(ParseChain::new("1.000,50").try_culture(Culture::Italian).convert::<f64>(), Ok(1000.5));
+        assert_eq!(
+            ParseChain::new("1,00").culture(Culture::English).try_culture(Culture::Indian).strict().convert::<f64>(),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            ParseChain::new("1,000.5").culture(Culture::French).try_culture(Culture::English).strict().convert::<f64>(),
+            Ok(1000.5)
+        );
+        // The error of the first culture is returned
+        assert_eq!(
+            ParseChain::new("abc").culture(Culture::English).try_culture(Culture::French).convert::<f64>(),
+            "abc".to_number_culture::<f64>(Culture::English)
+        );
+
+        // Same chain from the conversion trait
+        assert_eq!("1,000.50".to_number_parse_chain().culture(Culture::English).strict().convert::<f64>(), Ok(1000.5));
+        assert_eq!(
+            StringNumber::new("1 000,5".to_owned()).to_number_parse_chain().culture(Culture::French).convert::<f32>(),
+            Ok(1000.5)
         );
     }
 
     #[test]
-    fn number_error_conversion() {
+    fn test_try_parse() {
+        assert_eq!(try_parse("1,234.5", ',', '.', ThousandGrouping::ThreeBlock), Ok(1234.5));
+        assert_eq!(try_parse("-1 234,5", ' ', ',', ThousandGrouping::ThreeBlock), Ok(-1234.5));
+        assert_eq!(try_parse("1,00,000", ',', '.', ThousandGrouping::TwoBlock), Ok(100000.0));
+        assert_eq!(try_parse("1|234🦀5", '|', '🦀', ThousandGrouping::ThreeBlock), Ok(1234.5));
+
+        // The grouping is validated
         assert_eq!(
-            "10,000,000"
-                .to_number_separators::<i32>(space_comma()),
-            Err(ConversionError::UnableToConvertStringToNumber)
+            try_parse("1,00.5", ',', '.', ThousandGrouping::ThreeBlock),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            try_parse("1,00,000.5", ',', '.', ThousandGrouping::ThreeBlock),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            try_parse("100,000", ',', '.', ThousandGrouping::TwoBlock),
+            Err(ConversionError::InvalidGrouping { expected: 2, found: 3 })
+        );
+        assert_eq!(
+            try_parse("1 2345,5", ' ', ',', ThousandGrouping::ThreeBlock),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 4 })
         );
 
+        // Errors instead of panics
         assert_eq!(
-            "10,00,00,00"
-                .to_number_separators::<i32>(space_comma()),
+            try_parse("-5|000|66", '|', '|', ThousandGrouping::ThreeBlock),
+            Err(ConversionError::DuplicateSeparators { separator: Separator::CUSTOM('|') })
+        );
+        assert_eq!(try_parse("1,5", '1', ',', ThousandGrouping::ThreeBlock), Err(ConversionError::SeparatorNotFound));
+        assert_eq!(try_parse("1,5", '-', ',', ThousandGrouping::ThreeBlock), Err(ConversionError::SeparatorNotFound));
+        assert_eq!(try_parse("", ',', '.', ThousandGrouping::ThreeBlock), Err(ConversionError::UnableToConvertStringToNumber));
+        assert!(try_parse("-5🍓000🦀🦀🦀66", '🍓', '🦀', ThousandGrouping::ThreeBlock).is_err());
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_try_parse_never_panics(input in "\\PC{0,20}", thousand in proptest::char::any(), decimal in proptest::char::any()) {
+            let _ = try_parse(&input, thousand, decimal, ThousandGrouping::ThreeBlock);
+            let _ = try_parse(&input, thousand, decimal, ThousandGrouping::TwoBlock);
+        }
+    }
+
+    #[test]
+    fn test_number_full_width() {
+        assert_eq!("１，２３４．５".to_number_culture::<f64>(Culture::English).unwrap(), 1234.5);
+        assert_eq!("－１，２３４".to_number_culture::<i32>(Culture::English).unwrap(), -1234);
+        assert_eq!("１２３４５６７８９０".to_number::<i64>().unwrap(), 1234567890);
+        assert_eq!("１\u{3000}２３４，５".to_number_culture::<f64>(Culture::French).unwrap(), 1234.5);
+        assert_eq!("１．２３４，５".to_number_culture::<f64>(Culture::Italian).unwrap(), 1234.5);
+        // Mixed ASCII and full-width
+        assert_eq!("1，234.5".to_number_culture::<f64>(Culture::English).unwrap(), 1234.5);
+        assert_eq!(StringNumber::new("＋１２".to_owned()).clean(), "+12");
+        assert_eq!(
+            "１，２３４．５".to_number_culture::<f64>(Culture::French),
             Err(ConversionError::UnableToConvertStringToNumber)
         );
+    }
+
+    #[test]
+    fn test_number_minus_sign() {
+        assert_eq!("\u{2212}1 000".to_number_culture::<i32>(Culture::French).unwrap(), -1000);
+        assert_eq!("\u{2212}1 000,5".to_number_culture::<f64>(Culture::French).unwrap(), -1000.5);
+        assert_eq!("\u{2212}1,000.5".to_number_culture::<f64>(Culture::English).unwrap(), -1000.5);
+        assert_eq!("\u{2212}42".to_number::<i64>().unwrap(), -42);
+        assert_eq!("\u{2212},5".to_number_culture::<f32>(Culture::Italian).unwrap(), -0.5);
         assert_eq!(
-            "10,00,00,00"
-                .to_number::<i32>(),
+            "\u{2212}1 000".to_number_validated::<i32>(Culture::French).unwrap(),
+            -1000
+        );
+        assert_eq!(
+            "\u{2212}1 00".to_number_validated::<i32>(Culture::French),
+            Err(ConversionError::InvalidGrouping { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            "\u{2212}\u{2212}1".to_number::<i32>(),
             Err(ConversionError::UnableToConvertStringToNumber)
         );
+        assert_eq!("\u{2212}1 000".to_number_culture::<u32>(Culture::French), Err(ConversionError::UnableToConvertStringToNumber));
     }
+
     #[test]
-    fn number_conversion_not_allowed() {
-        let list = vec!["x", "10*5", "2..500"];
+    fn test_number_range() {
+        let range = NumberRange::new(1, 10).unwrap();
+        assert_eq!((range.min(), range.max()), (&1, &10));
+        assert!(range.contains(&1));
+        assert!(range.contains(&10));
+        assert!(!range.contains(&11));
+        assert!(NumberRange::new(5, 5).unwrap().contains(&"5".to_number::<i32>().unwrap()));
 
-        for string_value in list {
-            let wn = StringNumber::new(String::from(string_value));
+        assert_eq!(
+            NumberRange::new(10, 1),
+            Err(ConversionError::InvalidRange { min_str: "10".to_owned(), max_str: "1".to_owned() })
+        );
+        assert_eq!(
+            NumberRange::new(2.5, -1.5),
+            Err(ConversionError::InvalidRange { min_str: "2.5".to_owned(), max_str: "-1.5".to_owned() })
+        );
+        assert_eq!(
+            NumberRange::new(f64::NAN, 1.0),
+            Err(ConversionError::InvalidRange { min_str: "NaN".to_owned(), max_str: "1".to_owned() })
+        );
 
+        let range = NumberRange::new(-1.5, 1000.0).unwrap();
+        assert!(range.contains(&"1,000".to_number_culture::<f64>(Culture::English).unwrap()));
+        assert!(!range.contains(&"1 000,5".to_number_culture::<f64>(Culture::French).unwrap()));
+    }
+
+    #[test]
+    fn test_number_with_unit() {
+        let values = vec![
+            ("100.5 kg", Culture::English, 100.5, Some("kg")),
+            ("1,000 EUR", Culture::English, 1000.0, Some("EUR")),
+            ("1000", Culture::English, 1000.0, None),
+            ("50%", Culture::English, 50.0, Some("%")),
+            ("-12.5°C", Culture::English, -12.5, Some("°C")),
+            ("1 234,5 €", Culture::French, 1234.5, Some("€")),
+            ("1 234,5", Culture::French, 1234.5, None),
+            ("1.234,5 km/h", Culture::Italian, 1234.5, Some("km/h")),
+            (",5 m²", Culture::Italian, 0.5, Some("m²")),
+            ("1,00,000 INR", Culture::Indian, 100000.0, Some("INR")),
+            ("  42  items ", Culture::English, 42.0, Some("items")),
+        ];
+
+        for (string_num, culture, number, unit) in values {
             assert_eq!(
-                wn.to_number::<i32>(),
-                Err(ConversionError::UnableToConvertStringToNumber)
+                string_num.to_number_with_unit::<f64>(culture).unwrap(),
+                (number, unit.map(|u| u.to_owned())),
+                "{}",
+                string_num
+            );
+        }
+
+        assert_eq!("1,000 EUR".to_number_with_unit::<i32>(Culture::English).unwrap(), (1000, Some("EUR".to_owned())));
+
+        let errors = vec![
+            ("kg", Culture::English),
+            ("100.5 kg 2", Culture::English),
+            ("100.5 12", Culture::English),
+            ("1,000.5 EUR", Culture::French),
+            ("100.5kg", Culture::French),
+        ];
+        for (string_num, culture) in errors {
+            assert_eq!(
+                string_num.to_number_with_unit::<f64>(culture),
+                Err(ConversionError::UnableToConvertStringToNumber),
+                "{}",
+                string_num
             );
         }
     }
 
+    #[test]
+    fn test_to_number_with_full_context() {
+        let context = |value: f64, unit: Option<&str>, scale: Option<ScaleSuffix>, sign: Sign| NumberWithContext {
+            value,
+            unit: unit.map(String::from),
+            scale,
+            sign,
+        };
+        let values = vec![
+            ("1.5K USD", Culture::English, context(1500.0, Some("USD"), Some(ScaleSuffix::Kilo), Sign::Positive)),
+            ("-50 €", Culture::French, context(-50.0, Some("€"), None, Sign::Negative)),
+            // Scale without unit
+            ("2M", Culture::English, context(2_000_000.0, None, Some(ScaleSuffix::Mega), Sign::Positive)),
+            ("-1,25 k", Culture::French, context(-1250.0, None, Some(ScaleSuffix::Kilo), Sign::Negative)),
+            ("3.2B", Culture::English, context(3_200_000_000.0, None, Some(ScaleSuffix::Giga), Sign::Positive)),
+            ("0.001T", Culture::English, context(1_000_000_000.0, None, Some(ScaleSuffix::Tera), Sign::Positive)),
+            // Unit without scale
+            ("100.5 kg", Culture::English, context(100.5, Some("kg"), None, Sign::Positive)),
+            ("1 000,5 km/h", Culture::French, context(1000.5, Some("km/h"), None, Sign::Positive)),
+            // Neither scale nor unit
+            ("-1,234.5", Culture::English, context(-1234.5, None, None, Sign::Negative)),
+            ("+42", Culture::English, context(42.0, None, None, Sign::Positive)),
+            // Scale and unit with culture separators
+            ("+1.234,5K EUR", Culture::Italian, context(1_234_500.0, Some("EUR"), Some(ScaleSuffix::Kilo), Sign::Positive)),
+            ("-0,5 M €", Culture::French, context(-500_000.0, Some("€"), Some(ScaleSuffix::Mega), Sign::Negative)),
+        ];
+        for (string_num, culture, expected) in values {
+            assert_eq!(string_num.to_number_with_full_context::<f64>(culture), Ok(expected), "{}", string_num);
+        }
+
+        // The scale is applied on the digits
+        assert_eq!("1.5K".to_number_with_full_context::<i32>(Culture::English).unwrap().value, 1500);
+        assert_eq!("0.1M".to_number_with_full_context::<u32>(Culture::English).unwrap().value, 100_000);
+        assert_eq!("1.2345K".to_number_with_full_context::<i32>(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(
+            StringNumber::new("12K units".to_owned()).to_number_with_full_context::<i64>(Culture::English).unwrap().value,
+            12_000
+        );
+
+        let errors = vec!["K", "USD", "1.5X USD", "1.5K USD 2", ""];
+        for string_num in errors {
+            assert!(string_num.to_number_with_full_context::<f64>(Culture::English).is_err(), "{}", string_num);
+        }
+    }
+
+    #[test]
+    fn test_number_hexfloat() {
+        let values = vec![
+            ("0x1.8p3", 12.0),
+            ("0X1.8P3", 12.0),
+            ("-0x1.8p3", -12.0),
+            ("+0x1p-1", 0.5),
+            ("0x.8p1", 1.0),
+            ("0x10p0", 16.0),
+            ("0x1.p4", 16.0),
+            ("0xA.Bp0", 10.6875),
+            ("0x1.fffffffffffffp1023", f64::MAX),
+            ("0x0p0", 0.0),
+        ];
+
+        for (string_num, number) in values {
+            assert_eq!(string_num.to_number_hexfloat::<f64>().unwrap(), number, "{}", string_num);
+            assert_eq!(string_num.to_number::<f64>().unwrap(), number, "{}", string_num);
+        }
+
+        assert_eq!("0x1.8p3".to_number_hexfloat::<f32>().unwrap(), 12.0);
+        assert_eq!("0x1.8p3".to_number::<i32>().unwrap(), 12);
+
+        let errors = vec![
+            "0x1.8",
+            "0x1.8p",
+            "0x1.8p+",
+            "0x1.8p3.5",
+            "0x1.8e3",
+            "0xp3",
+            "0x.p3",
+            "0x1.Gp3",
+            "1.8p3",
+            "0x1.8p99999999999",
+            "0x1.8p3 kg",
+        ];
+        for string_num in errors {
+            assert!(string_num.to_number_hexfloat::<f64>().is_err(), "{}", string_num);
+            assert!(string_num.to_number::<f64>().is_err(), "{}", string_num);
+        }
+
+        assert_eq!("0x1p1024".to_number_hexfloat::<f64>(), Err(ConversionError::Overflow));
+
+        // The range of the target type is checked (f32::MAX is ~0x1.fffffep127, the smallest f32 is 0x1p-149)
+        assert_eq!("0x1p200".to_number_hexfloat::<f32>(), Err(ConversionError::Overflow));
+        assert_eq!("0x1p200".to_number::<f32>(), Err(ConversionError::Overflow));
+        assert_eq!("-0x1p128".to_number_hexfloat::<f32>(), Err(ConversionError::Overflow));
+        assert_eq!("0x1p127".to_number_hexfloat::<f32>(), Ok(2f32.powi(127)));
+        assert_eq!("0x1p-149".to_number_hexfloat::<f32>(), Ok(f32::from_bits(1)));
+        assert_eq!("0x1p-160".to_number_hexfloat::<f32>(), Err(ConversionError::Underflow));
+        assert_eq!("0x1p-1100".to_number_hexfloat::<f64>(), Err(ConversionError::Underflow));
+        assert_eq!("0x0p-160".to_number_hexfloat::<f32>(), Ok(0.0));
+        assert_eq!(
+            "0x1.8p-1".to_number::<i32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(StringNumber::new("0x1.8p3".to_owned()).to_number_hexfloat::<f64>().unwrap(), 12.0);
+    }
+
+    #[test]
+    fn test_number_radix() {
+        assert_eq!("0xFF".to_number_radix::<i32>(16), Ok(255));
+        assert_eq!("ff".to_number_radix::<i32>(16), Ok(255));
+        assert_eq!("-0x10".to_number_radix::<i64>(16), Ok(-16));
+        assert_eq!("1010".to_number_radix::<u8>(2), Ok(10));
+        assert_eq!("0b1010".to_number_radix::<u8>(2), Ok(10));
+        assert_eq!("0o777".to_number_radix::<u32>(8), Ok(511));
+        assert_eq!("z".to_number_radix::<u32>(36), Ok(35));
+
+        // The prefix is only stripped for its radix
+        assert_eq!("0b1".to_number_radix::<i32>(16), Ok(177));
+        assert_eq!(
+            "0x1".to_number_radix::<i32>(2),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+
+        assert_eq!("0x100".to_number_radix::<u8>(16), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("-1".to_number_radix::<u8>(2), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("102".to_number_radix::<i32>(2), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("10".to_number_radix::<i32>(37), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("10".to_number_radix::<i32>(1), Err(ConversionError::UnableToConvertStringToNumber));
+    }
+
     #[test]
     fn escape_special_char_regex() {
         // escape
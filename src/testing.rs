@@ -0,0 +1,152 @@
+//! Utilities to test the code using this crate (enabled with the "testing" feature)
+//!
+//! The assertions check the conversions in both ways (number to string, then string to number),
+//! and the proptest strategies generate the cultures and separators to write property based tests.
+//! ``` rust
+//! use num_string::testing::{any_culture, assert_roundtrip_int};
+//! use proptest::prelude::*;
+//! use proptest::test_runner::TestRunner;
+//!
+//!     // Usually written with the proptest! macro
+//!     TestRunner::default()
+//!         .run(&(any::<i64>(), any_culture()), |(value, culture)| {
+//!             assert_roundtrip_int(value, culture);
+//!             Ok(())
+//!         })
+//!         .unwrap();
+//! ```
+use proptest::prelude::*;
+use proptest::sample::select;
+
+use crate::{Culture, NumberConversion, NumberCultureSettings, Separator, ThousandGrouping, ToFormat};
+
+/// The separators used by the 'any_number_culture_settings' strategy
+const SEPARATORS: [Separator; 5] = [
+    Separator::COMMA,
+    Separator::DOT,
+    Separator::SPACE,
+    Separator::APOSTROPHE,
+    Separator::MIDDLEDOT,
+];
+
+/// Assert that the float formatted with the format ("N0" to "N9") and the culture is parsed back to the same value,
+/// within the rounding of the format (half of the last displayed digit)
+/// Ref 'test_roundtrip'
+pub fn assert_roundtrip_float(value: f64, culture: Culture, format: &str) {
+    let nb_digits = format
+        .strip_prefix('N')
+        .and_then(|digits| digits.parse::<i32>().ok())
+        .unwrap_or_else(|| panic!("Invalid format {}", format));
+
+    let formatted = value
+        .to_format(format, culture)
+        .unwrap_or_else(|e| panic!("Unable to format {} with {} ({:?}) : {}", value, format, culture, e));
+    let parsed = formatted
+        .as_str()
+        .to_number_culture::<f64>(culture)
+        .unwrap_or_else(|e| panic!("Unable to parse {} ({:?}) : {}", formatted, culture, e));
+
+    let tolerance = 0.5 * 10f64.powi(-nb_digits) + value.abs() * f64::EPSILON * 4.0;
+    assert!(
+        (parsed - value).abs() <= tolerance,
+        "{} formatted as {} ({:?}) is parsed as {}",
+        value,
+        formatted,
+        culture,
+        parsed
+    );
+}
+
+/// Assert that the integer formatted with the culture ("N0") is parsed back to the same value
+/// Ref 'test_roundtrip'
+pub fn assert_roundtrip_int(value: i64, culture: Culture) {
+    let formatted = value
+        .to_format("N0", culture)
+        .unwrap_or_else(|e| panic!("Unable to format {} ({:?}) : {}", value, culture, e));
+
+    assert_eq!(
+        formatted.as_str().to_number_culture::<i64>(culture),
+        Ok(value),
+        "{} formatted as {} ({:?})",
+        value,
+        formatted,
+        culture
+    );
+}
+
+/// Assert that the string number is parsed with the culture as the expected value (relative tolerance of a few ulps)
+/// Ref 'test_roundtrip'
+pub fn assert_parses_as(s: &str, culture: Culture, expected: f64) {
+    let parsed = s
+        .to_number_culture::<f64>(culture)
+        .unwrap_or_else(|e| panic!("Unable to parse {} ({:?}) : {}", s, culture, e));
+
+    assert!(
+        (parsed - expected).abs() <= expected.abs() * f64::EPSILON * 4.0,
+        "{} ({:?}) is parsed as {}, expected {}",
+        s,
+        culture,
+        parsed,
+        expected
+    );
+}
+
+/// Strategy generating all the cultures
+pub fn any_culture() -> impl Strategy<Value = Culture> {
+    select(enum_iterator::all::<Culture>().collect::<Vec<Culture>>())
+}
+
+/// Strategy generating valid culture settings : built-in separators (the thousand and decimal ones are different)
+/// with the three or two block grouping
+pub fn any_number_culture_settings() -> impl Strategy<Value = NumberCultureSettings> {
+    (
+        select(SEPARATORS.to_vec()),
+        select(SEPARATORS.to_vec()),
+        select(vec![ThousandGrouping::ThreeBlock, ThousandGrouping::TwoBlock]),
+    )
+        .prop_filter("The separators must be different", |(thousand, decimal, _)| thousand != decimal)
+        .prop_map(|(thousand, decimal, grouping)| NumberCultureSettings::new(thousand, decimal).with_grouping(grouping))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{any_culture, any_number_culture_settings, assert_parses_as, assert_roundtrip_float, assert_roundtrip_int};
+    use crate::{Culture, NumberConversion, ToFormat};
+
+    #[test]
+    fn test_roundtrip() {
+        assert_roundtrip_float(1234.567, Culture::French, "N2");
+        assert_roundtrip_float(0.004, Culture::English, "N2");
+        assert_roundtrip_int(-1_000_000, Culture::Indian);
+        assert_roundtrip_int(i64::MIN, Culture::Italian);
+        assert_parses_as("1 234,5", Culture::French, 1234.5);
+        assert_parses_as("-1,00,000.25", Culture::Indian, -100000.25);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parses_as_wrong_value() {
+        assert_parses_as("1,5", Culture::English, 1.5);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_roundtrip_int(value in any::<i64>(), culture in any_culture()) {
+            assert_roundtrip_int(value, culture);
+        }
+
+        #[test]
+        fn prop_roundtrip_float(value in 1.0f64..1e12, culture in any_culture(), digits in 0u8..=9) {
+            assert_roundtrip_float(value, culture, &format!("N{}", digits));
+        }
+
+        #[test]
+        fn prop_settings_roundtrip(value in any::<i32>(), settings in any_number_culture_settings()) {
+            prop_assert_ne!(settings.thousand_separator(), settings.decimal_separator());
+            let formatted = value.to_format_separators("N2", settings).unwrap();
+            prop_assert_eq!(formatted.as_str().to_number_separators::<f64>(settings), Ok(value as f64));
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //!  Global test case
 //! An overview of the main functionalities of the crate
+#![allow(clippy::inconsistent_digit_grouping)]
 
 use num_string::{ConvertString, NumberConversion, NumberCultureSettings, ToFormat};
 